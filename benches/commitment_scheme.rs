@@ -0,0 +1,127 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Compares the proof size and verification time of the KZG10 and IPA
+//! commitment schemes.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use dusk_plonk::commitment_scheme::{
+    CommitmentScheme, Ipa, IpaParameters, Kzg10,
+};
+use dusk_plonk::prelude::*;
+use rand::SeedableRng;
+use rand_xorshift::XorShiftRng;
+
+const LABEL: &[u8] = b"dusk-network";
+
+fn gadget(composer: &mut TurboComposer, size: usize) {
+    let mut a = BlsScalar::from(2u64);
+    let mut b = BlsScalar::from(3u64);
+    let mut c;
+
+    while composer.gates() < size as u32 {
+        a += BlsScalar::one();
+        b += BlsScalar::one();
+        c = a * b + a + b + BlsScalar::one();
+
+        let x = composer.append_witness(a);
+        let y = composer.append_witness(b);
+        let z = composer.append_witness(c);
+
+        let constraint = Constraint::new()
+            .mult(1)
+            .left(1)
+            .right(1)
+            .output(-BlsScalar::one())
+            .constant(1)
+            .a(x)
+            .b(y)
+            .o(z);
+
+        composer.append_gate(constraint);
+    }
+}
+
+fn prove_and_preprocess<S: CommitmentScheme>(
+    commit_key: &S::CommitKey,
+    size: usize,
+) -> (Verifier<S>, Proof<S>) {
+    let mut prover = Prover::<S>::with_label(LABEL);
+    gadget(prover.composer_mut(), size);
+    let proof = prover
+        .prove(commit_key)
+        .expect("Failed to prove bench circuit!");
+
+    let mut verifier = Verifier::<S>::with_label(LABEL);
+    gadget(verifier.composer_mut(), size);
+    verifier
+        .preprocess(commit_key)
+        .expect("Failed to preprocess bench circuit!");
+
+    (verifier, proof)
+}
+
+fn bench_verify<S: CommitmentScheme>(
+    c: &mut Criterion,
+    scheme: &str,
+    size: usize,
+    commit_key: &S::CommitKey,
+    opening_key: &S::OpeningKey,
+) {
+    let (verifier, proof) = prove_and_preprocess::<S>(commit_key, size);
+    verifier
+        .verify(&proof, opening_key, &[])
+        .expect("Failed to verify bench circuit!");
+
+    let power = (size as f64).log2() as usize;
+    println!(
+        "{} proof size for 2^{} = {} gates: {} bytes",
+        scheme,
+        power,
+        size,
+        proof.to_var_bytes().len()
+    );
+
+    let description = format!("{} verify 2^{} = {} gates", scheme, power, size);
+    c.bench_function(description.as_str(), |b| {
+        b.iter(|| {
+            verifier
+                .verify(black_box(&proof), opening_key, &[])
+                .expect("Failed to verify bench circuit!");
+        })
+    });
+}
+
+fn commitment_scheme_benchmark(c: &mut Criterion) {
+    let initial_degree = 5;
+    let final_degree = 14;
+
+    let rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32,
+        0x54, 0x06, 0xbc, 0xe5,
+    ]);
+    let pp = PublicParameters::setup(1 << final_degree, rng)
+        .expect("Failed to create PP");
+    let ipa = IpaParameters::setup(1 << final_degree)
+        .expect("Failed to create IPA parameters");
+
+    (initial_degree..final_degree).for_each(|degree| {
+        let size = 1 << degree;
+
+        let (ck, ok) = pp.trim(size).expect("Failed to trim PP");
+        bench_verify::<Kzg10>(c, "KZG10", size, &ck, &ok);
+
+        let params = ipa.trim(size).expect("Failed to trim IPA parameters");
+        bench_verify::<Ipa>(c, "IPA", size, &params, &params);
+    });
+}
+
+criterion_group! {
+    name = commitment_scheme;
+    config = Criterion::default().sample_size(10);
+    targets = commitment_scheme_benchmark
+}
+criterion_main!(commitment_scheme);
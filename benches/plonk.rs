@@ -29,13 +29,14 @@ where
 
 impl Circuit for BenchCircuit {
     const CIRCUIT_ID: [u8; 32] = [0xff; 32];
+    type PublicInputs = ();
 
     fn gadget(&mut self, composer: &mut TurboComposer) -> Result<(), Error> {
         let mut a = BlsScalar::from(2u64);
         let mut b = BlsScalar::from(3u64);
         let mut c;
 
-        while composer.gates() < self.padded_gates() as u32 {
+        while composer.gates() < self.degree as u32 {
             a += BlsScalar::one();
             b += BlsScalar::one();
             c = a * b + a + b + BlsScalar::one();
@@ -60,13 +61,7 @@ impl Circuit for BenchCircuit {
         Ok(())
     }
 
-    fn public_inputs(&self) -> Vec<PublicInputValue> {
-        vec![]
-    }
-
-    fn padded_gates(&self) -> usize {
-        self.degree
-    }
+    fn public_inputs(&self) {}
 }
 
 fn constraint_system_prove(
@@ -102,7 +97,7 @@ fn constraint_system_benchmark(c: &mut Criterion) {
                 let proof =
                     constraint_system_prove(&mut circuit, &pp, &pk, label);
 
-                BenchCircuit::verify(&pp, &vd, &proof, &[], label)
+                BenchCircuit::verify(&pp, &vd, &proof, &(), label)
                     .expect("Failed to verify bench circuit");
 
                 (circuit, pk, vd, proof)
@@ -110,7 +105,7 @@ fn constraint_system_benchmark(c: &mut Criterion) {
             .collect();
 
     data.iter().for_each(|(mut circuit, pk, _, _)| {
-        let size = circuit.padded_gates();
+        let size = pk.padded_gates();
         let power = (size as f64).log2() as usize;
         let description = format!("Prove 2^{} = {} gates", power, size);
 
@@ -121,14 +116,14 @@ fn constraint_system_benchmark(c: &mut Criterion) {
         });
     });
 
-    data.iter().for_each(|(circuit, _, vd, proof)| {
-        let size = circuit.padded_gates();
+    data.iter().for_each(|(_, _, vd, proof)| {
+        let size = vd.padded_gates();
         let power = (size as f64).log2() as usize;
         let description = format!("Verify 2^{} = {} gates", power, size);
 
         c.bench_function(description.as_str(), |b| {
             b.iter(|| {
-                BenchCircuit::verify(&pp, vd, black_box(proof), &[], label)
+                BenchCircuit::verify(&pp, vd, black_box(proof), &(), label)
                     .expect("Failed to verify bench circuit!");
             })
         });
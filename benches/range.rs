@@ -0,0 +1,127 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Compares the circuit size and proving time of the range constraints of
+//! `component_range` and the lookup-backed `component_range_lookup`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use dusk_plonk::prelude::*;
+use rand::SeedableRng;
+use rand_xorshift::XorShiftRng;
+
+const LABEL: &[u8] = b"dusk-network";
+
+// Number of range constraints of every circuit
+const CHECKS: u64 = 256;
+
+#[derive(Debug, Clone, Copy)]
+enum Range {
+    Quads,
+    Lookup { limb_bits: usize },
+}
+
+impl Range {
+    fn description(&self) -> String {
+        match self {
+            Range::Quads => "component_range".into(),
+            Range::Lookup { limb_bits } => {
+                format!("component_range_lookup ({} bit limbs)", limb_bits)
+            }
+        }
+    }
+}
+
+fn gadget(composer: &mut TurboComposer, range: Range, num_bits: usize) {
+    (0..CHECKS).for_each(|i| {
+        let witness = composer.append_witness(BlsScalar::from(i));
+
+        match range {
+            Range::Quads => composer.component_range(witness, num_bits),
+            Range::Lookup { limb_bits } => {
+                composer.component_range_lookup(witness, num_bits, limb_bits)
+            }
+        }
+    });
+}
+
+fn bench_range(
+    c: &mut Criterion,
+    commit_key: &CommitKey,
+    opening_key: &OpeningKey,
+    range: Range,
+    num_bits: usize,
+) {
+    let mut prover = Prover::new(LABEL);
+    gadget(prover.composer_mut(), range, num_bits);
+    let gates = prover.composer_mut().gates();
+
+    let mut verifier = Verifier::new(LABEL);
+    gadget(verifier.composer_mut(), range, num_bits);
+    verifier
+        .preprocess(commit_key)
+        .expect("Failed to preprocess bench circuit!");
+
+    let proof = prover
+        .prove(commit_key)
+        .expect("Failed to prove bench circuit!");
+    verifier
+        .verify(&proof, opening_key, &[])
+        .expect("Failed to verify bench circuit!");
+
+    println!(
+        "{} for {} checks of {} bits: {} gates",
+        range.description(),
+        CHECKS,
+        num_bits,
+        gates
+    );
+
+    let description = format!(
+        "Prove {} checks of {} bits with {}",
+        CHECKS,
+        num_bits,
+        range.description()
+    );
+    c.bench_function(description.as_str(), |b| {
+        b.iter(|| {
+            // Proving clears the witnesses of the circuit
+            gadget(prover.composer_mut(), range, num_bits);
+            black_box(&mut prover)
+                .prove(commit_key)
+                .expect("Failed to prove bench circuit!");
+        })
+    });
+}
+
+fn range_benchmark(c: &mut Criterion) {
+    // The 16 bit range table requires circuits of 2^17 gates
+    let degree = 18;
+
+    let rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32,
+        0x54, 0x06, 0xbc, 0xe5,
+    ]);
+    let pp =
+        PublicParameters::setup(1 << degree, rng).expect("Failed to create PP");
+    let (ck, ok) = pp.trim(1 << degree).expect("Failed to trim PP");
+
+    [32, 64, 128].iter().for_each(|num_bits| {
+        [
+            Range::Quads,
+            Range::Lookup { limb_bits: 8 },
+            Range::Lookup { limb_bits: 16 },
+        ]
+        .iter()
+        .for_each(|range| bench_range(c, &ck, &ok, *range, *num_bits));
+    });
+}
+
+criterion_group! {
+    name = range;
+    config = Criterion::default().sample_size(10);
+    targets = range_benchmark
+}
+criterion_main!(range);
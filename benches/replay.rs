@@ -0,0 +1,136 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Compares building and proving a compiled circuit with a full composer, as
+//! `Circuit::prove` does, against replaying its gadget in a witness-only
+//! composer, as `Circuit::prove_witness_only` does.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use dusk_plonk::prelude::*;
+use rand::SeedableRng;
+use rand_xorshift::XorShiftRng;
+
+const LABEL: &[u8] = b"dusk-network";
+
+#[derive(Debug, Clone, Copy)]
+struct ReplayCircuit {
+    degree: usize,
+}
+
+impl ReplayCircuit {
+    fn new(degree: usize) -> Self {
+        Self {
+            degree: 1 << degree,
+        }
+    }
+}
+
+impl Circuit for ReplayCircuit {
+    const CIRCUIT_ID: [u8; 32] = [0xfd; 32];
+    type PublicInputs = ();
+
+    fn gadget(&mut self, composer: &mut TurboComposer) -> Result<(), Error> {
+        let mut a = BlsScalar::from(2u64);
+        let mut b = BlsScalar::from(3u64);
+
+        // Fill the circuit with a mix of arithmetic, range and logic gates,
+        // leaving room for the padding of the last round
+        while composer.gates() + 64 < self.degree as u32 {
+            a += BlsScalar::one();
+            b += BlsScalar::one();
+
+            let x = composer.append_witness(a);
+            let y = composer.append_witness(b);
+
+            let constraint = Constraint::new().mult(1).left(1).a(x).b(y);
+            let z = composer.gate_mul(constraint);
+
+            composer.component_range(x, 32);
+            composer.component_xor(x, y, 32);
+            composer.assert_equal_constant(z, a * b + a, None);
+        }
+
+        Ok(())
+    }
+
+    fn public_inputs(&self) {}
+}
+
+fn bench_replay(c: &mut Criterion, pp: &PublicParameters, degree: usize) {
+    let mut circuit = ReplayCircuit::new(degree);
+    let (pk, vd) = circuit.compile(pp).expect("Failed to compile circuit!");
+
+    let proof = circuit
+        .prove_witness_only(pp, &pk, LABEL)
+        .expect("Failed to prove bench circuit!");
+    ReplayCircuit::verify(pp, &vd, &proof, &(), LABEL)
+        .expect("Failed to verify bench circuit!");
+
+    let size = pk.padded_gates();
+
+    let description = format!("Build 2^{} = {} gates", degree, size);
+    c.bench_function(description.as_str(), |b| {
+        b.iter(|| {
+            let mut prover = Prover::new(LABEL);
+            black_box(&mut circuit)
+                .gadget(prover.composer_mut())
+                .expect("Failed to build bench circuit!");
+        })
+    });
+
+    let description =
+        format!("Build 2^{} = {} gates, witness only", degree, size);
+    c.bench_function(description.as_str(), |b| {
+        b.iter(|| {
+            let mut prover = Prover::witness_only(LABEL);
+            black_box(&mut circuit)
+                .gadget(prover.composer_mut())
+                .expect("Failed to build bench circuit!");
+        })
+    });
+
+    let description = format!("Prove 2^{} = {} gates", degree, size);
+    c.bench_function(description.as_str(), |b| {
+        b.iter(|| {
+            black_box(&mut circuit)
+                .prove(pp, &pk, LABEL)
+                .expect("Failed to prove bench circuit!")
+        })
+    });
+
+    let description =
+        format!("Prove 2^{} = {} gates, witness only", degree, size);
+    c.bench_function(description.as_str(), |b| {
+        b.iter(|| {
+            black_box(&mut circuit)
+                .prove_witness_only(pp, &pk, LABEL)
+                .expect("Failed to prove bench circuit!")
+        })
+    });
+}
+
+fn replay_benchmark(c: &mut Criterion) {
+    let initial_degree = 10;
+    let final_degree = 17;
+
+    let rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32,
+        0x54, 0x06, 0xbc, 0xe5,
+    ]);
+    let pp = PublicParameters::setup(1 << final_degree, rng)
+        .expect("Failed to create PP");
+
+    (initial_degree..final_degree).for_each(|degree| {
+        bench_replay(c, &pp, degree);
+    });
+}
+
+criterion_group! {
+    name = replay;
+    config = Criterion::default().sample_size(10);
+    targets = replay_benchmark
+}
+criterion_main!(replay);
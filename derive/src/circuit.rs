@@ -0,0 +1,302 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use merlin::Transcript;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+    Attribute, Data, DeriveInput, Error, ExprPath, Fields, Ident, Lit, Meta,
+    NestedMeta, Result, Type,
+};
+
+/// How a field of the circuit is allocated in its composer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Allocation {
+    Witness,
+    Public,
+    Constant,
+    Skip,
+}
+
+impl Allocation {
+    const fn label(self) -> &'static [u8] {
+        match self {
+            Allocation::Witness => b"witness",
+            Allocation::Public => b"public",
+            Allocation::Constant => b"constant",
+            Allocation::Skip => b"skip",
+        }
+    }
+}
+
+struct Field<'a> {
+    ident: &'a Ident,
+    ty: &'a Type,
+    allocation: Allocation,
+}
+
+fn circuit_attrs(attrs: &[Attribute]) -> Result<Vec<NestedMeta>> {
+    let mut nested = vec![];
+
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("circuit")) {
+        match attr.parse_meta()? {
+            Meta::List(list) => nested.extend(list.nested),
+            meta => {
+                return Err(Error::new_spanned(
+                    meta,
+                    "expected `#[circuit(...)]`",
+                ))
+            }
+        }
+    }
+
+    Ok(nested)
+}
+
+fn field_allocation(field: &syn::Field) -> Result<Allocation> {
+    let mut allocation = None;
+
+    for meta in circuit_attrs(&field.attrs)? {
+        let parsed = match &meta {
+            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("witness") => {
+                Allocation::Witness
+            }
+            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("public") => {
+                Allocation::Public
+            }
+            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("constant") => {
+                Allocation::Constant
+            }
+            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("skip") => {
+                Allocation::Skip
+            }
+            _ => {
+                return Err(Error::new_spanned(
+                    meta,
+                    "expected `witness`, `public`, `constant` or `skip`",
+                ))
+            }
+        };
+
+        if allocation.replace(parsed).is_some() {
+            return Err(Error::new_spanned(
+                meta,
+                "a field can only be allocated once",
+            ));
+        }
+    }
+
+    allocation.ok_or_else(|| {
+        Error::new_spanned(
+            field,
+            "annotate the field with `#[circuit(witness)]`, \
+             `#[circuit(public)]`, `#[circuit(constant)]` or \
+             `#[circuit(skip)]`",
+        )
+    })
+}
+
+fn gadget_path(attrs: &[Attribute]) -> Result<ExprPath> {
+    let mut gadget = None;
+
+    for meta in circuit_attrs(attrs)? {
+        match &meta {
+            NestedMeta::Meta(Meta::NameValue(nv))
+                if nv.path.is_ident("gadget") =>
+            {
+                let path = match &nv.lit {
+                    Lit::Str(lit) => lit.parse()?,
+                    lit => {
+                        return Err(Error::new_spanned(
+                            lit,
+                            "expected the path to the gadget as a string",
+                        ))
+                    }
+                };
+
+                if gadget.replace(path).is_some() {
+                    return Err(Error::new_spanned(
+                        meta,
+                        "the gadget can only be given once",
+                    ));
+                }
+            }
+            _ => {
+                return Err(Error::new_spanned(
+                    meta,
+                    "expected `gadget = \"path\"`",
+                ))
+            }
+        }
+    }
+
+    Ok(gadget.unwrap_or_else(|| syn::parse_quote!(Self::constrain)))
+}
+
+/// Hashes the description of the circuit, which is the name and the fields
+/// of the struct and the path of the gadget constraining them.
+///
+/// The macro only sees the tokens of the struct, so the id doesn't change
+/// when the body of the gadget does. The shape of the compiled circuit is
+/// covered by its fingerprint instead.
+fn circuit_id(name: &Ident, fields: &[Field], gadget: &ExprPath) -> [u8; 32] {
+    let mut transcript = Transcript::new(b"dusk-plonk-circuit-id");
+
+    transcript.append_message(b"name", name.to_string().as_bytes());
+
+    fields.iter().for_each(|field| {
+        transcript.append_message(b"field", field.ident.to_string().as_bytes());
+        transcript.append_message(
+            b"type",
+            field.ty.to_token_stream().to_string().as_bytes(),
+        );
+        transcript.append_message(b"allocation", field.allocation.label());
+    });
+
+    transcript.append_message(
+        b"gadget",
+        gadget.to_token_stream().to_string().as_bytes(),
+    );
+
+    let mut id = [0u8; 32];
+    transcript.challenge_bytes(b"circuit-id", &mut id);
+
+    id
+}
+
+pub(crate) fn expand(input: DeriveInput) -> Result<TokenStream> {
+    let named = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    &input.ident,
+                    "Circuit can only be derived for structs with named \
+                     fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "Circuit can only be derived for structs",
+            ))
+        }
+    };
+
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "Circuit can't be derived for generic structs",
+        ));
+    }
+
+    let fields = named
+        .iter()
+        .map(|field| {
+            Ok(Field {
+                ident: field.ident.as_ref().expect("named field"),
+                ty: &field.ty,
+                allocation: field_allocation(field)?,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let gadget = gadget_path(&input.attrs)?;
+    let id = circuit_id(&input.ident, &fields, &gadget);
+    let id = id.iter();
+
+    let vis = &input.vis;
+    let name = &input.ident;
+    let witnesses_name = format_ident!("{}Witnesses", name);
+    let public_inputs_name = format_ident!("{}PublicInputs", name);
+
+    let allocated: Vec<_> = fields
+        .iter()
+        .filter(|field| field.allocation != Allocation::Skip)
+        .collect();
+    let public: Vec<_> = fields
+        .iter()
+        .filter(|field| field.allocation == Allocation::Public)
+        .collect();
+
+    let witness_fields = allocated.iter().map(|Field { ident, ty, .. }| {
+        quote! {
+            #vis #ident: <#ty as ::dusk_plonk::circuit::CircuitInput>::Witness
+        }
+    });
+
+    let allocations = allocated.iter().map(|field| {
+        let ident = field.ident;
+        let append = match field.allocation {
+            Allocation::Witness => quote!(append_witness),
+            Allocation::Public => quote!(append_public),
+            _ => quote!(append_constant),
+        };
+
+        quote! {
+            let #ident = ::dusk_plonk::circuit::CircuitInput::#append(
+                &self.#ident,
+                composer,
+            );
+        }
+    });
+    let allocated_idents = allocated.iter().map(|field| field.ident);
+
+    let public_fields = public
+        .iter()
+        .map(|Field { ident, ty, .. }| quote!(#vis #ident: #ty));
+    let public_idents = public.iter().map(|field| field.ident);
+    let public_idents_clone = public.iter().map(|field| field.ident);
+
+    let witnesses_doc =
+        format!("Witnesses allocated for the fields of [`{}`].", name);
+    let public_inputs_doc = format!("Public inputs of [`{}`].", name);
+
+    Ok(quote! {
+        #[doc = #witnesses_doc]
+        #[allow(missing_debug_implementations, missing_docs)]
+        #vis struct #witnesses_name {
+            #( #witness_fields, )*
+        }
+
+        #[doc = #public_inputs_doc]
+        #[allow(missing_debug_implementations, missing_docs)]
+        #[derive(::dusk_plonk::circuit::PublicInputs)]
+        #vis struct #public_inputs_name {
+            #( #public_fields, )*
+        }
+
+        impl ::dusk_plonk::circuit::Circuit for #name {
+            const CIRCUIT_ID: [u8; 32] = [#( #id ),*];
+
+            type PublicInputs = #public_inputs_name;
+
+            fn gadget(
+                &mut self,
+                composer: &mut ::dusk_plonk::prelude::TurboComposer,
+            ) -> ::core::result::Result<(), ::dusk_plonk::prelude::Error> {
+                #( #allocations )*
+
+                let witnesses = #witnesses_name {
+                    #( #allocated_idents, )*
+                };
+
+                #gadget(self, composer, witnesses)
+            }
+
+            fn public_inputs(&self) -> #public_inputs_name {
+                #public_inputs_name {
+                    #(
+                        #public_idents: ::core::clone::Clone::clone(
+                            &self.#public_idents_clone,
+                        ),
+                    )*
+                }
+            }
+        }
+    })
+}
@@ -0,0 +1,63 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Derive macros for [`dusk-plonk`](https://docs.rs/dusk-plonk) circuits.
+//!
+//! The macros are re-exported by `dusk-plonk` next to the traits they
+//! derive, and the code they generate refers to it as `::dusk_plonk`.
+
+#![deny(missing_docs)]
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+mod circuit;
+mod public_inputs;
+
+/// Derives `Circuit` for a struct whose fields are the inputs of the circuit.
+///
+/// Every field is annotated with how it's allocated in the circuit:
+///
+/// - `#[circuit(witness)]` as a private witness.
+/// - `#[circuit(public)]` as a public input.
+/// - `#[circuit(constant)]` as a constant of the circuit description.
+/// - `#[circuit(skip)]` not allocated at all.
+///
+/// The fields are allocated in declaration order and handed to the gadget
+/// in a `<Name>Witnesses` struct with a field of the same name for each of
+/// them. The gadget defaults to a `Self::constrain` method and can be set
+/// with `#[circuit(gadget = "path")]` on the struct. Its signature is
+/// `fn(&mut Self, &mut TurboComposer, <Name>Witnesses) -> Result<(), Error>`.
+///
+/// The public inputs of the circuit are a `<Name>PublicInputs` struct with
+/// the public fields, so the gadget must not append any other public input.
+///
+/// The `CIRCUIT_ID` is a hash of the name, fields and gadget path of the
+/// struct. It only covers the layout of the struct and not the body of the
+/// gadget, so editing the gadget keeps the same id. The compiled circuit is
+/// identified by the fingerprint of the `ProverKey` and `VerifierData`
+/// instead.
+#[proc_macro_derive(Circuit, attributes(circuit))]
+pub fn derive_circuit(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    circuit::expand(input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+/// Derives `PublicInputs` for a struct whose fields all implement it.
+///
+/// The scalars of the fields are appended in declaration order, which must
+/// be the order the gadget of the circuit appends them.
+#[proc_macro_derive(PublicInputs)]
+pub fn derive_public_inputs(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    public_inputs::expand(input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
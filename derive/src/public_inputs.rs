@@ -0,0 +1,58 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, Data, DeriveInput, Error, Index, Member, Result};
+
+pub(crate) fn expand(mut input: DeriveInput) -> Result<TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "PublicInputs can only be derived for structs",
+            ))
+        }
+    };
+
+    let members = fields.iter().enumerate().map(|(i, field)| {
+        field
+            .ident
+            .clone()
+            .map(Member::Named)
+            .unwrap_or_else(|| Member::Unnamed(Index::from(i)))
+    });
+
+    input.generics.type_params_mut().for_each(|param| {
+        param
+            .bounds
+            .push(parse_quote!(::dusk_plonk::circuit::PublicInputs));
+    });
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) =
+        input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::dusk_plonk::circuit::PublicInputs
+            for #name #ty_generics #where_clause
+        {
+            #[allow(unused_variables)]
+            fn append_scalars<E>(&self, scalars: &mut E)
+            where
+                E: ::core::iter::Extend<::dusk_plonk::prelude::BlsScalar>,
+            {
+                #(
+                    ::dusk_plonk::circuit::PublicInputs::append_scalars(
+                        &self.#members,
+                        scalars,
+                    );
+                )*
+            }
+        }
+    })
+}
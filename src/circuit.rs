@@ -6,12 +6,13 @@
 
 //! Tools & traits for PLONK circuits
 
-use crate::commitment_scheme::PublicParameters;
-use crate::constraint_system::TurboComposer;
+use crate::commitment_scheme::{CommitKey, Kzg10, PublicParameters};
+use crate::constraint_system::{TurboComposer, Witness, WitnessPoint};
 use crate::error::Error;
 use crate::proof_system::{Proof, Prover, ProverKey, Verifier, VerifierKey};
 #[cfg(feature = "canon")]
 use canonical_derive::Canon;
+use core::cmp::Ordering;
 use dusk_bls12_381::BlsScalar;
 use dusk_bytes::{DeserializableSlice, Serializable, Write};
 use dusk_jubjub::{JubJubAffine, JubJubExtended, JubJubScalar};
@@ -19,6 +20,12 @@ use parity_scale_codec::{Decode, Encode};
 use sp_std::vec;
 use sp_std::vec::Vec;
 
+#[cfg(feature = "std")]
+mod pool;
+
+#[cfg(feature = "std")]
+pub use pool::ProverPool;
+
 #[derive(Default, Debug, Clone, PartialEq, Decode, Encode)]
 #[cfg_attr(feature = "canon", derive(Canon))]
 /// Structure that represents a PLONK Circuit Public Input converted into it's
@@ -49,6 +56,228 @@ impl From<JubJubExtended> for PublicInputValue {
     }
 }
 
+/// Public inputs of a [`Circuit`], converted to the [`BlsScalar`]s its
+/// gadget appends to the circuit, in the same order.
+///
+/// It can be derived for structs whose fields implement it, in which case
+/// the scalars of the fields are appended in declaration order:
+///
+/// ```
+/// use dusk_plonk::prelude::*;
+///
+/// #[derive(PublicInputs)]
+/// struct Transfer {
+///     value: u64,
+///     note: JubJubAffine,
+/// }
+///
+/// let transfer = Transfer {
+///     value: 10,
+///     note: dusk_jubjub::GENERATOR,
+/// };
+/// let scalars = transfer.to_scalars();
+///
+/// assert_eq!(scalars.len(), 3);
+/// assert_eq!(scalars[0], BlsScalar::from(10u64));
+/// ```
+pub trait PublicInputs {
+    /// Appends the public input scalars to `scalars`.
+    fn append_scalars<E: Extend<BlsScalar>>(&self, scalars: &mut E);
+
+    /// Returns the public input scalars.
+    fn to_scalars(&self) -> Vec<BlsScalar> {
+        let mut scalars = Vec::new();
+        self.append_scalars(&mut scalars);
+        scalars
+    }
+}
+
+pub use dusk_plonk_derive::PublicInputs;
+
+impl PublicInputs for BlsScalar {
+    fn append_scalars<E: Extend<BlsScalar>>(&self, scalars: &mut E) {
+        scalars.extend(Some(*self));
+    }
+}
+
+impl PublicInputs for JubJubScalar {
+    fn append_scalars<E: Extend<BlsScalar>>(&self, scalars: &mut E) {
+        scalars.extend(Some(BlsScalar::from(*self)));
+    }
+}
+
+impl PublicInputs for JubJubAffine {
+    fn append_scalars<E: Extend<BlsScalar>>(&self, scalars: &mut E) {
+        scalars.extend([self.get_x(), self.get_y()]);
+    }
+}
+
+impl PublicInputs for JubJubExtended {
+    fn append_scalars<E: Extend<BlsScalar>>(&self, scalars: &mut E) {
+        JubJubAffine::from(*self).append_scalars(scalars);
+    }
+}
+
+macro_rules! impl_public_inputs_for_integer {
+    ($($t:ty),*) => {
+        $(
+            impl PublicInputs for $t {
+                fn append_scalars<E: Extend<BlsScalar>>(
+                    &self,
+                    scalars: &mut E,
+                ) {
+                    scalars.extend(Some(BlsScalar::from(*self as u64)));
+                }
+            }
+        )*
+    };
+}
+
+impl_public_inputs_for_integer!(u8, u16, u32, u64);
+
+impl PublicInputs for PublicInputValue {
+    fn append_scalars<E: Extend<BlsScalar>>(&self, scalars: &mut E) {
+        scalars.extend(self.0.iter().copied());
+    }
+}
+
+impl PublicInputs for () {
+    fn append_scalars<E: Extend<BlsScalar>>(&self, _scalars: &mut E) {}
+}
+
+impl<T: PublicInputs> PublicInputs for [T] {
+    fn append_scalars<E: Extend<BlsScalar>>(&self, scalars: &mut E) {
+        self.iter().for_each(|input| input.append_scalars(scalars));
+    }
+}
+
+impl<T: PublicInputs, const N: usize> PublicInputs for [T; N] {
+    fn append_scalars<E: Extend<BlsScalar>>(&self, scalars: &mut E) {
+        self[..].append_scalars(scalars);
+    }
+}
+
+impl<T: PublicInputs> PublicInputs for Vec<T> {
+    fn append_scalars<E: Extend<BlsScalar>>(&self, scalars: &mut E) {
+        self[..].append_scalars(scalars);
+    }
+}
+
+/// Values that can be allocated in a [`TurboComposer`] as the inputs of a
+/// circuit, used by the [`Circuit`](derive@Circuit) derive macro.
+pub trait CircuitInput {
+    /// Witness the value is allocated as.
+    type Witness;
+
+    /// Appends the value as a private witness.
+    fn append_witness(&self, composer: &mut TurboComposer) -> Self::Witness;
+
+    /// Appends the value as a public input.
+    fn append_public(&self, composer: &mut TurboComposer) -> Self::Witness;
+
+    /// Appends the value as a constant of the circuit description.
+    fn append_constant(&self, composer: &mut TurboComposer) -> Self::Witness;
+}
+
+pub use dusk_plonk_derive::Circuit;
+
+impl CircuitInput for BlsScalar {
+    type Witness = Witness;
+
+    fn append_witness(&self, composer: &mut TurboComposer) -> Witness {
+        composer.append_witness(*self)
+    }
+
+    fn append_public(&self, composer: &mut TurboComposer) -> Witness {
+        composer.append_public(*self)
+    }
+
+    fn append_constant(&self, composer: &mut TurboComposer) -> Witness {
+        composer.append_constant(*self)
+    }
+}
+
+impl CircuitInput for JubJubScalar {
+    type Witness = Witness;
+
+    fn append_witness(&self, composer: &mut TurboComposer) -> Witness {
+        BlsScalar::from(*self).append_witness(composer)
+    }
+
+    fn append_public(&self, composer: &mut TurboComposer) -> Witness {
+        BlsScalar::from(*self).append_public(composer)
+    }
+
+    fn append_constant(&self, composer: &mut TurboComposer) -> Witness {
+        BlsScalar::from(*self).append_constant(composer)
+    }
+}
+
+macro_rules! impl_circuit_input_for_integer {
+    ($($t:ty),*) => {
+        $(
+            impl CircuitInput for $t {
+                type Witness = Witness;
+
+                fn append_witness(
+                    &self,
+                    composer: &mut TurboComposer,
+                ) -> Witness {
+                    BlsScalar::from(*self as u64).append_witness(composer)
+                }
+
+                fn append_public(
+                    &self,
+                    composer: &mut TurboComposer,
+                ) -> Witness {
+                    BlsScalar::from(*self as u64).append_public(composer)
+                }
+
+                fn append_constant(
+                    &self,
+                    composer: &mut TurboComposer,
+                ) -> Witness {
+                    BlsScalar::from(*self as u64).append_constant(composer)
+                }
+            }
+        )*
+    };
+}
+
+impl_circuit_input_for_integer!(u8, u16, u32, u64);
+
+impl CircuitInput for JubJubAffine {
+    type Witness = WitnessPoint;
+
+    fn append_witness(&self, composer: &mut TurboComposer) -> WitnessPoint {
+        composer.append_point(*self)
+    }
+
+    fn append_public(&self, composer: &mut TurboComposer) -> WitnessPoint {
+        composer.append_public_point(*self)
+    }
+
+    fn append_constant(&self, composer: &mut TurboComposer) -> WitnessPoint {
+        composer.append_constant_point(*self)
+    }
+}
+
+impl CircuitInput for JubJubExtended {
+    type Witness = WitnessPoint;
+
+    fn append_witness(&self, composer: &mut TurboComposer) -> WitnessPoint {
+        JubJubAffine::from(*self).append_witness(composer)
+    }
+
+    fn append_public(&self, composer: &mut TurboComposer) -> WitnessPoint {
+        JubJubAffine::from(*self).append_public(composer)
+    }
+
+    fn append_constant(&self, composer: &mut TurboComposer) -> WitnessPoint {
+        JubJubAffine::from(*self).append_constant(composer)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Decode, Encode)]
 /// Collection of structs/objects that the Verifier will use in order to
 /// de/serialize data needed for Circuit proof verification.
@@ -57,18 +286,21 @@ impl From<JubJubExtended> for PublicInputValue {
 pub struct VerifierData {
     key: VerifierKey,
     public_inputs_indexes: Vec<u32>,
+    fingerprint: [u8; 32],
 }
 
 impl VerifierData {
-    /// Creates a new `VerifierData` from a [`VerifierKey`] and the public
-    /// input positions of the circuit that it represents.
+    /// Creates a new `VerifierData` from a [`VerifierKey`], the public
+    /// input positions and the fingerprint of the circuit that it represents.
     pub const fn new(
         key: VerifierKey,
         public_inputs_indexes: Vec<u32>,
+        fingerprint: [u8; 32],
     ) -> Self {
         Self {
             key,
             public_inputs_indexes,
+            fingerprint,
         }
     }
 
@@ -77,23 +309,95 @@ impl VerifierData {
         &self.key
     }
 
-    /// Returns a reference to the contained Public Input positions.
+    /// Returns a reference to the contained Public Input positions, in
+    /// allocation order.
     pub fn public_inputs_indexes(&self) -> &[u32] {
         &self.public_inputs_indexes
     }
 
+    /// Returns the size the circuit was padded to when it was compiled.
+    pub const fn padded_gates(&self) -> u32 {
+        self.key.padded_gates()
+    }
+
+    /// Places the public inputs of the circuit, given in allocation order, at
+    /// their positions in the dense vector of public inputs checked by the
+    /// [`Verifier`].
+    ///
+    /// The public input positions are checked to be sorted, unique and
+    /// within the circuit, and there must be exactly one public input for
+    /// each of them.
+    pub fn dense_public_inputs(
+        &self,
+        public_inputs: &[BlsScalar],
+    ) -> Result<Vec<BlsScalar>, Error> {
+        // The verifier data may come from an untrusted source
+        self.check_public_inputs_indexes()?;
+
+        let pi_indexes = self.public_inputs_indexes();
+
+        if public_inputs.len() != pi_indexes.len() {
+            return Err(Error::PublicInputsLengthMismatch {
+                expected: pi_indexes.len(),
+                found: public_inputs.len(),
+            });
+        }
+
+        let mut dense_pi =
+            vec![BlsScalar::zero(); self.padded_gates() as usize];
+
+        public_inputs
+            .iter()
+            .zip(pi_indexes.iter().copied())
+            .for_each(|(value, pos)| {
+                dense_pi[pos as usize] = -value;
+            });
+
+        Ok(dense_pi)
+    }
+
+    /// Checks that the public input positions are sorted, unique and within
+    /// the circuit.
+    fn check_public_inputs_indexes(&self) -> Result<(), Error> {
+        let gates = self.padded_gates();
+
+        if let Some(&index) =
+            self.public_inputs_indexes.iter().find(|&&i| i >= gates)
+        {
+            return Err(Error::PublicInputIndexOutOfBounds { index });
+        }
+
+        self.public_inputs_indexes.windows(2).try_for_each(|pair| {
+            match pair[0].cmp(&pair[1]) {
+                Ordering::Less => Ok(()),
+                Ordering::Equal => {
+                    Err(Error::PublicInputIndexDuplicated { index: pair[0] })
+                }
+                Ordering::Greater => Err(Error::PublicInputIndexesUnsorted),
+            }
+        })
+    }
+
+    /// Returns the fingerprint of the shape of the circuit, as computed by
+    /// [`TurboComposer::fingerprint`] when it was compiled.
+    pub const fn fingerprint(&self) -> &[u8; 32] {
+        &self.fingerprint
+    }
+
     /// Deserializes the `VerifierData` into a vector of bytes.
     #[allow(unused_must_use)]
     pub fn to_var_bytes(&self) -> Vec<u8> {
         let mut buff = vec![
             0u8;
-            VerifierKey::SIZE
+            VerifierKey::<Kzg10>::SIZE
+                + self.fingerprint.len()
                 + u32::SIZE
                 + self.public_inputs_indexes.len() * u32::SIZE
         ];
         let mut writer = &mut buff[..];
 
         writer.write(&self.key.to_bytes());
+        writer.write(&self.fingerprint);
         writer.write(&(self.public_inputs_indexes.len() as u32).to_bytes());
         self.public_inputs_indexes.iter().copied().for_each(|pos| {
             // Omit the result since disk_bytes write can't fail here
@@ -105,8 +409,20 @@ impl VerifierData {
     }
 
     /// Serializes `VerifierData` from a slice of bytes.
+    ///
+    /// The public input positions are checked to be sorted, unique and
+    /// within the circuit.
     pub fn from_slice(mut buf: &[u8]) -> Result<Self, Error> {
         let key = VerifierKey::from_reader(&mut buf)?;
+
+        let mut fingerprint = [0u8; 32];
+        if buf.len() < fingerprint.len() {
+            return Err(Error::NotEnoughBytes);
+        }
+        let (a, b) = buf.split_at(fingerprint.len());
+        fingerprint.copy_from_slice(a);
+        buf = b;
+
         let pos_num = u32::from_reader(&mut buf)? as usize;
 
         let mut public_inputs_indexes = vec![];
@@ -114,10 +430,15 @@ impl VerifierData {
             public_inputs_indexes.push(u32::from_reader(&mut buf)?);
         }
 
-        Ok(Self {
+        let verifier_data = Self {
             key,
             public_inputs_indexes,
-        })
+            fingerprint,
+        };
+
+        verifier_data.check_public_inputs_indexes()?;
+
+        Ok(verifier_data)
     }
 }
 
@@ -148,8 +469,17 @@ impl VerifierData {
 ///     f: JubJubAffine,
 /// }
 ///
+/// // Public inputs of the circuit, in the order the gadget appends them
+/// #[derive(Debug, PublicInputs)]
+/// pub struct TestPublicInputs {
+///     c: BlsScalar,
+///     d: BlsScalar,
+///     f: JubJubAffine,
+/// }
+///
 /// impl Circuit for TestCircuit {
 ///     const CIRCUIT_ID: [u8; 32] = [0xff; 32];
+///     type PublicInputs = TestPublicInputs;
 ///     fn gadget(
 ///         &mut self,
 ///         composer: &mut TurboComposer,
@@ -194,12 +524,12 @@ impl VerifierData {
 ///         Ok(())
 ///     }
 ///
-///     fn public_inputs(&self) -> Vec<PublicInputValue> {
-///         vec![self.c.into(), self.d.into(), self.f.into()]
-///     }
-///
-///     fn padded_gates(&self) -> usize {
-///         1 << 11
+///     fn public_inputs(&self) -> TestPublicInputs {
+///         TestPublicInputs {
+///             c: self.c,
+///             d: self.d,
+///             f: self.f,
+///         }
 ///     }
 /// }
 ///
@@ -208,9 +538,10 @@ impl VerifierData {
 /// 0x32, 0x54, 0x06, 0xbc, 0xe5,
 /// ]);
 ///
-/// let pp = PublicParameters::setup(1 << 12, rng)?;
 /// // Initialize the circuit
 /// let mut circuit = TestCircuit::default();
+/// // Setup parameters big enough for the circuit
+/// let pp = PublicParameters::setup(circuit.min_srs_degree()?, rng)?;
 /// // Compile the circuit
 /// let (pk, vd) = circuit.compile(&pp)?;
 ///
@@ -231,14 +562,13 @@ impl VerifierData {
 /// }?;
 ///
 /// // Verifier POV
-/// let public_inputs: Vec<PublicInputValue> = vec![
-///     BlsScalar::from(25u64).into(),
-///     BlsScalar::from(100u64).into(),
-///     JubJubAffine::from(
+/// let public_inputs = TestPublicInputs {
+///     c: BlsScalar::from(25u64),
+///     d: BlsScalar::from(100u64),
+///     f: JubJubAffine::from(
 ///         dusk_jubjub::GENERATOR_EXTENDED * JubJubScalar::from(2u64),
-///     )
-///     .into(),
-/// ];
+///     ),
+/// };
 ///
 /// TestCircuit::verify(
 ///     &pp,
@@ -253,92 +583,158 @@ where
     Self: Sized,
 {
     /// Circuit identifier associated constant.
+    ///
+    /// The identifier is chosen by the implementor, or derived from the
+    /// layout of the struct by `#[derive(Circuit)]`, and isn't checked
+    /// against the gates of the circuit. The keys of a compiled circuit are
+    /// bound to its shape by [`ProverKey::fingerprint`] and
+    /// [`VerifierData::fingerprint`].
     const CIRCUIT_ID: [u8; 32];
 
+    /// Public inputs of the circuit, converted to scalars in the order the
+    /// gadget appends them.
+    type PublicInputs: PublicInputs;
+
     /// Gadget implementation used to fill the composer.
     fn gadget(&mut self, composer: &mut TurboComposer) -> Result<(), Error>;
 
     /// Compiles the circuit by using a function that returns a `Result`
     /// with the `ProverKey`, `VerifierKey` and the circuit size.
+    ///
+    /// The circuit is padded to the size returned by
+    /// [`TurboComposer::padded_gates`], which is recorded in both keys.
     fn compile(
         &mut self,
         pub_params: &PublicParameters,
     ) -> Result<(ProverKey, VerifierData), Error> {
-        // Setup PublicParams
-        let (ck, _) = pub_params.trim(self.padded_gates())?;
-
         // Generate & save `ProverKey` with some random values.
         let mut prover = Prover::new(b"CircuitCompilation");
 
         self.gadget(prover.composer_mut())?;
 
+        // Setup PublicParams
+        let (ck, _) = pub_params.trim(prover.composer_mut().padded_gates())?;
+
         let public_inputs_indexes =
             prover.composer_mut().public_input_indexes();
 
-        prover.preprocess(&ck)?;
-
-        // Generate & save `VerifierKey` with some random values.
-        let mut verifier = Verifier::new(b"CircuitCompilation");
-
-        self.gadget(verifier.composer_mut())?;
+        // The `VerifierKey` is computed in the same pass as the `ProverKey`
+        let verifier_key = prover.preprocess(&ck)?;
 
-        verifier.preprocess(&ck)?;
+        let prover_key = prover
+            .prover_key
+            .expect("Unexpected error. Missing ProverKey in compilation");
+        let fingerprint = *prover_key.fingerprint();
 
         Ok((
-            prover
-                .prover_key
-                .expect("Unexpected error. Missing ProverKey in compilation"),
-            VerifierData::new(
-                verifier.verifier_key.expect(
-                    "Unexpected error. Missing VerifierKey in compilation",
-                ),
-                public_inputs_indexes,
-            ),
+            prover_key,
+            VerifierData::new(verifier_key, public_inputs_indexes, fingerprint),
         ))
     }
 
     /// Generates a proof using the provided `CircuitInputs` & `ProverKey`
     /// instances.
+    ///
+    /// Returns [`Error::CircuitShapeMismatch`] if the gadget doesn't build
+    /// the same circuit it built when the `ProverKey` was compiled.
     fn prove(
         &mut self,
         pub_params: &PublicParameters,
         prover_key: &ProverKey,
         transcript_init: &'static [u8],
     ) -> Result<Proof, Error> {
-        let (ck, _) = pub_params.trim(self.padded_gates())?;
+        let (ck, _) = pub_params.trim(prover_key.padded_gates() as usize)?;
 
+        self.prove_with_commit_key(&ck, prover_key, transcript_init)
+    }
+
+    /// Generates a proof like [`Circuit::prove`], using a [`CommitKey`]
+    /// large enough for the `prover_key`, as the one returned by
+    /// [`PublicParameters::trim`] for [`ProverKey::padded_gates`].
+    ///
+    /// Both keys are only borrowed, so they can be shared by many proofs
+    /// built at the same time without being copied.
+    fn prove_with_commit_key(
+        &mut self,
+        commit_key: &CommitKey,
+        prover_key: &ProverKey,
+        transcript_init: &'static [u8],
+    ) -> Result<Proof, Error> {
         // New Prover instance
         let mut prover = Prover::new(transcript_init);
 
         // Fill witnesses for Prover
         self.gadget(prover.composer_mut())?;
 
-        // Add ProverKey to Prover
-        prover.prover_key = Some(prover_key.clone());
-        prover.prove(&ck)
+        // The witnesses must fill the circuit that was compiled
+        if &prover.composer_mut().fingerprint() != prover_key.fingerprint() {
+            return Err(Error::CircuitShapeMismatch);
+        }
+
+        prover.prove_with_preprocessed(commit_key, prover_key)
+    }
+
+    /// Generates a proof like [`Circuit::prove`], replaying the gadget in a
+    /// witness-only composer, as described in
+    /// [`TurboComposer::is_witness_only`].
+    ///
+    /// The selectors and the permutation of the circuit are taken from the
+    /// `prover_key` instead of being built again, which saves most of the
+    /// work of running the gadget on large circuits. The shape of the circuit
+    /// can't be fingerprinted without its selectors, so
+    /// [`Error::CircuitShapeMismatch`] is returned only if its number of
+    /// gates, the digest of its wires or its public input positions differ
+    /// from the ones recorded by the `prover_key`. A gadget that builds
+    /// another circuit differing only in its selectors, such as the constants
+    /// of its gates, produces a proof that doesn't verify.
+    ///
+    /// A gadget that optimizes its circuit can't be proven this way, and
+    /// fails with [`Error::WitnessOnlyOptimization`].
+    fn prove_witness_only(
+        &mut self,
+        pub_params: &PublicParameters,
+        prover_key: &ProverKey,
+        transcript_init: &'static [u8],
+    ) -> Result<Proof, Error> {
+        let (ck, _) = pub_params.trim(prover_key.padded_gates() as usize)?;
+
+        self.prove_witness_only_with_commit_key(
+            &ck,
+            prover_key,
+            transcript_init,
+        )
+    }
+
+    /// Generates a proof like [`Circuit::prove_witness_only`], using a
+    /// [`CommitKey`] large enough for the `prover_key`.
+    fn prove_witness_only_with_commit_key(
+        &mut self,
+        commit_key: &CommitKey,
+        prover_key: &ProverKey,
+        transcript_init: &'static [u8],
+    ) -> Result<Proof, Error> {
+        let mut prover = Prover::witness_only(transcript_init);
+
+        // Fill witnesses for Prover
+        self.gadget(prover.composer_mut())?;
+
+        prover.prove_with_preprocessed(commit_key, prover_key)
     }
 
     /// Verify the provided proof for the compiled verifier data
+    ///
+    /// The public input positions of `verifier_data` are checked to be
+    /// sorted, unique and within the circuit, and there must be exactly one
+    /// public input scalar for each of them.
     fn verify(
         pub_params: &PublicParameters,
         verifier_data: &VerifierData,
         proof: &Proof,
-        public_inputs: &[PublicInputValue],
+        public_inputs: &Self::PublicInputs,
         transcript_init: &'static [u8],
     ) -> Result<(), Error> {
-        let gates = verifier_data.key().padded_gates();
-        let pi_indexes = verifier_data.public_inputs_indexes();
-
-        let mut dense_pi = vec![BlsScalar::zero(); gates as usize];
-
-        public_inputs
-            .iter()
-            .map(|pi| pi.0.clone())
-            .flatten()
-            .zip(pi_indexes.iter().cloned())
-            .for_each(|(value, pos)| {
-                dense_pi[pos as usize] = -value;
-            });
+        let dense_pi =
+            verifier_data.dense_public_inputs(&public_inputs.to_scalars())?;
 
         let mut verifier = Verifier::new(transcript_init);
 
@@ -349,9 +745,18 @@ where
         verifier.verify(proof, opening_key, &dense_pi)
     }
 
-    /// Return the list of public inputs generated by the gadget
-    fn public_inputs(&self) -> Vec<PublicInputValue>;
+    /// Return the public inputs generated by the gadget
+    fn public_inputs(&self) -> Self::PublicInputs;
 
-    /// Returns the Circuit size padded to the next power of two.
-    fn padded_gates(&self) -> usize;
+    /// Returns the minimum degree of the [`PublicParameters`] needed to
+    /// compile the circuit and prove it.
+    ///
+    /// The circuit is measured by running its gadget, so it must succeed with
+    /// the current values of the circuit, as it must when compiling it.
+    fn min_srs_degree(&mut self) -> Result<usize, Error> {
+        let mut composer = TurboComposer::new();
+        self.gadget(&mut composer)?;
+
+        Ok(composer.padded_gates())
+    }
 }
@@ -0,0 +1,167 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use crate::circuit::Circuit;
+use crate::commitment_scheme::{CommitKey, PublicParameters};
+use crate::error::Error;
+use crate::proof_system::{Proof, ProverKey};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// Proves circuits compiled to the same [`ProverKey`] on a set of worker
+/// threads.
+///
+/// The [`ProverKey`] and the [`CommitKey`] are held behind [`Arc`]s and only
+/// borrowed by every proof, so they're never copied no matter how many
+/// proofs are built at the same time. A pool is cheap to clone, and its
+/// clones share the same keys.
+#[derive(Debug, Clone)]
+pub struct ProverPool {
+    commit_key: Arc<CommitKey>,
+    prover_key: Arc<ProverKey>,
+    label: &'static [u8],
+    threads: usize,
+    witness_only: bool,
+}
+
+impl ProverPool {
+    /// Creates a pool proving with the shared `commit_key` and `prover_key`
+    /// on up to `threads` worker threads, keying the transcript of every
+    /// proof with `label`.
+    ///
+    /// The `commit_key` must be large enough for the `prover_key`, as
+    /// required by [`Circuit::prove_with_commit_key`].
+    pub fn new(
+        commit_key: Arc<CommitKey>,
+        prover_key: Arc<ProverKey>,
+        label: &'static [u8],
+        threads: usize,
+    ) -> Self {
+        Self {
+            commit_key,
+            prover_key,
+            label,
+            threads: threads.max(1),
+            witness_only: false,
+        }
+    }
+
+    /// Creates a pool trimming the `pub_params` once to the size of the
+    /// `prover_key`, as [`Circuit::prove`] does for every proof.
+    pub fn from_public_parameters(
+        pub_params: &PublicParameters,
+        prover_key: Arc<ProverKey>,
+        label: &'static [u8],
+        threads: usize,
+    ) -> Result<Self, Error> {
+        let (commit_key, _) =
+            pub_params.trim(prover_key.padded_gates() as usize)?;
+
+        Ok(Self::new(Arc::new(commit_key), prover_key, label, threads))
+    }
+
+    /// Returns the [`ProverKey`] shared by the proofs of the pool.
+    pub fn prover_key(&self) -> &Arc<ProverKey> {
+        &self.prover_key
+    }
+
+    /// Returns the [`CommitKey`] shared by the proofs of the pool.
+    pub fn commit_key(&self) -> &Arc<CommitKey> {
+        &self.commit_key
+    }
+
+    /// Returns the maximum number of worker threads of the pool.
+    pub const fn threads(&self) -> usize {
+        self.threads
+    }
+
+    /// Sets whether the circuits are proven by replaying their gadgets in a
+    /// witness-only composer, as [`Circuit::prove_witness_only`] does,
+    /// instead of checking their shape as [`Circuit::prove`] does.
+    pub fn set_witness_only(&mut self, witness_only: bool) {
+        self.witness_only = witness_only;
+    }
+
+    /// Proves a single `circuit` on the calling thread.
+    pub fn prove<C: Circuit>(&self, circuit: &mut C) -> Result<Proof, Error> {
+        if self.witness_only {
+            circuit.prove_witness_only_with_commit_key(
+                &self.commit_key,
+                &self.prover_key,
+                self.label,
+            )
+        } else {
+            circuit.prove_with_commit_key(
+                &self.commit_key,
+                &self.prover_key,
+                self.label,
+            )
+        }
+    }
+
+    /// Proves all the `circuits` in parallel, returning the result of every
+    /// proof in the same order as the circuits.
+    ///
+    /// The circuits are handed to the worker threads as they become idle, so
+    /// circuits that take longer to prove don't hold the others back.
+    ///
+    /// # Panics
+    ///
+    /// Panics if proving any of the circuits panics.
+    pub fn prove_all<C>(&self, circuits: Vec<C>) -> Vec<Result<Proof, Error>>
+    where
+        C: Circuit + Send + 'static,
+    {
+        let len = circuits.len();
+        let threads = self.threads.min(len);
+
+        let queue = Arc::new(Mutex::new(circuits.into_iter().enumerate()));
+
+        let workers: Vec<_> = (0..threads)
+            .map(|_| {
+                let pool = self.clone();
+                let queue = Arc::clone(&queue);
+
+                thread::spawn(move || {
+                    let mut proofs = Vec::new();
+
+                    // The lock is released before proving the circuit
+                    while let Some((i, mut circuit)) = Self::next(&*queue) {
+                        proofs.push((i, pool.prove(&mut circuit)));
+                    }
+
+                    proofs
+                })
+            })
+            .collect();
+
+        let mut proofs: Vec<Option<Result<Proof, Error>>> =
+            (0..len).map(|_| None).collect();
+
+        workers.into_iter().for_each(|worker| {
+            let worker_proofs = worker
+                .join()
+                .unwrap_or_else(|panic| std::panic::resume_unwind(panic));
+
+            worker_proofs
+                .into_iter()
+                .for_each(|(i, proof)| proofs[i] = Some(proof));
+        });
+
+        proofs
+            .into_iter()
+            .map(|proof| proof.expect("every circuit is proven by a worker"))
+            .collect()
+    }
+
+    /// Takes the next circuit to prove out of the `queue`.
+    fn next<I: Iterator>(queue: &Mutex<I>) -> Option<I::Item> {
+        queue
+            .lock()
+            .expect("a worker panicked while taking a circuit")
+            .next()
+    }
+}
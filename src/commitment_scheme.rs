@@ -14,10 +14,19 @@
 //! commitment schemes without modification, one would need to remove the
 //! lineariser
 
+mod ipa;
 mod kzg10;
+mod scheme;
 
-pub(crate) use kzg10::Commitment;
+pub use crate::fft::polynomial::Polynomial;
+pub use kzg10::{Commitment, OpeningProof};
+pub use scheme::{CommitmentScheme, Opening};
 
 pub(crate) use kzg10::AggregateProof;
 
-pub use kzg10::{CommitKey, OpeningKey, PublicParameters};
+pub use kzg10::{
+    CommitKey, ContributionProof, Kzg10, OpeningKey, PointEncoding,
+    PublicParameters,
+};
+
+pub use ipa::{Ipa, IpaParameters, IpaProof};
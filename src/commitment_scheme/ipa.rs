@@ -0,0 +1,516 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Implementation of a transparent polynomial commitment scheme based on the
+//! inner product argument of Bulletproofs, as used in Halo.
+//!
+//! A polynomial is committed to as a Pedersen vector commitment to its
+//! coefficients. Its evaluation at a point `z` is the inner product of the
+//! coefficients with the powers of `z`, which the opening proves in a
+//! logarithmic number of rounds. The generators are derived by hashing to
+//! G1, so no trusted setup is required, at the cost of opening proofs
+//! logarithmic in size and a verification linear in the degree.
+
+use super::{Commitment, CommitmentScheme, Opening, Polynomial};
+use crate::error::Error;
+use crate::transcript::TranscriptProtocol;
+use crate::util::{batch_inversion, powers_of};
+use dusk_bls12_381::{
+    multiscalar_mul::msm_variable_base, BlsScalar, G1Affine, G1Projective,
+};
+use dusk_bytes::{DeserializableSlice, Serializable};
+use merlin::Transcript;
+use parity_scale_codec::{Decode, Encode};
+use sp_std::vec;
+use sp_std::vec::Vec;
+
+/// Multiplier clearing the cofactor of a point of the BLS12-381 G1 curve,
+/// `1 - x` for the curve parameter `x`.
+const G1_COFACTOR_CLEARING: u64 = 0xd201_0000_0001_0001;
+
+/// Label of the transcript the generators are derived from.
+const GENERATORS_LABEL: &[u8] = b"dusk-plonk-ipa-generators";
+
+/// The inner product argument commitment scheme, using transparent
+/// [`IpaParameters`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Ipa;
+
+/// The generators used both to commit to polynomials and to verify their
+/// openings with the [`Ipa`] scheme.
+///
+/// They can be derived by anyone with [`IpaParameters::setup`] and a
+/// smaller set of parameters is always a prefix of a larger one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IpaParameters {
+    /// Generators the coefficients of the polynomials are committed with.
+    pub(crate) generators: Vec<G1Affine>,
+    /// Generator binding the evaluation in an opening.
+    pub(crate) u: G1Affine,
+}
+
+impl IpaParameters {
+    /// Derives the parameters needed to commit to polynomials of degree up
+    /// to `max_degree`.
+    ///
+    /// The number of generators is rounded up to the next power of two, so
+    /// the parameters may support a larger degree than requested.
+    pub fn setup(max_degree: usize) -> Result<IpaParameters, Error> {
+        if max_degree < 1 {
+            return Err(Error::DegreeIsZero);
+        }
+        let size = (max_degree + 1).next_power_of_two();
+
+        let mut transcript = Transcript::new(GENERATORS_LABEL);
+        let u = hash_to_g1(&mut transcript);
+        let generators =
+            (0..size).map(|_| hash_to_g1(&mut transcript)).collect();
+
+        Ok(IpaParameters { generators, u })
+    }
+
+    /// Returns the maximum degree of the polynomials these parameters can
+    /// commit to.
+    pub fn max_degree(&self) -> usize {
+        self.generators.len() - 1
+    }
+
+    /// Trims the parameters down to the ones needed to commit to
+    /// polynomials of degree up to `truncated_degree`.
+    pub fn trim(
+        &self,
+        truncated_degree: usize,
+    ) -> Result<IpaParameters, Error> {
+        if truncated_degree == 0 {
+            return Err(Error::TruncatedDegreeIsZero);
+        }
+        let size = (truncated_degree + 1).next_power_of_two();
+        if size > self.generators.len() {
+            return Err(Error::TruncatedDegreeTooLarge);
+        }
+
+        Ok(IpaParameters {
+            generators: self.generators[..size].to_vec(),
+            u: self.u,
+        })
+    }
+
+    /// Commits to a [`Polynomial`] returning the corresponding
+    /// [`Commitment`].
+    ///
+    /// Returns an error if the polynomial's degree is more than the max
+    /// degree of the parameters.
+    pub fn commit(&self, polynomial: &Polynomial) -> Result<Commitment, Error> {
+        if polynomial.len() > self.generators.len() {
+            return Err(Error::PolynomialDegreeTooLarge);
+        }
+        if polynomial.is_zero() {
+            return Ok(Commitment::default());
+        }
+
+        Ok(Commitment::from(msm_variable_base(
+            &self.generators[..polynomial.len()],
+            &polynomial.coeffs,
+        )))
+    }
+
+    /// Number of folding rounds of an opening with these parameters.
+    fn rounds(&self) -> usize {
+        self.generators.len().trailing_zeros() as usize
+    }
+
+    /// Binds the aggregated commitment, point and evaluation of an opening
+    /// to `transcript`, returning the generator the evaluation is committed
+    /// to with.
+    fn bind_opening(
+        &self,
+        transcript: &mut Transcript,
+        commitment: &Commitment,
+        point: &BlsScalar,
+        evaluation: &BlsScalar,
+    ) -> G1Affine {
+        transcript.append_commitment(b"ipa_commitment", commitment);
+        transcript.append_scalar(b"ipa_point", point);
+        transcript.append_scalar(b"ipa_eval", evaluation);
+        let challenge = transcript.challenge_scalar(b"ipa_u");
+
+        G1Affine::from(self.u * challenge)
+    }
+
+    fn open(
+        &self,
+        polynomials: &[Polynomial],
+        point: &BlsScalar,
+        transcript: &mut Transcript,
+    ) -> Result<IpaProof, Error> {
+        if polynomials.is_empty() {
+            return Err(Error::OpeningProofMismatch);
+        }
+
+        let challenge = transcript.challenge_scalar(b"aggregate_witness");
+        let powers = powers_of(&challenge, polynomials.len() - 1);
+        let aggregate: Polynomial = polynomials
+            .iter()
+            .zip(powers.iter())
+            .map(|(poly, challenge)| poly * challenge)
+            .sum();
+
+        let commitment = self.commit(&aggregate)?;
+        let evaluation = aggregate.evaluate(point);
+        let u = self.bind_opening(transcript, &commitment, point, &evaluation);
+
+        let size = self.generators.len();
+        let mut a = aggregate.coeffs;
+        a.resize(size, BlsScalar::zero());
+        let mut b = powers_of(point, size - 1);
+        let mut g = self.generators.clone();
+
+        let mut l = Vec::with_capacity(self.rounds());
+        let mut r = Vec::with_capacity(self.rounds());
+
+        while a.len() > 1 {
+            let half = a.len() / 2;
+            let (a_lo, a_hi) = a.split_at(half);
+            let (b_lo, b_hi) = b.split_at(half);
+            let (g_lo, g_hi) = g.split_at(half);
+
+            let l_i = G1Affine::from(
+                msm_variable_base(g_hi, a_lo) + u * inner_product(a_lo, b_hi),
+            );
+            let r_i = G1Affine::from(
+                msm_variable_base(g_lo, a_hi) + u * inner_product(a_hi, b_lo),
+            );
+
+            let x = round_challenge(transcript, &l_i, &r_i);
+            let x_inv = x.invert().unwrap();
+
+            let a_next: Vec<BlsScalar> = a_lo
+                .iter()
+                .zip(a_hi)
+                .map(|(lo, hi)| lo * x + hi * x_inv)
+                .collect();
+            let b_next: Vec<BlsScalar> = b_lo
+                .iter()
+                .zip(b_hi)
+                .map(|(lo, hi)| lo * x_inv + hi * x)
+                .collect();
+            let g_next: Vec<G1Projective> = g_lo
+                .iter()
+                .zip(g_hi)
+                .map(|(lo, hi)| lo * x_inv + hi * x)
+                .collect();
+
+            a = a_next;
+            b = b_next;
+            g = vec![G1Affine::identity(); half];
+            G1Projective::batch_normalize(&g_next, &mut g);
+
+            l.push(l_i);
+            r.push(r_i);
+        }
+
+        Ok(IpaProof { l, r, a: a[0] })
+    }
+
+    fn verify(
+        &self,
+        opening: &Opening<'_, Ipa>,
+        transcript: &mut Transcript,
+    ) -> Result<(), Error> {
+        let proof = opening.proof;
+        if opening.commitments.is_empty()
+            || opening.commitments.len() != opening.evaluations.len()
+        {
+            return Err(Error::OpeningProofMismatch);
+        }
+        let rounds = self.rounds();
+        if proof.l.len() != rounds || proof.r.len() != rounds {
+            return Err(Error::InnerProductRoundsMismatch);
+        }
+
+        let challenge = transcript.challenge_scalar(b"aggregate_witness");
+        let powers = powers_of(&challenge, opening.commitments.len() - 1);
+        let points: Vec<G1Affine> =
+            opening.commitments.iter().map(|comm| comm.0).collect();
+        let commitment = Commitment::from(msm_variable_base(&points, &powers));
+        let evaluation: BlsScalar = opening
+            .evaluations
+            .iter()
+            .zip(powers.iter())
+            .map(|(eval, challenge)| eval * challenge)
+            .sum();
+        let u = self.bind_opening(
+            transcript,
+            &commitment,
+            &opening.point,
+            &evaluation,
+        );
+
+        let challenges: Vec<BlsScalar> = proof
+            .l
+            .iter()
+            .zip(proof.r.iter())
+            .map(|(l_i, r_i)| round_challenge(transcript, l_i, r_i))
+            .collect();
+        let mut challenges_inv = challenges.clone();
+        batch_inversion(&mut challenges_inv);
+
+        // Fold the commitment with the cross terms of every round
+        let mut folded = G1Projective::from(commitment.0) + u * evaluation;
+        for (((l_i, r_i), x), x_inv) in proof
+            .l
+            .iter()
+            .zip(proof.r.iter())
+            .zip(challenges.iter())
+            .zip(challenges_inv.iter())
+        {
+            folded += l_i * x.square() + r_i * x_inv.square();
+        }
+
+        // Coefficients of the generators in the folded one. The first round
+        // splits on the most significant bit of the index, so the vector is
+        // built starting from the last round.
+        let mut s = vec![BlsScalar::one()];
+        for (x, x_inv) in challenges.iter().zip(challenges_inv.iter()).rev() {
+            let lo = s.iter().map(|s_i| s_i * x_inv);
+            let hi = s.iter().map(|s_i| s_i * x);
+            s = lo.chain(hi).collect();
+        }
+        let g = msm_variable_base(&self.generators, &s);
+
+        // The folded powers of the point keep their structure, each round
+        // scaling them by `x^-1 + x z^half`
+        let mut half = self.generators.len();
+        let b: BlsScalar = challenges
+            .iter()
+            .zip(challenges_inv.iter())
+            .map(|(x, x_inv)| {
+                half /= 2;
+                x_inv + x * opening.point.pow(&[half as u64, 0, 0, 0])
+            })
+            .product();
+
+        if folded != g * proof.a + u * (proof.a * b) {
+            return Err(Error::InnerProductCheckFailure);
+        }
+        Ok(())
+    }
+}
+
+/// Proof of the opening of a polynomial with the [`Ipa`] scheme.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Encode, Decode)]
+pub struct IpaProof {
+    /// Left cross terms of each round.
+    pub(crate) l: Vec<G1Affine>,
+    /// Right cross terms of each round.
+    pub(crate) r: Vec<G1Affine>,
+    /// The folded coefficient of the polynomial.
+    pub(crate) a: BlsScalar,
+}
+
+impl IpaProof {
+    /// Serializes the proof as its number of rounds, followed by the cross
+    /// terms of each round and the folded coefficient.
+    pub fn to_var_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(
+            u64::SIZE + 2 * self.l.len() * G1Affine::SIZE + BlsScalar::SIZE,
+        );
+        bytes.extend_from_slice(&(self.l.len() as u64).to_bytes());
+        self.l.iter().zip(self.r.iter()).for_each(|(l_i, r_i)| {
+            bytes.extend_from_slice(&l_i.to_bytes());
+            bytes.extend_from_slice(&r_i.to_bytes());
+        });
+        bytes.extend_from_slice(&self.a.to_bytes());
+
+        bytes
+    }
+
+    /// Deserializes a proof from its start in `buf`, advancing `buf` past
+    /// it.
+    pub fn from_reader(buf: &mut &[u8]) -> Result<IpaProof, Error> {
+        let rounds = u64::from_reader(buf)? as usize;
+        if rounds >= 64 {
+            return Err(Error::InnerProductRoundsMismatch);
+        }
+
+        let mut l = Vec::with_capacity(rounds);
+        let mut r = Vec::with_capacity(rounds);
+        for _ in 0..rounds {
+            l.push(G1Affine::from_reader(buf)?);
+            r.push(G1Affine::from_reader(buf)?);
+        }
+        let a = BlsScalar::from_reader(buf)?;
+
+        Ok(IpaProof { l, r, a })
+    }
+}
+
+impl CommitmentScheme for Ipa {
+    type CommitKey = IpaParameters;
+    type OpeningKey = IpaParameters;
+    type OpeningProof = IpaProof;
+
+    fn commit(
+        commit_key: &IpaParameters,
+        polynomial: &Polynomial,
+    ) -> Result<Commitment, Error> {
+        commit_key.commit(polynomial)
+    }
+
+    fn open(
+        commit_key: &IpaParameters,
+        polynomials: &[Polynomial],
+        point: &BlsScalar,
+        transcript: &mut Transcript,
+    ) -> Result<IpaProof, Error> {
+        commit_key.open(polynomials, point, transcript)
+    }
+
+    fn verify(
+        opening_key: &IpaParameters,
+        openings: &[Opening<'_, Self>],
+        transcript: &mut Transcript,
+    ) -> Result<(), Error> {
+        openings
+            .iter()
+            .try_for_each(|opening| opening_key.verify(opening, transcript))
+    }
+
+    fn opening_proof_to_var_bytes(proof: &IpaProof) -> Vec<u8> {
+        proof.to_var_bytes()
+    }
+
+    fn opening_proof_from_reader(buf: &mut &[u8]) -> Result<IpaProof, Error> {
+        IpaProof::from_reader(buf)
+    }
+}
+
+fn inner_product(a: &[BlsScalar], b: &[BlsScalar]) -> BlsScalar {
+    a.iter().zip(b.iter()).map(|(a, b)| a * b).sum()
+}
+
+fn round_challenge(
+    transcript: &mut Transcript,
+    l: &G1Affine,
+    r: &G1Affine,
+) -> BlsScalar {
+    transcript.append_message(b"ipa_l", &l.to_bytes());
+    transcript.append_message(b"ipa_r", &r.to_bytes());
+    transcript.challenge_scalar(b"ipa_round")
+}
+
+/// Derives a point of the prime order subgroup of G1 from `transcript`, with
+/// an unknown discrete logarithm with respect to any other derived point.
+///
+/// Candidate x-coordinates are drawn until one is on the curve, then the
+/// cofactor of the resulting point is cleared.
+fn hash_to_g1(transcript: &mut Transcript) -> G1Affine {
+    loop {
+        let mut bytes = [0u8; G1Affine::SIZE];
+        transcript.challenge_bytes(b"point", &mut bytes);
+        // Set the compression flag and clear the infinity flag, keeping the
+        // sign flag as drawn
+        bytes[0] = (bytes[0] & 0x3f) | 0x80;
+
+        let point: Option<G1Affine> =
+            G1Affine::from_compressed_unchecked(&bytes).into();
+        if let Some(point) = point {
+            let point =
+                G1Affine::from(point * BlsScalar::from(G1_COFACTOR_CLEARING));
+            if !bool::from(point.is_identity()) {
+                return point;
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand_core::OsRng;
+
+    fn random_poly(degree: usize) -> Polynomial {
+        Polynomial::from_coefficients_vec(
+            (0..=degree)
+                .map(|_| BlsScalar::random(&mut OsRng))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_generators_are_a_prefix() {
+        let params = IpaParameters::setup(31).unwrap();
+        let small = IpaParameters::setup(7).unwrap();
+
+        assert_eq!(params.max_degree(), 31);
+        assert_eq!(params.trim(7).unwrap(), small);
+        assert!(params
+            .generators
+            .iter()
+            .all(|g| bool::from(g.is_torsion_free())));
+    }
+
+    #[test]
+    fn test_open_and_verify() {
+        let params = IpaParameters::setup(31).unwrap();
+        let polys: Vec<Polynomial> = (0..4).map(|_| random_poly(25)).collect();
+        let point = BlsScalar::random(&mut OsRng);
+
+        let commitments: Vec<Commitment> = polys
+            .iter()
+            .map(|poly| params.commit(poly).unwrap())
+            .collect();
+        let mut evaluations: Vec<BlsScalar> =
+            polys.iter().map(|poly| poly.evaluate(&point)).collect();
+
+        let proof = Ipa::open(
+            &params,
+            &polys,
+            &point,
+            &mut Transcript::new(b"ipa-test"),
+        )
+        .unwrap();
+        assert_eq!(proof.l.len(), 5);
+
+        let opening = Opening {
+            point,
+            commitments: &commitments,
+            evaluations: &evaluations,
+            proof: &proof,
+        };
+        Ipa::verify(&params, &[opening], &mut Transcript::new(b"ipa-test"))
+            .expect("The opening should verify");
+
+        evaluations[2] += BlsScalar::one();
+        let opening = Opening {
+            point,
+            commitments: &commitments,
+            evaluations: &evaluations,
+            proof: &proof,
+        };
+        assert!(matches!(
+            Ipa::verify(&params, &[opening], &mut Transcript::new(b"ipa-test")),
+            Err(Error::InnerProductCheckFailure)
+        ));
+    }
+
+    #[test]
+    fn test_proof_serialization() {
+        let params = IpaParameters::setup(15).unwrap();
+        let poly = random_poly(15);
+        let point = BlsScalar::random(&mut OsRng);
+
+        let proof = params
+            .open(&[poly], &point, &mut Transcript::new(b"ipa-test"))
+            .unwrap();
+        let bytes = proof.to_var_bytes();
+        let mut buf = &bytes[..];
+
+        assert_eq!(IpaProof::from_reader(&mut buf).unwrap(), proof);
+        assert!(buf.is_empty());
+    }
+}
@@ -12,9 +12,12 @@ pub mod srs;
 pub(crate) use proof::AggregateProof;
 
 pub use key::{CommitKey, OpeningKey};
-pub use srs::PublicParameters;
+pub use srs::{ContributionProof, PointEncoding, PublicParameters};
 
 pub(crate) mod commitment;
 pub(crate) mod proof;
+mod scheme;
 
-pub(crate) use commitment::Commitment;
+pub use commitment::Commitment;
+pub use proof::OpeningProof;
+pub use scheme::Kzg10;
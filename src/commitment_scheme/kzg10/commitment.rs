@@ -12,7 +12,7 @@ use parity_scale_codec::{Decode, Encode};
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Decode, Encode)]
 /// Holds a commitment to a polynomial in a form of a [`G1Affine`]-bls12_381
 /// point.
-pub(crate) struct Commitment(
+pub struct Commitment(
     /// The commitment is a group element.
     pub(crate) G1Affine,
 );
@@ -7,7 +7,8 @@
 //! Key module contains the utilities and data structures
 //! that support the generation and usage of Commit and
 //! Opening keys.
-use super::{proof::Proof, Commitment};
+use super::proof::{opening_transcript, AggregateProof, OpeningProof, Proof};
+use super::Commitment;
 use crate::{
     error::Error, fft::Polynomial, transcript::TranscriptProtocol, util,
 };
@@ -167,33 +168,32 @@ impl CommitKey {
         }
     }
 
-    /// Checks whether the polynomial we are committing to:
-    /// - Has zero degree
-    /// - Has a degree which is more than the max supported degree
-    ///
-    /// Returns an error if any of the above conditions are true.
+    /// Checks whether the polynomial we are committing to has a degree which
+    /// is more than the max supported degree, returning an error if so.
     fn check_commit_degree_is_within_bounds(
         &self,
         poly_degree: usize,
     ) -> Result<(), Error> {
-        match (poly_degree == 0, poly_degree > self.max_degree()) {
-            (true, _) => Err(Error::PolynomialDegreeIsZero),
-            (false, true) => Err(Error::PolynomialDegreeTooLarge),
-            (false, false) => Ok(()),
+        match poly_degree > self.max_degree() {
+            true => Err(Error::PolynomialDegreeTooLarge),
+            false => Ok(()),
         }
     }
 
     /// Commits to a [`Polynomial`] returning the corresponding [`Commitment`].
     ///
-    /// Returns an error if the polynomial's degree is more than the max degree
-    /// of the commit key.
-    pub(crate) fn commit(
-        &self,
-        polynomial: &Polynomial,
-    ) -> Result<Commitment, Error> {
+    /// The commitment to the zero polynomial is the identity point, so
+    /// circuits that don't use some of the selectors can still be committed
+    /// to. Returns an error if the polynomial's degree is more than the max
+    /// degree of the commit key.
+    pub fn commit(&self, polynomial: &Polynomial) -> Result<Commitment, Error> {
         // Check whether we can safely commit to this polynomial
         self.check_commit_degree_is_within_bounds(polynomial.degree())?;
 
+        if polynomial.is_zero() {
+            return Ok(Commitment::default());
+        }
+
         // Compute commitment
         Ok(Commitment::from(msm_variable_base(
             &self.powers_of_g,
@@ -223,6 +223,44 @@ impl CommitKey {
             .sum();
         numerator.ruffini(*point)
     }
+
+    /// Opens the given [`Polynomial`]s at `point`, returning an
+    /// [`OpeningProof`] of their evaluations there.
+    ///
+    /// The polynomials are aggregated with a challenge bound to their
+    /// commitments, the point and the evaluations, so a single witness is
+    /// committed to regardless of how many polynomials are opened.
+    ///
+    /// Returns an error if no polynomial is given or if any of the
+    /// polynomials cannot be committed to with this key.
+    pub fn open(
+        &self,
+        polynomials: &[Polynomial],
+        point: &BlsScalar,
+    ) -> Result<OpeningProof, Error> {
+        if polynomials.is_empty() {
+            return Err(Error::OpeningProofMismatch);
+        }
+
+        let commitments = polynomials
+            .iter()
+            .map(|poly| self.commit(poly))
+            .collect::<Result<Vec<_>, _>>()?;
+        let evaluations: Vec<BlsScalar> = polynomials
+            .iter()
+            .map(|poly| poly.evaluate(point))
+            .collect();
+
+        let mut transcript =
+            opening_transcript(&commitments, point, &evaluations);
+        let witness_poly =
+            self.compute_aggregate_witness(polynomials, point, &mut transcript);
+
+        Ok(OpeningProof {
+            witness: self.commit(&witness_poly)?,
+            evaluations,
+        })
+    }
 }
 
 /// Opening Key is used to verify opening proofs made about a committed
@@ -327,6 +365,67 @@ impl OpeningKey {
         };
         Ok(())
     }
+
+    /// Verifies an [`OpeningProof`] of the polynomials committed to by
+    /// `commitments` at `point`.
+    pub fn verify(
+        &self,
+        commitments: &[Commitment],
+        point: &BlsScalar,
+        proof: &OpeningProof,
+    ) -> Result<(), Error> {
+        self.batch_verify(&[(commitments, *point, proof)])
+    }
+
+    /// Verifies several [`OpeningProof`]s, each given with the commitments
+    /// to its polynomials and the point they were opened at, with a single
+    /// pairing check.
+    pub fn batch_verify(
+        &self,
+        openings: &[(&[Commitment], BlsScalar, &OpeningProof)],
+    ) -> Result<(), Error> {
+        if openings.is_empty() {
+            return Ok(());
+        }
+
+        let mut transcript = Transcript::new(b"dusk-plonk-kzg10-batch");
+        let mut points = Vec::with_capacity(openings.len());
+        let mut proofs = Vec::with_capacity(openings.len());
+
+        for (commitments, point, proof) in openings {
+            if commitments.is_empty()
+                || commitments.len() != proof.evaluations.len()
+            {
+                return Err(Error::OpeningProofMismatch);
+            }
+
+            let aggregate_proof = AggregateProof {
+                commitment_to_witness: proof.witness,
+                evaluated_points: proof.evaluations.clone(),
+                commitments_to_polynomials: commitments.to_vec(),
+            };
+            let flattened_proof = aggregate_proof.flatten(
+                &mut opening_transcript(commitments, point, &proof.evaluations),
+            );
+
+            transcript.append_scalar(b"point", point);
+            transcript.append_commitment(
+                b"commitment",
+                &flattened_proof.commitment_to_polynomial,
+            );
+            transcript
+                .append_scalar(b"evaluation", &flattened_proof.evaluated_point);
+            transcript.append_commitment(
+                b"witness",
+                &flattened_proof.commitment_to_witness,
+            );
+
+            points.push(*point);
+            proofs.push(flattened_proof);
+        }
+
+        self.batch_check(&points, &proofs, &mut transcript)
+    }
 }
 
 #[cfg(feature = "std")]
@@ -444,6 +543,29 @@ mod test {
         Ok(())
     }
     #[test]
+    fn test_commit_constant() -> Result<(), Error> {
+        let (ck, _) = setup_test(7)?;
+
+        // The zero polynomial commits to the identity
+        let zero =
+            Polynomial::from_coefficients_vec(vec![BlsScalar::zero(); 4]);
+        assert_eq!(ck.commit(&zero)?, Commitment::default());
+
+        let constant = BlsScalar::from(7);
+        let poly = Polynomial::from_coefficients_vec(vec![constant]);
+        assert_eq!(
+            ck.commit(&poly)?,
+            Commitment::from(ck.powers_of_g[0] * constant)
+        );
+
+        let poly = Polynomial::rand(8, &mut OsRng);
+        assert!(matches!(
+            ck.commit(&poly),
+            Err(Error::PolynomialDegreeTooLarge)
+        ));
+        Ok(())
+    }
+    #[test]
     fn test_batch_verification() -> Result<(), Error> {
         let degree = 25;
         let (ck, vk) = setup_test(degree)?;
@@ -555,6 +677,77 @@ mod test {
         )
     }
 
+    #[test]
+    fn test_public_opening() -> Result<(), Error> {
+        let (ck, opening_key) = setup_test(27)?;
+        let point = BlsScalar::from(10);
+
+        let polynomials: Vec<Polynomial> = (25..28)
+            .map(|degree| Polynomial::rand(degree, &mut OsRng))
+            .collect();
+        let commitments = polynomials
+            .iter()
+            .map(|poly| ck.commit(poly))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let proof = ck.open(&polynomials, &point)?;
+        assert_eq!(proof.evaluations()[1], polynomials[1].evaluate(&point));
+        opening_key.verify(&commitments, &point, &proof)?;
+
+        let got_proof = OpeningProof::from_slice(&proof.to_var_bytes())?;
+        assert_eq!(got_proof, proof);
+
+        // A wrong point, evaluation or commitment must not verify
+        let wrong_point = BlsScalar::from(11);
+        assert!(opening_key
+            .verify(&commitments, &wrong_point, &proof)
+            .is_err());
+
+        let mut wrong_proof = proof.clone();
+        wrong_proof.evaluations[0] += BlsScalar::one();
+        assert!(opening_key
+            .verify(&commitments, &point, &wrong_proof)
+            .is_err());
+
+        assert!(matches!(
+            opening_key.verify(&commitments[1..], &point, &proof),
+            Err(Error::OpeningProofMismatch)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_public_batch_opening() -> Result<(), Error> {
+        let (ck, opening_key) = setup_test(26)?;
+        let point_a = BlsScalar::from(10);
+        let point_b = BlsScalar::from(11);
+
+        let poly_a = Polynomial::rand(25, &mut OsRng);
+        let poly_b = Polynomial::rand(26, &mut OsRng);
+        let poly_c = Polynomial::rand(24, &mut OsRng);
+
+        let comms_a = [ck.commit(&poly_a)?, ck.commit(&poly_b)?];
+        let comms_b = [ck.commit(&poly_c)?];
+
+        let proof_a = ck.open(&[poly_a, poly_b], &point_a)?;
+        let proof_b = ck.open(&[poly_c], &point_b)?;
+
+        opening_key.batch_verify(&[
+            (&comms_a[..], point_a, &proof_a),
+            (&comms_b[..], point_b, &proof_b),
+        ])?;
+
+        assert!(opening_key
+            .batch_verify(&[
+                (&comms_a[..], point_b, &proof_a),
+                (&comms_b[..], point_b, &proof_b),
+            ])
+            .is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn commit_key_serde() -> Result<(), Error> {
         let (commit_key, _) = setup_test(11)?;
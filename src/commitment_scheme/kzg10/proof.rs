@@ -21,9 +21,11 @@ pub(crate) struct Proof {
     pub(crate) commitment_to_polynomial: Commitment,
 }
 
+use crate::error::Error;
 use crate::transcript::TranscriptProtocol;
 use crate::util::powers_of;
 use dusk_bls12_381::G1Projective;
+use dusk_bytes::{DeserializableSlice, Serializable};
 use merlin::Transcript;
 use sp_std::vec::Vec;
 
@@ -100,3 +102,88 @@ impl AggregateProof {
         }
     }
 }
+
+/// Proof that one or more committed polynomials evaluate to the given values
+/// at a single point.
+///
+/// It is created by [`CommitKey::open`] and checked with
+/// [`OpeningKey::verify`] or [`OpeningKey::batch_verify`].
+///
+/// [`CommitKey::open`]: super::CommitKey::open
+/// [`OpeningKey::verify`]: super::OpeningKey::verify
+/// [`OpeningKey::batch_verify`]: super::OpeningKey::batch_verify
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpeningProof {
+    /// Commitment to the aggregated witness polynomial.
+    pub(crate) witness: Commitment,
+    /// Evaluations of each of the opened polynomials at the point.
+    pub(crate) evaluations: Vec<BlsScalar>,
+}
+
+impl OpeningProof {
+    /// Returns the commitment to the aggregated witness polynomial.
+    pub fn witness(&self) -> &Commitment {
+        &self.witness
+    }
+
+    /// Returns the evaluations of the opened polynomials, in the order they
+    /// were opened.
+    pub fn evaluations(&self) -> &[BlsScalar] {
+        &self.evaluations
+    }
+
+    /// Serializes the [`OpeningProof`] into a vector of bytes.
+    pub fn to_var_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(
+            Commitment::SIZE + self.evaluations.len() * BlsScalar::SIZE,
+        );
+        bytes.extend_from_slice(&self.witness.to_bytes());
+        self.evaluations
+            .iter()
+            .for_each(|eval| bytes.extend_from_slice(&eval.to_bytes()));
+
+        bytes
+    }
+
+    /// Deserializes an [`OpeningProof`] created with
+    /// [`OpeningProof::to_var_bytes`].
+    pub fn from_slice(bytes: &[u8]) -> Result<OpeningProof, Error> {
+        if bytes.len() < Commitment::SIZE + BlsScalar::SIZE
+            || (bytes.len() - Commitment::SIZE) % BlsScalar::SIZE != 0
+        {
+            return Err(Error::NotEnoughBytes);
+        }
+
+        let mut buf = bytes;
+        let witness = Commitment::from_reader(&mut buf)?;
+        let evaluations = buf
+            .chunks_exact(BlsScalar::SIZE)
+            .map(BlsScalar::from_slice)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(OpeningProof {
+            witness,
+            evaluations,
+        })
+    }
+}
+
+/// Creates the transcript an [`OpeningProof`] aggregates its polynomials
+/// with, binding the challenge to the full statement being proven.
+pub(crate) fn opening_transcript(
+    commitments: &[Commitment],
+    point: &BlsScalar,
+    evaluations: &[BlsScalar],
+) -> Transcript {
+    let mut transcript = Transcript::new(b"dusk-plonk-kzg10-opening");
+    transcript.append_u64(b"n", commitments.len() as u64);
+    commitments
+        .iter()
+        .for_each(|comm| transcript.append_commitment(b"commitment", comm));
+    transcript.append_scalar(b"point", point);
+    evaluations
+        .iter()
+        .for_each(|eval| transcript.append_scalar(b"evaluation", eval));
+
+    transcript
+}
@@ -0,0 +1,99 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! [`CommitmentScheme`] implementation for KZG10.
+
+use super::{AggregateProof, CommitKey, Commitment, OpeningKey};
+use crate::commitment_scheme::{CommitmentScheme, Opening, Polynomial};
+use crate::error::Error;
+use crate::transcript::TranscriptProtocol;
+use dusk_bls12_381::BlsScalar;
+use dusk_bytes::{DeserializableSlice, Serializable};
+use merlin::Transcript;
+use sp_std::vec::Vec;
+
+/// The KZG10 polynomial commitment scheme, using the [`PublicParameters`]
+/// of a trusted setup.
+///
+/// Its opening proofs are a single [`Commitment`] to the aggregated witness
+/// polynomial, and openings at several points are verified with a single
+/// pairing check.
+///
+/// [`PublicParameters`]: super::PublicParameters
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Kzg10;
+
+impl CommitmentScheme for Kzg10 {
+    type CommitKey = CommitKey;
+    type OpeningKey = OpeningKey;
+    type OpeningProof = Commitment;
+
+    fn commit(
+        commit_key: &CommitKey,
+        polynomial: &Polynomial,
+    ) -> Result<Commitment, Error> {
+        commit_key.commit(polynomial)
+    }
+
+    fn open(
+        commit_key: &CommitKey,
+        polynomials: &[Polynomial],
+        point: &BlsScalar,
+        transcript: &mut Transcript,
+    ) -> Result<Commitment, Error> {
+        let witness = commit_key.compute_aggregate_witness(
+            polynomials,
+            point,
+            transcript,
+        );
+        commit_key.commit(&witness)
+    }
+
+    fn verify(
+        opening_key: &OpeningKey,
+        openings: &[Opening<'_, Self>],
+        transcript: &mut Transcript,
+    ) -> Result<(), Error> {
+        if openings.iter().any(|opening| {
+            opening.commitments.is_empty()
+                || opening.commitments.len() != opening.evaluations.len()
+        }) {
+            return Err(Error::OpeningProofMismatch);
+        }
+
+        let flattened: Vec<_> = openings
+            .iter()
+            .map(|opening| {
+                let mut aggregate =
+                    AggregateProof::with_witness(*opening.proof);
+                opening
+                    .evaluations
+                    .iter()
+                    .zip(opening.commitments.iter())
+                    .for_each(|(eval, comm)| {
+                        aggregate.add_part((*eval, *comm))
+                    });
+                aggregate.flatten(transcript)
+            })
+            .collect();
+        let points: Vec<BlsScalar> =
+            openings.iter().map(|opening| opening.point).collect();
+
+        openings.iter().for_each(|opening| {
+            transcript.append_commitment(b"opening_witness", opening.proof)
+        });
+
+        opening_key.batch_check(&points, &flattened, transcript)
+    }
+
+    fn opening_proof_to_var_bytes(proof: &Commitment) -> Vec<u8> {
+        proof.to_bytes().to_vec()
+    }
+
+    fn opening_proof_from_reader(buf: &mut &[u8]) -> Result<Commitment, Error> {
+        Ok(Commitment::from_reader(buf)?)
+    }
+}
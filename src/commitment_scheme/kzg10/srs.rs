@@ -16,6 +16,14 @@ use serde::{Deserialize, Serialize};
 use sp_std::vec;
 use sp_std::vec::Vec;
 
+mod ceremony;
+mod import;
+
+pub use ceremony::ContributionProof;
+pub use import::PointEncoding;
+
+use ceremony::PowersCheck;
+
 /// The Public Parameters can also be referred to as the Structured Reference
 /// String (SRS). It is available to both the prover and verifier and allows the
 /// verifier to efficiently verify and make claims about polynomials up to and
@@ -129,6 +137,28 @@ impl PublicParameters {
         }
     }
 
+    /// Deserialize [`PublicParameters`] from a set of bytes created by
+    /// [`PublicParameters::to_raw_var_bytes`], as
+    /// [`PublicParameters::from_slice_unchecked`] does, and [`verify`] the
+    /// resulting parameters.
+    ///
+    /// # Safety
+    /// The points are still deserialized without being checked, so the same
+    /// requirements of [`PublicParameters::from_slice_unchecked`] apply. The
+    /// verification only makes sure the powers were not truncated or
+    /// tampered with.
+    ///
+    /// [`verify`]: PublicParameters::verify
+    pub unsafe fn from_slice_unchecked_verified(
+        bytes: &[u8],
+        rng: impl RngCore,
+    ) -> Result<Self, Error> {
+        let pp = Self::from_slice_unchecked(bytes);
+        pp.verify(rng)?;
+
+        Ok(pp)
+    }
+
     /// Serialises a [`PublicParameters`] struct into a slice of bytes.
     pub fn to_var_bytes(&self) -> Vec<u8> {
         let mut bytes = self.opening_key.to_bytes().to_vec();
@@ -162,6 +192,73 @@ impl PublicParameters {
         Ok(pp)
     }
 
+    /// Deserialise a slice of bytes into a Public Parameter struct, as
+    /// [`PublicParameters::from_slice`] does, and [`verify`] the resulting
+    /// parameters.
+    ///
+    /// [`verify`]: PublicParameters::verify
+    pub fn from_slice_verified(
+        bytes: &[u8],
+        rng: impl RngCore,
+    ) -> Result<PublicParameters, Error> {
+        let pp = Self::from_slice(bytes)?;
+        pp.verify(rng)?;
+
+        Ok(pp)
+    }
+
+    /// Checks the [`PublicParameters`] are well formed, that is, that
+    /// `powers_of_g[0]` is the generator of the [`OpeningKey`] and that
+    /// every `powers_of_g[i + 1]` is `\beta powers_of_g[i]` for the `\beta`
+    /// of `beta_h`.
+    ///
+    /// All the powers are checked at once with two pairings over random
+    /// linear combinations of them, drawing the coefficients from `rng`.
+    /// Only if that check fails, the first offending power is searched for
+    /// by bisection and reported with
+    /// [`Error::PublicParametersPowerMismatch`].
+    pub fn verify(&self, mut rng: impl RngCore) -> Result<(), Error> {
+        let powers_of_g = &self.commit_key.powers_of_g;
+        let opening_key = &self.opening_key;
+
+        if powers_of_g.len() < 2 {
+            return Err(Error::DegreeIsZero);
+        }
+        if powers_of_g[0] != opening_key.g {
+            return Err(Error::PublicParametersGeneratorMismatch);
+        }
+        if bool::from(opening_key.g.is_identity())
+            || bool::from(opening_key.h.is_identity())
+            || bool::from(opening_key.beta_h.is_identity())
+        {
+            return Err(Error::PublicParametersIdentityPoint);
+        }
+
+        // Pair `i` relates `powers_of_g[i]` and `powers_of_g[i + 1]`
+        let pairs_hold = |from: usize, to: usize, rng: &mut dyn RngCore| {
+            let mut check = PowersCheck::new();
+            check.append(&powers_of_g[from..=to], rng);
+            check.holds(opening_key)
+        };
+
+        let (mut lo, mut hi) = (0, powers_of_g.len() - 1);
+        if pairs_hold(lo, hi, &mut rng) {
+            return Ok(());
+        }
+
+        // The pairs in `lo..hi` always contain an offending one
+        while hi - lo > 1 {
+            let mid = (lo + hi) / 2;
+            if pairs_hold(lo, mid, &mut rng) {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+
+        Err(Error::PublicParametersPowerMismatch { index: hi })
+    }
+
     /// Trim truncates the [`PublicParameters`] to allow the prover to commit to
     /// polynomials up to the and including the truncated degree.
     /// Returns the [`CommitKey`] and [`OpeningKey`] used to generate and verify
@@ -234,4 +331,61 @@ mod test {
         assert_eq!(pp.opening_key.h, pp_p.opening_key.h);
         assert_eq!(pp.opening_key.beta_h, pp_p.opening_key.beta_h);
     }
+
+    #[test]
+    fn verify_public_parameters() {
+        let pp = PublicParameters::setup(1 << 7, &mut OsRng).unwrap();
+        pp.verify(&mut OsRng)
+            .expect("Setup parameters should be well formed");
+
+        let got_pp = PublicParameters::from_slice_verified(
+            &pp.to_var_bytes(),
+            &mut OsRng,
+        )
+        .unwrap();
+        assert_eq!(got_pp, pp);
+
+        let got_pp = unsafe {
+            PublicParameters::from_slice_unchecked_verified(
+                &pp.to_raw_var_bytes(),
+                &mut OsRng,
+            )
+        }
+        .unwrap();
+        assert_eq!(got_pp, pp);
+
+        let mut bad_pp = pp.clone();
+        bad_pp.commit_key.powers_of_g[37] = pp.commit_key.powers_of_g[36];
+        assert!(matches!(
+            bad_pp.verify(&mut OsRng),
+            Err(Error::PublicParametersPowerMismatch { index: 37 })
+        ));
+        assert!(PublicParameters::from_slice_verified(
+            &bad_pp.to_var_bytes(),
+            &mut OsRng
+        )
+        .is_err());
+        assert!(unsafe {
+            PublicParameters::from_slice_unchecked_verified(
+                &bad_pp.to_raw_var_bytes(),
+                &mut OsRng,
+            )
+        }
+        .is_err());
+
+        let mut bad_pp = pp.clone();
+        bad_pp.commit_key.powers_of_g[0] = G1Affine::generator();
+        assert!(matches!(
+            bad_pp.verify(&mut OsRng),
+            Err(Error::PublicParametersGeneratorMismatch)
+        ));
+
+        let OpeningKey { g, h, .. } = pp.opening_key;
+        let mut bad_pp = pp;
+        bad_pp.opening_key = OpeningKey::new(g, h, G2Affine::generator());
+        assert!(matches!(
+            bad_pp.verify(&mut OsRng),
+            Err(Error::PublicParametersPowerMismatch { index: 1 })
+        ));
+    }
 }
@@ -0,0 +1,617 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Multi-party generation of the [`PublicParameters`].
+//!
+//! A ceremony starts from [`PublicParameters::ceremony_genesis`], whose
+//! secret is one. Each participant then applies a contribution, which
+//! rerandomizes every power by a fresh secret `x` and publishes a
+//! [`ContributionProof`]. As long as one of the participants discards its
+//! secret, the final `\beta` is unknown to everyone.
+//!
+//! The chain of proofs, together with the final parameters, can be checked
+//! with [`PublicParameters::verify_contributions`]. With the `std` feature,
+//! enabled by default, both operations are also available over readers and
+//! writers, so parameters larger than the available memory can be processed.
+
+use super::PublicParameters;
+use crate::commitment_scheme::kzg10::key::{CommitKey, OpeningKey};
+use crate::error::Error;
+use crate::transcript::TranscriptProtocol;
+use crate::util;
+use dusk_bls12_381::{
+    multiscalar_mul::msm_variable_base, BlsScalar, G1Affine, G1Projective,
+    G2Affine, G2Prepared,
+};
+use dusk_bytes::{DeserializableSlice, Serializable};
+use merlin::Transcript;
+use rand_core::RngCore;
+use sp_std::vec;
+use sp_std::vec::Vec;
+
+/// Proof of a single contribution to the [`PublicParameters`].
+///
+/// It holds the first power of `\beta` in `G_1` after the contribution was
+/// applied, the public key `x H` of the contribution secret and a Schnorr
+/// proof of knowledge of `x` bound to both powers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ContributionProof {
+    /// First power of `\beta` in `G_1` after the contribution.
+    pub(crate) beta_g: G1Affine,
+    /// The contribution secret times the generator of `G_2`.
+    pub(crate) public_key: G2Affine,
+    /// Commitment to the nonce of the proof of knowledge.
+    pub(crate) commitment: G2Affine,
+    /// Response to the challenge of the proof of knowledge.
+    pub(crate) response: BlsScalar,
+}
+
+impl Serializable<{ G1Affine::SIZE + G2Affine::SIZE * 2 + BlsScalar::SIZE }>
+    for ContributionProof
+{
+    type Error = dusk_bytes::Error;
+
+    #[allow(unused_must_use)]
+    fn to_bytes(&self) -> [u8; Self::SIZE] {
+        use dusk_bytes::Write;
+        let mut buf = [0u8; Self::SIZE];
+        let mut writer = &mut buf[..];
+        // This can't fail therefore we don't care about the Result nor use it.
+        writer.write(&self.beta_g.to_bytes());
+        writer.write(&self.public_key.to_bytes());
+        writer.write(&self.commitment.to_bytes());
+        writer.write(&self.response.to_bytes());
+
+        buf
+    }
+
+    fn from_bytes(buf: &[u8; Self::SIZE]) -> Result<Self, Self::Error> {
+        let mut buffer = &buf[..];
+        let beta_g = G1Affine::from_reader(&mut buffer)?;
+        let public_key = G2Affine::from_reader(&mut buffer)?;
+        let commitment = G2Affine::from_reader(&mut buffer)?;
+        let response = BlsScalar::from_reader(&mut buffer)?;
+
+        Ok(Self {
+            beta_g,
+            public_key,
+            commitment,
+            response,
+        })
+    }
+}
+
+impl ContributionProof {
+    /// Proves the knowledge of `secret`, which moved the first power of
+    /// `\beta` in `G_1` from `prev_beta_g` to `beta_g`.
+    fn new(
+        prev_beta_g: &G1Affine,
+        beta_g: G1Affine,
+        h: &G2Affine,
+        secret: &BlsScalar,
+        rng: impl RngCore,
+    ) -> Self {
+        let public_key = G2Affine::from(h * secret);
+
+        let nonce = util::random_scalar(rng);
+        let commitment = G2Affine::from(h * nonce);
+
+        let challenge =
+            Self::challenge(prev_beta_g, &beta_g, &public_key, &commitment);
+        let response = nonce + challenge * secret;
+
+        Self {
+            beta_g,
+            public_key,
+            commitment,
+            response,
+        }
+    }
+
+    fn challenge(
+        prev_beta_g: &G1Affine,
+        beta_g: &G1Affine,
+        public_key: &G2Affine,
+        commitment: &G2Affine,
+    ) -> BlsScalar {
+        let mut transcript = Transcript::new(b"dusk-plonk-srs-contribution");
+        transcript.append_message(b"prev_beta_g", &prev_beta_g.to_bytes());
+        transcript.append_message(b"beta_g", &beta_g.to_bytes());
+        transcript.append_message(b"public_key", &public_key.to_bytes());
+        transcript.append_message(b"commitment", &commitment.to_bytes());
+
+        transcript.challenge_scalar(b"challenge")
+    }
+
+    /// Returns the first power of `\beta` in `G_1` after the contribution was
+    /// applied.
+    pub fn beta_g(&self) -> &G1Affine {
+        &self.beta_g
+    }
+
+    /// Verifies the contribution was applied on top of `prev_beta_g`, the
+    /// first power of `\beta` in `G_1` before the contribution, by someone
+    /// knowing its secret.
+    ///
+    /// `h` is the generator of `G_2` of the parameters being contributed to.
+    pub fn verify(
+        &self,
+        prev_beta_g: &G1Affine,
+        h: &G2Affine,
+    ) -> Result<(), Error> {
+        if bool::from(self.public_key.is_identity()) {
+            return Err(Error::ContributionProofInvalid);
+        }
+
+        let challenge = Self::challenge(
+            prev_beta_g,
+            &self.beta_g,
+            &self.public_key,
+            &self.commitment,
+        );
+        let lhs = h * self.response;
+        let rhs = self.public_key * challenge + self.commitment;
+        if G2Affine::from(lhs) != G2Affine::from(rhs) {
+            return Err(Error::ContributionProofInvalid);
+        }
+
+        // e(beta_g, h) = e(prev_beta_g, x h)
+        let pairing = dusk_bls12_381::multi_miller_loop(&[
+            (&self.beta_g, &G2Prepared::from(*h)),
+            (&-prev_beta_g, &G2Prepared::from(self.public_key)),
+        ])
+        .final_exponentiation();
+
+        if pairing != dusk_bls12_381::Gt::identity() {
+            return Err(Error::ContributionChainBroken);
+        }
+
+        Ok(())
+    }
+}
+
+/// Randomized batched check of `powers_of_g[i + 1] = \beta powers_of_g[i]`
+/// that can be fed with the powers in chunks.
+///
+/// Given random `r_i`, it checks
+/// `e(\sum r_i powers_of_g[i], \beta H) = e(\sum r_i powers_of_g[i + 1], H)`.
+#[derive(Debug, Clone)]
+pub(crate) struct PowersCheck {
+    first: Option<G1Affine>,
+    last: Option<G1Affine>,
+    len: usize,
+    lhs: G1Projective,
+    rhs: G1Projective,
+}
+
+impl PowersCheck {
+    pub(crate) fn new() -> Self {
+        Self {
+            first: None,
+            last: None,
+            len: 0,
+            lhs: G1Projective::identity(),
+            rhs: G1Projective::identity(),
+        }
+    }
+
+    /// Appends the next `powers` to the check.
+    pub(crate) fn append(
+        &mut self,
+        powers: &[G1Affine],
+        mut rng: impl RngCore,
+    ) {
+        if powers.is_empty() {
+            return;
+        }
+        self.first.get_or_insert(powers[0]);
+        self.len += powers.len();
+
+        let mut window = Vec::with_capacity(powers.len() + 1);
+        window.extend(self.last);
+        window.extend_from_slice(powers);
+        self.last = window.last().copied();
+
+        let pairs = window.len() - 1;
+        if pairs == 0 {
+            return;
+        }
+
+        let scalars: Vec<BlsScalar> =
+            (0..pairs).map(|_| util::random_scalar(&mut rng)).collect();
+        self.lhs += msm_variable_base(&window[..pairs], &scalars);
+        self.rhs += msm_variable_base(&window[1..], &scalars);
+    }
+
+    /// Number of powers appended so far.
+    #[cfg(feature = "std")]
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks the appended powers against the given [`OpeningKey`].
+    pub(crate) fn finish(self, opening_key: &OpeningKey) -> Result<(), Error> {
+        if self.len < 2
+            || self.first != Some(opening_key.g)
+            || bool::from(opening_key.beta_h.is_identity())
+        {
+            return Err(Error::CeremonyInconsistentPowers);
+        }
+
+        if !self.holds(opening_key) {
+            return Err(Error::CeremonyInconsistentPowers);
+        }
+
+        Ok(())
+    }
+
+    /// Runs the pairing check of the appended powers against the `\beta H`
+    /// of the given [`OpeningKey`].
+    pub(crate) fn holds(&self, opening_key: &OpeningKey) -> bool {
+        let pairing = dusk_bls12_381::multi_miller_loop(&[
+            (&G1Affine::from(self.lhs), &opening_key.prepared_beta_h),
+            (&G1Affine::from(-self.rhs), &opening_key.prepared_h),
+        ])
+        .final_exponentiation();
+
+        pairing == dusk_bls12_381::Gt::identity()
+    }
+}
+
+/// Follows a chain of contributions starting at the generator `g`,
+/// returning the first power of `\beta` in `G_1` it ends at.
+fn verify_chain(
+    g: &G1Affine,
+    h: &G2Affine,
+    contributions: &[ContributionProof],
+) -> Result<G1Affine, Error> {
+    contributions
+        .iter()
+        .try_fold(*g, |prev_beta_g, contribution| {
+            contribution.verify(&prev_beta_g, h)?;
+            Ok(contribution.beta_g)
+        })
+}
+
+impl PublicParameters {
+    /// Creates the [`PublicParameters`] a ceremony of the given `max_degree`
+    /// starts from.
+    ///
+    /// Its secret is one, hence every power is the generator of `G_1` and the
+    /// parameters are insecure until at least one contribution is applied.
+    pub fn ceremony_genesis(
+        max_degree: usize,
+    ) -> Result<PublicParameters, Error> {
+        if max_degree < 1 {
+            return Err(Error::DegreeIsZero);
+        }
+
+        let g = G1Affine::generator();
+        let h = G2Affine::generator();
+
+        Ok(PublicParameters {
+            commit_key: CommitKey {
+                powers_of_g: vec![g; max_degree + 1],
+            },
+            opening_key: OpeningKey::new(g, h, h),
+        })
+    }
+
+    /// Applies a contribution with a secret drawn from `rng`, multiplying
+    /// every `powers_of_g[i]` by `x^i` and `beta_h` by `x`.
+    ///
+    /// The secret is dropped once the returned [`ContributionProof`] is
+    /// computed.
+    pub fn contribute(&mut self, mut rng: impl RngCore) -> ContributionProof {
+        let secret = util::random_scalar(&mut rng);
+        let prev_beta_g = self.commit_key.powers_of_g[1];
+
+        let powers_of_secret = util::powers_of(&secret, self.max_degree());
+        let powers_of_g: Vec<G1Projective> = self
+            .commit_key
+            .powers_of_g
+            .iter()
+            .zip(powers_of_secret.iter())
+            .map(|(g, x)| g * x)
+            .collect();
+        G1Projective::batch_normalize(
+            &powers_of_g,
+            &mut self.commit_key.powers_of_g,
+        );
+
+        let OpeningKey { g, h, beta_h, .. } = self.opening_key;
+        self.opening_key = OpeningKey::new(g, h, (beta_h * secret).into());
+
+        ContributionProof::new(
+            &prev_beta_g,
+            self.commit_key.powers_of_g[1],
+            &self.opening_key.h,
+            &secret,
+            rng,
+        )
+    }
+
+    /// Verifies that these [`PublicParameters`] are the result of applying
+    /// `contributions`, in order, on top of the ceremony genesis, and that
+    /// their powers are well formed.
+    pub fn verify_contributions(
+        &self,
+        contributions: &[ContributionProof],
+        mut rng: impl RngCore,
+    ) -> Result<(), Error> {
+        let opening_key = &self.opening_key;
+        let beta_g =
+            verify_chain(&opening_key.g, &opening_key.h, contributions)?;
+        if self.commit_key.powers_of_g.get(1) != Some(&beta_g) {
+            return Err(Error::ContributionChainBroken);
+        }
+
+        let mut check = PowersCheck::new();
+        check.append(&self.commit_key.powers_of_g, &mut rng);
+        check.finish(opening_key)
+    }
+}
+
+#[cfg(feature = "std")]
+mod stream {
+    use super::*;
+    use std::io::{self, Read, Write};
+
+    /// Number of powers processed at once while streaming.
+    pub(super) const STREAM_CHUNK: usize = 1 << 12;
+
+    /// Fills `buf` from `reader`, returning the number of bytes read before
+    /// reaching the end of the stream.
+    fn read_chunk<R: Read>(
+        reader: &mut R,
+        buf: &mut [u8],
+    ) -> Result<usize, Error> {
+        let mut read = 0;
+        while read < buf.len() {
+            match reader.read(&mut buf[read..]) {
+                Ok(0) => break,
+                Ok(n) => read += n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(_) => return Err(Error::CeremonyIoError),
+            }
+        }
+
+        Ok(read)
+    }
+
+    fn read_opening_key<R: Read>(reader: &mut R) -> Result<OpeningKey, Error> {
+        let mut buf = [0u8; OpeningKey::SIZE];
+        if read_chunk(reader, &mut buf)? != OpeningKey::SIZE {
+            return Err(Error::NotEnoughBytes);
+        }
+
+        Ok(OpeningKey::from_bytes(&buf)?)
+    }
+
+    /// Reads the next chunk of powers, returning an empty vector once the
+    /// stream is exhausted.
+    fn read_powers<R: Read>(
+        reader: &mut R,
+        buf: &mut [u8],
+    ) -> Result<Vec<G1Affine>, Error> {
+        let read = read_chunk(reader, buf)?;
+        if read % G1Affine::SIZE != 0 {
+            return Err(Error::NotEnoughBytes);
+        }
+
+        let powers = buf[..read]
+            .chunks_exact(G1Affine::SIZE)
+            .map(|point| G1Affine::from_slice(point))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(powers)
+    }
+
+    impl PublicParameters {
+        /// Applies a contribution to the [`PublicParameters`] serialized by
+        /// [`PublicParameters::to_var_bytes`] in `reader`, writing the
+        /// result in the same format to `writer`.
+        ///
+        /// Only a bounded chunk of the powers is kept in memory at any time.
+        pub fn contribute_stream<R: Read, W: Write>(
+            mut reader: R,
+            mut writer: W,
+            mut rng: impl RngCore,
+        ) -> Result<ContributionProof, Error> {
+            let opening_key = read_opening_key(&mut reader)?;
+
+            let secret = util::random_scalar(&mut rng);
+            let beta_h = G2Affine::from(opening_key.beta_h * secret);
+            let opening_key =
+                OpeningKey::new(opening_key.g, opening_key.h, beta_h);
+            writer
+                .write_all(&opening_key.to_bytes())
+                .map_err(|_| Error::CeremonyIoError)?;
+
+            let mut buf = vec![0u8; STREAM_CHUNK * G1Affine::SIZE];
+            let mut power = BlsScalar::one();
+            let mut index = 0;
+            let mut beta_g = None;
+
+            loop {
+                let powers = read_powers(&mut reader, &mut buf)?;
+                if powers.is_empty() {
+                    break;
+                }
+
+                let projective: Vec<G1Projective> = powers
+                    .iter()
+                    .map(|g| {
+                        let g = g * power;
+                        power *= secret;
+                        g
+                    })
+                    .collect();
+                let mut affine = vec![G1Affine::identity(); powers.len()];
+                G1Projective::batch_normalize(&projective, &mut affine);
+
+                if index <= 1 && index + powers.len() > 1 {
+                    beta_g = Some((powers[1 - index], affine[1 - index]));
+                }
+                index += powers.len();
+
+                affine.iter().try_for_each(|g| {
+                    writer
+                        .write_all(&g.to_bytes())
+                        .map_err(|_| Error::CeremonyIoError)
+                })?;
+            }
+
+            let (prev_beta_g, beta_g) =
+                beta_g.ok_or(Error::CeremonyNotEnoughPowers)?;
+            writer.flush().map_err(|_| Error::CeremonyIoError)?;
+
+            Ok(ContributionProof::new(
+                &prev_beta_g,
+                beta_g,
+                &opening_key.h,
+                &secret,
+                rng,
+            ))
+        }
+
+        /// Streaming counterpart of
+        /// [`PublicParameters::verify_contributions`], reading the
+        /// parameters serialized by [`PublicParameters::to_var_bytes`] from
+        /// `reader`.
+        pub fn verify_contributions_stream<R: Read>(
+            mut reader: R,
+            contributions: &[ContributionProof],
+            mut rng: impl RngCore,
+        ) -> Result<(), Error> {
+            let opening_key = read_opening_key(&mut reader)?;
+            let beta_g =
+                verify_chain(&opening_key.g, &opening_key.h, contributions)?;
+
+            let mut buf = vec![0u8; STREAM_CHUNK * G1Affine::SIZE];
+            let mut check = PowersCheck::new();
+
+            loop {
+                let powers = read_powers(&mut reader, &mut buf)?;
+                if powers.is_empty() {
+                    break;
+                }
+
+                let index = check.len();
+                if index <= 1
+                    && index + powers.len() > 1
+                    && powers[1 - index] != beta_g
+                {
+                    return Err(Error::ContributionChainBroken);
+                }
+                check.append(&powers, &mut rng);
+            }
+
+            check.finish(&opening_key)
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand_core::OsRng;
+
+    fn run_ceremony(
+        max_degree: usize,
+        participants: usize,
+    ) -> (PublicParameters, Vec<ContributionProof>) {
+        let mut pp = PublicParameters::ceremony_genesis(max_degree).unwrap();
+        let contributions = (0..participants)
+            .map(|_| pp.contribute(&mut OsRng))
+            .collect();
+
+        (pp, contributions)
+    }
+
+    #[test]
+    fn contributions_chain() {
+        let (pp, contributions) = run_ceremony(1 << 6, 3);
+
+        pp.verify_contributions(&contributions, &mut OsRng)
+            .expect("Honest contributions should verify");
+
+        // Dropping or reordering contributions breaks the chain
+        assert!(pp
+            .verify_contributions(&contributions[1..], &mut OsRng)
+            .is_err());
+        let mut swapped = contributions.clone();
+        swapped.swap(0, 1);
+        assert!(pp.verify_contributions(&swapped, &mut OsRng).is_err());
+    }
+
+    #[test]
+    fn contribution_proof_serialization() {
+        let (_, contributions) = run_ceremony(1 << 2, 1);
+        let proof = contributions[0];
+
+        let got = ContributionProof::from_bytes(&proof.to_bytes()).unwrap();
+        assert_eq!(got, proof);
+    }
+
+    #[test]
+    fn tampered_contributions() {
+        let (mut pp, mut contributions) = run_ceremony(1 << 5, 2);
+
+        let mut forged = contributions.clone();
+        forged[1].response += BlsScalar::one();
+        assert!(matches!(
+            pp.verify_contributions(&forged, &mut OsRng),
+            Err(Error::ContributionProofInvalid)
+        ));
+
+        // Corrupting one of the powers is caught by the batched check
+        pp.commit_key.powers_of_g[7] = G1Affine::generator();
+        assert!(matches!(
+            pp.verify_contributions(&contributions, &mut OsRng),
+            Err(Error::CeremonyInconsistentPowers)
+        ));
+
+        contributions.pop();
+        assert!(pp.verify_contributions(&contributions, &mut OsRng).is_err());
+    }
+
+    #[test]
+    fn contributions_stream() {
+        // Exceed the streaming chunk size to cover the chunk boundaries
+        let max_degree = stream::STREAM_CHUNK + 3;
+        let (pp, mut contributions) = run_ceremony(max_degree, 1);
+
+        let mut bytes = Vec::new();
+        contributions.push(
+            PublicParameters::contribute_stream(
+                &pp.to_var_bytes()[..],
+                &mut bytes,
+                &mut OsRng,
+            )
+            .unwrap(),
+        );
+
+        PublicParameters::verify_contributions_stream(
+            &bytes[..],
+            &contributions,
+            &mut OsRng,
+        )
+        .expect("Streamed contributions should verify");
+
+        let pp = PublicParameters::from_slice(&bytes).unwrap();
+        assert_eq!(pp.max_degree(), max_degree);
+        pp.verify_contributions(&contributions, &mut OsRng).unwrap();
+
+        assert!(PublicParameters::verify_contributions_stream(
+            &bytes[..bytes.len() - 1],
+            &contributions,
+            &mut OsRng,
+        )
+        .is_err());
+    }
+}
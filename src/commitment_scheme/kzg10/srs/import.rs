@@ -0,0 +1,314 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Importers that build [`PublicParameters`] out of the transcripts of
+//! existing BLS12-381 powers-of-tau ceremonies.
+//!
+//! Every importer only extracts the points PLONK needs, that is
+//! `{ \tau^i G_1 }` up to the requested degree together with `G_2` and
+//! `\tau G_2`, and runs the consistency checks of
+//! [`PublicParameters::from_ceremony_powers`] over them.
+
+use super::PublicParameters;
+use crate::commitment_scheme::kzg10::key::{CommitKey, OpeningKey};
+use crate::error::Error;
+use dusk_bls12_381::{G1Affine, G2Affine, G2Prepared};
+use rand_core::RngCore;
+use serde::Deserialize;
+use sp_std::vec::Vec;
+
+/// Size of the BLAKE2b hash that prefixes every Zcash powers-of-tau
+/// accumulator file.
+const ZCASH_HASH_SIZE: usize = 64;
+
+/// Encoding of the curve points stored in a Zcash powers-of-tau
+/// accumulator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PointEncoding {
+    /// Compressed points, as stored in the `response` files.
+    Compressed,
+    /// Uncompressed points, as stored in the `challenge` files.
+    Uncompressed,
+}
+
+impl PointEncoding {
+    const fn g1_size(self) -> usize {
+        match self {
+            Self::Compressed => 48,
+            Self::Uncompressed => 96,
+        }
+    }
+
+    const fn g2_size(self) -> usize {
+        match self {
+            Self::Compressed => 96,
+            Self::Uncompressed => 192,
+        }
+    }
+
+    fn read_g1(self, bytes: &[u8]) -> Result<G1Affine, Error> {
+        let point = match self {
+            Self::Compressed => {
+                let mut buf = [0u8; 48];
+                buf.copy_from_slice(bytes);
+                G1Affine::from_compressed(&buf)
+            }
+            Self::Uncompressed => {
+                let mut buf = [0u8; 96];
+                buf.copy_from_slice(bytes);
+                G1Affine::from_uncompressed(&buf)
+            }
+        };
+
+        Option::from(point).ok_or(Error::PointMalformed)
+    }
+
+    fn read_g2(self, bytes: &[u8]) -> Result<G2Affine, Error> {
+        let point = match self {
+            Self::Compressed => {
+                let mut buf = [0u8; 96];
+                buf.copy_from_slice(bytes);
+                G2Affine::from_compressed(&buf)
+            }
+            Self::Uncompressed => {
+                let mut buf = [0u8; 192];
+                buf.copy_from_slice(bytes);
+                G2Affine::from_uncompressed(&buf)
+            }
+        };
+
+        Option::from(point).ok_or(Error::PointMalformed)
+    }
+}
+
+/// Layout of the JSON transcript published by the Ethereum KZG ceremony.
+#[derive(Deserialize)]
+struct EthereumCeremony<'a> {
+    #[serde(borrow)]
+    transcripts: Vec<EthereumTranscript<'a>>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EthereumTranscript<'a> {
+    num_g1_powers: usize,
+    num_g2_powers: usize,
+    #[serde(borrow)]
+    powers_of_tau: EthereumPowersOfTau<'a>,
+    #[serde(borrow, default)]
+    witness: Option<EthereumWitness<'a>>,
+}
+
+#[derive(Deserialize)]
+struct EthereumPowersOfTau<'a> {
+    #[serde(borrow, rename = "G1Powers")]
+    g1_powers: Vec<&'a str>,
+    #[serde(borrow, rename = "G2Powers")]
+    g2_powers: Vec<&'a str>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EthereumWitness<'a> {
+    #[serde(borrow)]
+    running_products: Vec<&'a str>,
+}
+
+/// Decodes a `0x` prefixed hex string into `buf`, failing if the string
+/// does not exactly fill it.
+fn decode_hex(hex: &str, buf: &mut [u8]) -> Result<(), Error> {
+    let hex = hex.strip_prefix("0x").unwrap_or(hex).as_bytes();
+    if hex.len() != buf.len() * 2 {
+        return Err(Error::CeremonyTranscriptMalformed);
+    }
+
+    let nibble = |c: u8| match c {
+        b'0'..=b'9' => Ok(c - b'0'),
+        b'a'..=b'f' => Ok(c - b'a' + 10),
+        b'A'..=b'F' => Ok(c - b'A' + 10),
+        _ => Err(Error::CeremonyTranscriptMalformed),
+    };
+
+    for (byte, pair) in buf.iter_mut().zip(hex.chunks_exact(2)) {
+        *byte = nibble(pair[0])? << 4 | nibble(pair[1])?;
+    }
+
+    Ok(())
+}
+
+fn decode_hex_g1(hex: &str) -> Result<G1Affine, Error> {
+    let mut buf = [0u8; 48];
+    decode_hex(hex, &mut buf)?;
+    PointEncoding::Compressed.read_g1(&buf)
+}
+
+fn decode_hex_g2(hex: &str) -> Result<G2Affine, Error> {
+    let mut buf = [0u8; 96];
+    decode_hex(hex, &mut buf)?;
+    PointEncoding::Compressed.read_g2(&buf)
+}
+
+impl PublicParameters {
+    /// Builds [`PublicParameters`] of the given `max_degree` out of the
+    /// powers of a ceremony secret.
+    ///
+    /// `powers_of_g` must contain `{ \tau^i G_1 }` starting at `i = 0`, `h`
+    /// is the generator of `G_2` and `beta_h` is `\tau G_2`. Returns an
+    /// error if the powers do not start at the standard generators or if the
+    /// pairing `e(\tau G_1, G_2) = e(G_1, \tau G_2)` does not hold.
+    ///
+    /// Every power up to `max_degree` is then checked to be `\tau` times
+    /// the previous one with [`PublicParameters::verify`], drawing the
+    /// coefficients of its random linear combinations from `rng`.
+    pub fn from_ceremony_powers(
+        mut powers_of_g: Vec<G1Affine>,
+        h: G2Affine,
+        beta_h: G2Affine,
+        max_degree: usize,
+        rng: impl RngCore,
+    ) -> Result<PublicParameters, Error> {
+        if max_degree < 1 {
+            return Err(Error::DegreeIsZero);
+        }
+        if powers_of_g.len() <= max_degree {
+            return Err(Error::CeremonyNotEnoughPowers);
+        }
+        powers_of_g.truncate(max_degree + 1);
+
+        let g = powers_of_g[0];
+        if g != G1Affine::generator() || h != G2Affine::generator() {
+            return Err(Error::CeremonyInconsistentPowers);
+        }
+
+        // A zero secret would collapse every power onto the identity
+        let beta_g = powers_of_g[1];
+        if bool::from(beta_g.is_identity()) || bool::from(beta_h.is_identity())
+        {
+            return Err(Error::CeremonyInconsistentPowers);
+        }
+
+        let pairing = dusk_bls12_381::multi_miller_loop(&[
+            (&beta_g, &G2Prepared::from(h)),
+            (&-g, &G2Prepared::from(beta_h)),
+        ])
+        .final_exponentiation();
+
+        if pairing != dusk_bls12_381::Gt::identity() {
+            return Err(Error::CeremonyInconsistentPowers);
+        }
+
+        let pp = PublicParameters {
+            commit_key: CommitKey { powers_of_g },
+            opening_key: OpeningKey::new(g, h, beta_h),
+        };
+        pp.verify(rng)?;
+
+        Ok(pp)
+    }
+
+    /// Imports [`PublicParameters`] of the given `max_degree` from a Zcash
+    /// style powers-of-tau accumulator, such as the `challenge` and
+    /// `response` files of the BLS12-381 `powersoftau` ceremony.
+    ///
+    /// The accumulator is expected to start with the 64 bytes hash of the
+    /// previous contribution, followed by `2 * tau_powers_length - 1` powers
+    /// of tau in `G_1` and `tau_powers_length` powers of tau in `G_2`. Any
+    /// trailing data, such as the `\alpha` and `\beta` powers or the
+    /// contribution public key, is ignored.
+    ///
+    /// The powers are checked with
+    /// [`PublicParameters::from_ceremony_powers`], using `rng`.
+    pub fn from_zcash_accumulator(
+        bytes: &[u8],
+        tau_powers_length: usize,
+        encoding: PointEncoding,
+        max_degree: usize,
+        rng: impl RngCore,
+    ) -> Result<PublicParameters, Error> {
+        if tau_powers_length < 2 {
+            return Err(Error::CeremonyNotEnoughPowers);
+        }
+        let g1_powers_length = (tau_powers_length << 1) - 1;
+        if max_degree >= g1_powers_length {
+            return Err(Error::CeremonyNotEnoughPowers);
+        }
+
+        let g1_size = encoding.g1_size();
+        let g2_size = encoding.g2_size();
+        let g2_offset = ZCASH_HASH_SIZE + g1_powers_length * g1_size;
+        if bytes.len() < g2_offset + 2 * g2_size {
+            return Err(Error::NotEnoughBytes);
+        }
+
+        let powers_of_g = bytes[ZCASH_HASH_SIZE..]
+            .chunks_exact(g1_size)
+            .take(max_degree + 1)
+            .map(|point| encoding.read_g1(point))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let g2_powers = &bytes[g2_offset..];
+        let h = encoding.read_g2(&g2_powers[..g2_size])?;
+        let beta_h = encoding.read_g2(&g2_powers[g2_size..2 * g2_size])?;
+
+        Self::from_ceremony_powers(powers_of_g, h, beta_h, max_degree, rng)
+    }
+
+    /// Imports [`PublicParameters`] of the given `max_degree` from the JSON
+    /// transcript of the Ethereum KZG ceremony.
+    ///
+    /// The smallest transcript holding more than `max_degree` powers of tau
+    /// in `G_1` is used. Besides the checks of
+    /// [`PublicParameters::from_ceremony_powers`], the declared number of
+    /// powers and the last running product of the transcript witness are
+    /// checked against the powers themselves.
+    ///
+    /// The powers are checked with
+    /// [`PublicParameters::from_ceremony_powers`], using `rng`.
+    pub fn from_ethereum_ceremony(
+        json: &[u8],
+        max_degree: usize,
+        rng: impl RngCore,
+    ) -> Result<PublicParameters, Error> {
+        let ceremony: EthereumCeremony = serde_json::from_slice(json)
+            .map_err(|_| Error::CeremonyTranscriptMalformed)?;
+
+        let transcript = ceremony
+            .transcripts
+            .iter()
+            .filter(|t| t.num_g1_powers > max_degree)
+            .min_by_key(|t| t.num_g1_powers)
+            .ok_or(Error::CeremonyNotEnoughPowers)?;
+
+        let powers = &transcript.powers_of_tau;
+        if powers.g1_powers.len() != transcript.num_g1_powers
+            || powers.g2_powers.len() != transcript.num_g2_powers
+            || powers.g2_powers.len() < 2
+        {
+            return Err(Error::CeremonyTranscriptMalformed);
+        }
+
+        let powers_of_g = powers.g1_powers[..=max_degree]
+            .iter()
+            .map(|point| decode_hex_g1(point))
+            .collect::<Result<Vec<_>, _>>()?;
+        let h = decode_hex_g2(powers.g2_powers[0])?;
+        let beta_h = decode_hex_g2(powers.g2_powers[1])?;
+
+        // The running product accumulates every contribution to the secret,
+        // hence the last one must be the first power of tau.
+        let running_product = transcript
+            .witness
+            .as_ref()
+            .and_then(|w| w.running_products.last());
+        if let Some(running_product) = running_product {
+            if decode_hex_g1(running_product)? != powers_of_g[1] {
+                return Err(Error::CeremonyInconsistentPowers);
+            }
+        }
+
+        Self::from_ceremony_powers(powers_of_g, h, beta_h, max_degree, rng)
+    }
+}
@@ -0,0 +1,94 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! The interface the proof system requires from a polynomial commitment
+//! scheme.
+
+use super::{Commitment, Polynomial};
+use crate::error::Error;
+use core::fmt::Debug;
+use dusk_bls12_381::BlsScalar;
+use merlin::Transcript;
+use parity_scale_codec::{Decode, Encode};
+use sp_std::vec::Vec;
+
+/// A homomorphic polynomial commitment scheme over the BLS12-381 scalar
+/// field, committing to polynomials with points of G1.
+///
+/// The [`Prover`](crate::prelude::Prover) and
+/// [`Verifier`](crate::prelude::Verifier) are generic over the scheme, which
+/// defaults to [`Kzg10`](super::Kzg10), and so is the
+/// [`VerifierKey`](crate::prelude::VerifierKey) holding the commitments to
+/// the preprocessed polynomials of a circuit. The
+/// [`ProverKey`](crate::prelude::ProverKey) only holds the polynomials
+/// themselves, which don't depend on the scheme, so a circuit preprocessed
+/// once can be proven with any of them.
+pub trait CommitmentScheme: Sized {
+    /// Key used to commit to polynomials and to open them.
+    type CommitKey;
+    /// Key used to verify opening proofs.
+    type OpeningKey;
+    /// Proof that a set of committed polynomials evaluate to some values at
+    /// a single point.
+    type OpeningProof: Debug
+        + Clone
+        + PartialEq
+        + Eq
+        + Default
+        + Encode
+        + Decode;
+
+    /// Commits to a [`Polynomial`].
+    fn commit(
+        commit_key: &Self::CommitKey,
+        polynomial: &Polynomial,
+    ) -> Result<Commitment, Error>;
+
+    /// Opens the given polynomials at `point`.
+    ///
+    /// Any challenge needed to aggregate the polynomials is drawn from
+    /// `transcript`, which must then hold the commitments to them and their
+    /// evaluations at `point`.
+    fn open(
+        commit_key: &Self::CommitKey,
+        polynomials: &[Polynomial],
+        point: &BlsScalar,
+        transcript: &mut Transcript,
+    ) -> Result<Self::OpeningProof, Error>;
+
+    /// Verifies a batch of [`Opening`]s.
+    ///
+    /// The openings must be given in the order they were created with
+    /// [`CommitmentScheme::open`] and `transcript` must have the same view
+    /// the prover had when creating the first of them.
+    fn verify(
+        opening_key: &Self::OpeningKey,
+        openings: &[Opening<'_, Self>],
+        transcript: &mut Transcript,
+    ) -> Result<(), Error>;
+
+    /// Serializes an opening proof.
+    fn opening_proof_to_var_bytes(proof: &Self::OpeningProof) -> Vec<u8>;
+
+    /// Deserializes an opening proof, advancing `buf` past it.
+    fn opening_proof_from_reader(
+        buf: &mut &[u8],
+    ) -> Result<Self::OpeningProof, Error>;
+}
+
+/// Claimed evaluations of committed polynomials at a point, together with
+/// the proof of their opening.
+#[derive(Debug)]
+pub struct Opening<'a, S: CommitmentScheme> {
+    /// The point the polynomials were opened at.
+    pub point: BlsScalar,
+    /// The commitments to the opened polynomials.
+    pub commitments: &'a [Commitment],
+    /// The evaluation of each of the polynomials at `point`.
+    pub evaluations: &'a [BlsScalar],
+    /// The proof of the opening.
+    pub proof: &'a S::OpeningProof,
+}
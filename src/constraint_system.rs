@@ -9,10 +9,15 @@
 //! tools and abstractions, used by the Composer to generate,
 //! build, preprocess circuits.
 
+pub(crate) mod analysis;
 pub(crate) mod composer;
 pub(crate) mod constraint;
 pub(crate) mod ecc;
+pub(crate) mod hint;
+pub(crate) mod linear_combination;
 pub(crate) mod logic;
+pub(crate) mod memory;
+pub(crate) mod optimizer;
 pub(crate) mod range;
 pub(crate) mod witness;
 
@@ -26,7 +31,11 @@ mod boolean;
 #[cfg(test)]
 pub(crate) mod helper;
 
+pub use analysis::ConstraintReport;
 pub use composer::TurboComposer;
 pub use constraint::Constraint;
 pub use ecc::WitnessPoint;
+pub use linear_combination::LinearCombination;
+pub use memory::Memory;
+pub use optimizer::OptimizationReport;
 pub use witness::Witness;
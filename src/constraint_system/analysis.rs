@@ -0,0 +1,355 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use crate::constraint_system::{TurboComposer, WireData, Witness};
+use dusk_bls12_381::BlsScalar;
+use sp_std::vec::Vec;
+
+/// Number of gates appended by [`TurboComposer::new`], which constrain the
+/// zero witness and the witnesses of the dummy gates.
+const SETUP_GATES: usize = 3;
+
+/// Report of the witnesses and gates of a [`TurboComposer`] that don't
+/// constrain anything, returned by [`TurboComposer::analyze_constraints`].
+///
+/// Every list is sorted, so the report can be compared in tests.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConstraintReport {
+    /// Witnesses that aren't wired into any gate, such as hints that are
+    /// never used.
+    pub unconstrained_witnesses: Vec<Witness>,
+    /// Witnesses that are only wired into gates where the selectors of their
+    /// wires are zero, so they don't take part in any constraint.
+    pub zero_coefficient_witnesses: Vec<Witness>,
+    /// Witnesses that only take part in the constraint of a single gate.
+    ///
+    /// A private input used once is often fine, but an intermediate result
+    /// that only appears in the gate computing it isn't checked against
+    /// anything, so these aren't counted by [`ConstraintReport::is_empty`]
+    /// and are worth reviewing.
+    pub single_gate_witnesses: Vec<Witness>,
+    /// Indexes of the gates whose selectors are all zero, and which aren't
+    /// read by the custom gate preceding them nor carry a public input.
+    pub empty_gates: Vec<usize>,
+    /// Witnesses returned by
+    /// [`append_hint`](TurboComposer::append_hint) that are either
+    /// unconstrained or only wired with zero coefficients.
+    ///
+    /// They're already listed with the other under-constrained witnesses,
+    /// but are reported apart since a hint is never meant to be left
+    /// unchecked.
+    pub unconstrained_hints: Vec<Witness>,
+}
+
+impl ConstraintReport {
+    /// Returns `true` if no under-constrained witness or empty gate was
+    /// found, regardless of the witnesses used in a single gate.
+    pub fn is_empty(&self) -> bool {
+        self.unconstrained_witnesses.is_empty()
+            && self.zero_coefficient_witnesses.is_empty()
+            && self.empty_gates.is_empty()
+    }
+}
+
+impl TurboComposer {
+    /// Walks the gates of the circuit looking for witnesses and gates that
+    /// don't constrain anything, which usually are soundness bugs of the
+    /// gadgets that appended them.
+    ///
+    /// The analysis should run before the circuit is preprocessed, since the
+    /// gates padding the circuit are empty. A witness-only composer, see
+    /// [`TurboComposer::is_witness_only`], doesn't record the gates to analyze,
+    /// so its report is empty.
+    pub fn analyze_constraints(&self) -> ConstraintReport {
+        if self.witness_only {
+            return ConstraintReport::default();
+        }
+
+        let gates = self.n as usize;
+        let zero = BlsScalar::zero();
+
+        // The custom gates constrain all the wires of their own gate. The
+        // range gate also reads the fourth wire of the next gate, and the
+        // logic and ECC gates its left, right and fourth wires, while the
+        // lookup gate doesn't read the next gate
+        let range = |i: usize| self.q_range[i] != zero;
+        let logic_or_ecc = |i: usize| {
+            self.q_logic[i] != zero
+                || self.q_fixed_group_add[i] != zero
+                || self.q_variable_group_add[i] != zero
+        };
+        let custom =
+            |i: usize| range(i) || logic_or_ecc(i) || self.q_lookup[i] != zero;
+        let reads_next = |i: usize| range(i) || logic_or_ecc(i);
+
+        let arithmetic = |i: usize| self.q_arith[i] != zero;
+        let mult = |i: usize| arithmetic(i) && self.q_m[i] != zero;
+
+        // Gates whose constraints read the wire, out of the gate it's in and
+        // the one preceding it
+        let constraining_gates = |wire: &WireData| {
+            let i = match *wire {
+                WireData::Left(i)
+                | WireData::Right(i)
+                | WireData::Output(i)
+                | WireData::Fourth(i) => i,
+            };
+
+            if i >= gates {
+                return (None, None);
+            }
+
+            let previous = i.checked_sub(1);
+            let (coefficient, previous) = match wire {
+                WireData::Left(_) => (
+                    mult(i) || self.q_l[i] != zero,
+                    previous.filter(|j| logic_or_ecc(*j)),
+                ),
+                WireData::Right(_) => (
+                    mult(i) || self.q_r[i] != zero,
+                    previous.filter(|j| logic_or_ecc(*j)),
+                ),
+                WireData::Output(_) => (self.q_o[i] != zero, None),
+                WireData::Fourth(_) => {
+                    (self.q_4[i] != zero, previous.filter(|j| reads_next(*j)))
+                }
+            };
+
+            let current = (arithmetic(i) && coefficient) || custom(i);
+
+            (current.then(|| i), previous)
+        };
+
+        let mut report = ConstraintReport::default();
+
+        self.perm.variable_map.iter().for_each(|(witness, wires)| {
+            if wires.is_empty() {
+                report.unconstrained_witnesses.push(*witness);
+                return;
+            }
+
+            let mut constrained = wires
+                .iter()
+                .map(constraining_gates)
+                .flat_map(|(current, previous)| {
+                    current.into_iter().chain(previous)
+                })
+                .collect::<Vec<_>>();
+            constrained.sort_unstable();
+            constrained.dedup();
+
+            match constrained[..] {
+                [] => report.zero_coefficient_witnesses.push(*witness),
+                [gate] if gate >= SETUP_GATES => {
+                    report.single_gate_witnesses.push(*witness)
+                }
+                _ => (),
+            }
+        });
+
+        report.empty_gates = (0..gates)
+            .filter(|&i| {
+                self.q_m[i] == zero
+                    && self.q_l[i] == zero
+                    && self.q_r[i] == zero
+                    && self.q_o[i] == zero
+                    && self.q_4[i] == zero
+                    && self.q_c[i] == zero
+                    && !custom(i)
+                    && !(i > 0 && reads_next(i - 1))
+                    && !self
+                        .public_inputs_sparse_store
+                        .contains_key(&(i as u32))
+            })
+            .collect();
+
+        report
+            .unconstrained_witnesses
+            .sort_by_key(|witness| witness.index());
+        report
+            .zero_coefficient_witnesses
+            .sort_by_key(|witness| witness.index());
+        report
+            .single_gate_witnesses
+            .sort_by_key(|witness| witness.index());
+
+        report.unconstrained_hints = self
+            .hints
+            .iter()
+            .filter(|hint| {
+                let listed = |witnesses: &[Witness]| {
+                    witnesses
+                        .binary_search_by_key(&hint.index(), Witness::index)
+                        .is_ok()
+                };
+
+                listed(&report.unconstrained_witnesses)
+                    || listed(&report.zero_coefficient_witnesses)
+            })
+            .copied()
+            .collect();
+        report
+            .unconstrained_hints
+            .sort_by_key(|witness| witness.index());
+
+        report
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constraint_system::Constraint;
+    use crate::plonkup::LookupTable;
+
+    #[test]
+    fn test_analyze_sound_circuit() {
+        let mut composer = TurboComposer::new();
+
+        let a = composer.append_witness(BlsScalar::from(3u64));
+        let b = composer.append_witness(BlsScalar::from(4u64));
+
+        let constraint = Constraint::new().mult(1).a(a).b(b);
+        let c = composer.gate_mul(constraint);
+        composer.assert_equal_constant(c, BlsScalar::from(12u64), None);
+
+        composer.component_range(a, 8);
+
+        let report = composer.analyze_constraints();
+
+        assert!(report.is_empty());
+        assert!(report.single_gate_witnesses.contains(&b));
+        assert!(!report.single_gate_witnesses.contains(&a));
+        assert!(!report.single_gate_witnesses.contains(&c));
+    }
+
+    #[test]
+    fn test_analyze_unconstrained_witnesses() {
+        let mut composer = TurboComposer::new();
+
+        let a = composer.append_witness(BlsScalar::from(3u64));
+        let [hint] = composer.append_hint(&[a], |values| [values[0].square()]);
+
+        let report = composer.analyze_constraints();
+
+        assert_eq!(report.unconstrained_witnesses, vec![a, hint]);
+        assert_eq!(report.unconstrained_hints, vec![hint]);
+        assert!(report.zero_coefficient_witnesses.is_empty());
+        assert!(report.empty_gates.is_empty());
+    }
+
+    #[test]
+    fn test_analyze_zero_coefficients() {
+        let mut composer = TurboComposer::new();
+
+        let a = composer.append_witness(BlsScalar::from(3u64));
+        let b = composer.append_witness(BlsScalar::from(4u64));
+
+        // `b` is wired, but with a zero coefficient
+        let constraint = Constraint::new().left(1).a(a).b(b);
+        let c = composer.gate_add(constraint);
+        composer.assert_equal_constant(c, BlsScalar::from(3u64), None);
+
+        let report = composer.analyze_constraints();
+
+        assert!(report.unconstrained_witnesses.is_empty());
+        assert_eq!(report.zero_coefficient_witnesses, vec![b]);
+        assert!(report.empty_gates.is_empty());
+    }
+
+    #[test]
+    fn test_analyze_empty_gates() {
+        let mut composer = TurboComposer::new();
+
+        let a = composer.append_witness(BlsScalar::from(3u64));
+        let gate = composer.gates() as usize;
+        composer.append_gate(Constraint::new().a(a).b(a));
+
+        let report = composer.analyze_constraints();
+
+        assert!(report.unconstrained_witnesses.is_empty());
+        assert_eq!(report.zero_coefficient_witnesses, vec![a]);
+        assert_eq!(report.empty_gates, vec![gate]);
+    }
+
+    #[test]
+    fn test_analyze_single_gate_witnesses() {
+        let mut composer = TurboComposer::new();
+
+        let a = composer.append_witness(BlsScalar::from(3u64));
+        let b = composer.append_witness(BlsScalar::from(4u64));
+
+        let constraint = Constraint::new().mult(1).a(a).b(b);
+        let c = composer.gate_mul(constraint);
+        composer.assert_equal_constant(c, BlsScalar::from(12u64), None);
+
+        // The witnesses of the gates appended by the composer aren't listed
+        let report = composer.analyze_constraints();
+        assert!(report.is_empty());
+        assert_eq!(report.single_gate_witnesses, vec![a, b]);
+
+        let constraint = Constraint::new().left(1).right(1).a(a).b(b);
+        let d = composer.gate_add(constraint);
+        composer.assert_equal_constant(d, BlsScalar::from(7u64), None);
+
+        let report = composer.analyze_constraints();
+        assert!(report.is_empty());
+        assert!(report.single_gate_witnesses.is_empty());
+    }
+
+    #[test]
+    fn test_analyze_range_reads_next_fourth_wire() {
+        let mut composer = TurboComposer::new();
+
+        let a = composer.append_witness(BlsScalar::from(3u64));
+        composer.component_range(a, 8);
+
+        // The last gate of the range component only holds the fourth wire
+        // read by the gate before it, so it isn't empty
+        assert!(composer.analyze_constraints().is_empty());
+
+        // The range gate doesn't read the left wire of the next gate, which
+        // is followed by the gate binding the accumulator to `a`
+        let x = composer.append_witness(BlsScalar::from(5u64));
+        let last = composer.gates() as usize - 2;
+        composer.w_l[last] = x;
+        composer.perm.add_variable_to_map(x, WireData::Left(last));
+
+        let report = composer.analyze_constraints();
+        assert_eq!(report.zero_coefficient_witnesses, vec![x]);
+        assert!(report.empty_gates.is_empty());
+    }
+
+    #[test]
+    fn test_analyze_lookup_doesnt_read_next_gate() {
+        let mut composer = TurboComposer::new();
+
+        let mut table = LookupTable::new();
+        table.insert_multi_mul(0, 2);
+        let table = composer.append_plonkup_table(&table);
+
+        let a = composer.append_witness(BlsScalar::from(2u64));
+        let b = composer.append_witness(BlsScalar::from(3u64));
+        let c = composer.append_witness(BlsScalar::from(2u64));
+        let one = composer.append_witness(BlsScalar::one());
+        composer.append_plonkup_gate(table, a, b, c, one, None);
+
+        // The wires of the lookup gate are constrained by it
+        let report = composer.analyze_constraints();
+        assert!(report.is_empty());
+        assert_eq!(report.single_gate_witnesses, vec![a, b, c, one]);
+
+        // But the gate following it isn't read by the lookup
+        let x = composer.append_witness(BlsScalar::from(5u64));
+        let gate = composer.gates() as usize;
+        composer.append_gate(Constraint::new().a(x).d(x));
+
+        let report = composer.analyze_constraints();
+        assert_eq!(report.zero_coefficient_witnesses, vec![x]);
+        assert_eq!(report.empty_gates, vec![gate]);
+    }
+}
@@ -20,9 +20,11 @@
 
 use crate::constraint_system::{Constraint, Selector, WiredWitness, Witness};
 use crate::permutation::Permutation;
-use crate::plonkup::LookupTable;
+use crate::plonkup::{LookupTable, LookupTableId};
 use dusk_bls12_381::BlsScalar;
+use dusk_bytes::Serializable;
 use hashbrown::HashMap;
+use merlin::Transcript;
 use sp_std::collections::btree_map::BTreeMap;
 use sp_std::vec;
 use sp_std::vec::Vec;
@@ -96,14 +98,27 @@ pub struct TurboComposer {
     /// Fourth wire witness vector.
     pub(crate) w_4: Vec<Witness>,
 
-    /// Public lookup table
-    pub(crate) lookup_table: LookupTable,
+    /// Public lookup tables, indexed by their [`LookupTableId`]. The first
+    /// one holds the rows of the dummy gates.
+    pub(crate) lookup_tables: Vec<LookupTable>,
+    /// Range tables registered by [`TurboComposer::component_range_lookup`],
+    /// indexed by their limb size in bits.
+    pub(crate) range_tables: BTreeMap<usize, LookupTableId>,
 
     /// These are the actual variable values.
     pub(crate) witnesses: HashMap<Witness, BlsScalar>,
+    /// Witnesses appended by [`TurboComposer::append_hint`].
+    pub(crate) hints: Vec<Witness>,
 
     /// Permutation argument.
     pub(crate) perm: Permutation,
+
+    /// Whether the composer only records the witnesses and the wires of the
+    /// circuit, see [`TurboComposer::is_witness_only`].
+    pub(crate) witness_only: bool,
+    /// Whether [`TurboComposer::optimize`] was called on a witness-only
+    /// composer.
+    pub(crate) witness_only_optimized: bool,
 }
 
 impl TurboComposer {
@@ -121,6 +136,18 @@ impl TurboComposer {
         self.n
     }
 
+    /// Returns the size the circuit is padded to when it's preprocessed.
+    ///
+    /// This is the number of gates or the number of rows of the lookup
+    /// tables, whichever is larger, rounded up to the next power of two. It's
+    /// also the minimum degree of the
+    /// [`PublicParameters`](crate::commitment_scheme::PublicParameters)
+    /// needed to preprocess and prove the circuit.
+    pub fn padded_gates(&self) -> usize {
+        core::cmp::max(self.n as usize, self.lookup_table_len())
+            .next_power_of_two()
+    }
+
     /// Evaluate the runtime value of a witness
     ///
     /// # Safety
@@ -128,7 +155,9 @@ impl TurboComposer {
     /// Witness evaluation inside a gadget isn't expected and could produce an
     /// unsound circuit (different circuit representation for the same code).
     ///
-    /// Calling this function performs operations outside the circuit.
+    /// Calling this function performs operations outside the circuit. Use
+    /// [`TurboComposer::append_hint`] to compute new witnesses from the
+    /// values of existing ones instead.
     pub unsafe fn evaluate_witness(&self, witness: &Witness) -> &BlsScalar {
         &self.witnesses[witness]
     }
@@ -155,6 +184,111 @@ impl TurboComposer {
             .copied()
             .collect::<Vec<u32>>()
     }
+
+    /// Returns the values of the public inputs of the circuit in allocation
+    /// order, which is also the order of their positions.
+    ///
+    /// These are the values expected by
+    /// [`VerifierData::dense_public_inputs`](crate::circuit::VerifierData::
+    /// dense_public_inputs) to verify a proof of the circuit.
+    pub fn public_inputs(&self) -> Vec<BlsScalar> {
+        self.public_inputs_sparse_store
+            .values()
+            .map(|pi| -pi)
+            .collect()
+    }
+
+    /// Returns a fingerprint of the shape of the circuit.
+    ///
+    /// The fingerprint is a hash of the selectors, the wiring, the public
+    /// input positions and the lookup tables of the circuit, none of which
+    /// should depend on the values of its witnesses. A gadget branching on
+    /// the value of a witness, as with
+    /// [`TurboComposer::evaluate_witness`], may produce circuits with
+    /// different fingerprints for different witnesses.
+    pub fn fingerprint(&self) -> [u8; 32] {
+        let mut transcript = Transcript::new(b"circuit-fingerprint");
+
+        transcript.append_u64(b"n", self.n as u64);
+
+        let selectors: [(&'static [u8], &Vec<BlsScalar>); 12] = [
+            (b"q_m", &self.q_m),
+            (b"q_l", &self.q_l),
+            (b"q_r", &self.q_r),
+            (b"q_o", &self.q_o),
+            (b"q_4", &self.q_4),
+            (b"q_c", &self.q_c),
+            (b"q_arith", &self.q_arith),
+            (b"q_range", &self.q_range),
+            (b"q_logic", &self.q_logic),
+            (b"q_fixed_group_add", &self.q_fixed_group_add),
+            (b"q_variable_group_add", &self.q_variable_group_add),
+            (b"q_lookup", &self.q_lookup),
+        ];
+
+        selectors.iter().for_each(|(label, selector)| {
+            let mut bytes =
+                Vec::with_capacity(selector.len() * BlsScalar::SIZE);
+            selector
+                .iter()
+                .for_each(|s| bytes.extend_from_slice(&s.to_bytes()));
+
+            transcript.append_message(*label, &bytes);
+        });
+
+        self.append_wires(&mut transcript);
+
+        self.lookup_tables.iter().for_each(|table| {
+            transcript.append_message(b"lookup_table", &table.to_var_bytes());
+        });
+
+        let mut fingerprint = [0u8; 32];
+        transcript.challenge_bytes(b"fingerprint", &mut fingerprint);
+
+        fingerprint
+    }
+
+    /// Returns a digest of the witnesses assigned to the wires of the gates
+    /// and of the public input positions of the circuit.
+    ///
+    /// Unlike [`TurboComposer::fingerprint`], the digest doesn't need the
+    /// selectors of the gates, so it also describes the circuit of a
+    /// witness-only composer, see [`TurboComposer::is_witness_only`].
+    pub fn wires_digest(&self) -> [u8; 32] {
+        let mut transcript = Transcript::new(b"circuit-wires-digest");
+
+        transcript.append_u64(b"n", self.n as u64);
+        self.append_wires(&mut transcript);
+
+        let mut digest = [0u8; 32];
+        transcript.challenge_bytes(b"wires_digest", &mut digest);
+
+        digest
+    }
+
+    /// Appends the indexes of the witnesses of every wire and the public
+    /// input positions to the `transcript`.
+    fn append_wires(&self, transcript: &mut Transcript) {
+        let wires: [(&'static [u8], &Vec<Witness>); 4] = [
+            (b"w_l", &self.w_l),
+            (b"w_r", &self.w_r),
+            (b"w_o", &self.w_o),
+            (b"w_4", &self.w_4),
+        ];
+
+        wires.iter().for_each(|(label, wire)| {
+            let mut bytes = Vec::with_capacity(wire.len() * u64::SIZE);
+            wire.iter().for_each(|w| {
+                bytes.extend_from_slice(&(w.index() as u64).to_le_bytes())
+            });
+
+            transcript.append_message(*label, &bytes);
+        });
+
+        self.public_inputs_sparse_store.keys().for_each(|pos| {
+            transcript.append_u64(b"public_input", *pos as u64);
+        });
+    }
 }
 
 impl Default for TurboComposer {
@@ -176,6 +310,35 @@ impl TurboComposer {
         TurboComposer::with_size(0)
     }
 
+    /// Generates a new empty `TurboComposer` that only records the values of
+    /// the witnesses, the wires of the gates and the public inputs of the
+    /// circuit.
+    pub(crate) fn witness_only() -> Self {
+        TurboComposer::init(0, true)
+    }
+
+    /// Returns `true` if the composer only records the values of the
+    /// witnesses, the wires of the gates and the public inputs of the
+    /// circuit.
+    ///
+    /// The selectors, the permutation and the lookup tables of the circuit
+    /// aren't built by a witness-only composer, so it can't be preprocessed.
+    /// It's meant to replay a gadget whose
+    /// [`ProverKey`](crate::proof_system::ProverKey) was already computed,
+    /// which already holds them, in order to prove it again with new
+    /// witnesses.
+    ///
+    /// Since the selectors and the permutation aren't recorded,
+    /// [`TurboComposer::fingerprint`],
+    /// [`TurboComposer::analyze_constraints`],
+    /// [`TurboComposer::unconstrained_hints`] and [`TurboComposer::optimize`]
+    /// don't describe the circuit of a witness-only composer. A gadget that
+    /// optimizes its circuit can't be replayed, as the wires it records
+    /// aren't the optimized ones, so proving it fails.
+    pub const fn is_witness_only(&self) -> bool {
+        self.witness_only
+    }
+
     /// Constrain a scalar into the circuit description and return an allocated
     /// [`Witness`] with its value
     pub fn append_constant(&mut self, value: BlsScalar) -> Witness {
@@ -186,11 +349,32 @@ impl TurboComposer {
         witness
     }
 
+    /// Allocate a public input into the composer and return the [`Witness`]
+    /// holding its value.
+    ///
+    /// The witness is bound to the public input by a gate of its own, so it
+    /// can be wired into any gadget like a private witness. Public inputs are
+    /// listed by [`TurboComposer::public_inputs`] in allocation order.
+    pub fn append_public<T: Into<BlsScalar>>(&mut self, public: T) -> Witness {
+        let public = public.into();
+        let witness = self.append_witness(public);
+
+        self.assert_equal_constant(witness, BlsScalar::zero(), Some(-public));
+
+        witness
+    }
+
     /// Creates a new circuit with an expected circuit size.
     /// This will allow for less reallocations when building the circuit
     /// since the `Vec`s will already have an appropriate allocation at the
     /// beginning of the composing stage.
     pub(crate) fn with_size(size: usize) -> Self {
+        TurboComposer::init(size, false)
+    }
+
+    /// Creates a new circuit with an expected circuit size, either recording
+    /// all of its description or only its witnesses.
+    fn init(size: usize, witness_only: bool) -> Self {
         let mut composer = TurboComposer {
             n: 0,
 
@@ -213,11 +397,16 @@ impl TurboComposer {
             w_o: Vec::with_capacity(size),
             w_4: Vec::with_capacity(size),
 
-            lookup_table: LookupTable::new(),
+            lookup_tables: vec![LookupTable::new()],
+            range_tables: BTreeMap::new(),
 
             witnesses: HashMap::with_capacity(size),
+            hints: Vec::new(),
 
             perm: Permutation::new(),
+
+            witness_only,
+            witness_only_optimized: false,
         };
 
         // Reserve the first witness to be zero
@@ -233,8 +422,13 @@ impl TurboComposer {
     pub fn append_witness<T: Into<BlsScalar>>(&mut self, scalar: T) -> Witness {
         let scalar = scalar.into();
 
-        // Get a new Witness from the permutation
-        let var = self.perm.new_variable();
+        // Get a new Witness from the permutation, unless the permutation isn't
+        // recorded, in which case the witnesses are indexed in the same order
+        let var = if self.witness_only {
+            Witness::new(self.witnesses.len())
+        } else {
+            self.perm.new_variable()
+        };
 
         // The composer now links the BlsScalar to the Witness returned from
         // the Permutation
@@ -275,26 +469,28 @@ impl TurboComposer {
         self.w_o.push(o);
         self.w_4.push(d);
 
-        // Add selector vectors
-        self.q_m.push(q_m);
-        self.q_l.push(q_l);
-        self.q_r.push(q_r);
-        self.q_o.push(q_o);
-        self.q_4.push(q_4);
-        self.q_c.push(q_c);
-
-        self.q_arith.push(q_arith);
-        self.q_range.push(q_range);
-        self.q_logic.push(q_logic);
-        self.q_fixed_group_add.push(q_fixed_group_add);
-        self.q_variable_group_add.push(q_variable_group_add);
-        self.q_lookup.push(q_lookup);
-
         if s.has_public_input() {
             self.public_inputs_sparse_store.insert(self.n as u32, pi);
         }
 
-        self.perm.add_variables_to_map(a, b, o, d, self.n as usize);
+        if !self.witness_only {
+            // Add selector vectors
+            self.q_m.push(q_m);
+            self.q_l.push(q_l);
+            self.q_r.push(q_r);
+            self.q_o.push(q_o);
+            self.q_4.push(q_4);
+            self.q_c.push(q_c);
+
+            self.q_arith.push(q_arith);
+            self.q_range.push(q_range);
+            self.q_logic.push(q_logic);
+            self.q_fixed_group_add.push(q_fixed_group_add);
+            self.q_variable_group_add.push(q_variable_group_add);
+            self.q_lookup.push(q_lookup);
+
+            self.perm.add_variables_to_map(a, b, o, d, self.n as usize);
+        }
 
         self.n += 1;
     }
@@ -470,18 +666,21 @@ impl TurboComposer {
     /// description which are guaranteed to always satisfy the gate equation.
     pub fn append_dummy_gates(&mut self) {
         // Add a dummy constraint so that we do not have zero polynomials
-        self.q_m.push(BlsScalar::from(1));
-        self.q_l.push(BlsScalar::from(2));
-        self.q_r.push(BlsScalar::from(3));
-        self.q_o.push(BlsScalar::from(4));
-        self.q_c.push(BlsScalar::from(4));
-        self.q_4.push(BlsScalar::one());
-        self.q_arith.push(BlsScalar::one());
-        self.q_range.push(BlsScalar::zero());
-        self.q_logic.push(BlsScalar::zero());
-        self.q_fixed_group_add.push(BlsScalar::zero());
-        self.q_variable_group_add.push(BlsScalar::zero());
-        self.q_lookup.push(BlsScalar::one());
+        if !self.witness_only {
+            self.q_m.push(BlsScalar::from(1));
+            self.q_l.push(BlsScalar::from(2));
+            self.q_r.push(BlsScalar::from(3));
+            self.q_o.push(BlsScalar::from(4));
+            self.q_c.push(BlsScalar::from(4));
+            self.q_4.push(BlsScalar::one());
+            self.q_arith.push(BlsScalar::one());
+            self.q_range.push(BlsScalar::zero());
+            self.q_logic.push(BlsScalar::zero());
+            self.q_fixed_group_add.push(BlsScalar::zero());
+            self.q_variable_group_add.push(BlsScalar::zero());
+            self.q_lookup.push(BlsScalar::zero());
+        }
+
         let var_six = self.append_witness(BlsScalar::from(6));
         let var_one = self.append_witness(BlsScalar::from(1));
         let var_seven = self.append_witness(BlsScalar::from(7));
@@ -490,71 +689,68 @@ impl TurboComposer {
         self.w_r.push(var_seven);
         self.w_o.push(var_min_twenty);
         self.w_4.push(var_one);
-        self.perm.add_variables_to_map(
-            var_six,
-            var_seven,
-            var_min_twenty,
-            var_one,
-            self.n as usize,
-        );
+        if !self.witness_only {
+            self.perm.add_variables_to_map(
+                var_six,
+                var_seven,
+                var_min_twenty,
+                var_one,
+                self.n as usize,
+            );
+        }
         self.n += 1;
         //Add another dummy constraint so that we do not get the identity
         // permutation
-        self.q_m.push(BlsScalar::from(1));
-        self.q_l.push(BlsScalar::from(1));
-        self.q_r.push(BlsScalar::from(1));
-        self.q_o.push(BlsScalar::from(1));
-        self.q_c.push(BlsScalar::from(127));
-        self.q_4.push(BlsScalar::zero());
-        self.q_arith.push(BlsScalar::one());
-        self.q_range.push(BlsScalar::zero());
-        self.q_logic.push(BlsScalar::zero());
-        self.q_fixed_group_add.push(BlsScalar::zero());
-        self.q_variable_group_add.push(BlsScalar::zero());
-        self.q_lookup.push(BlsScalar::one());
+        if !self.witness_only {
+            self.q_m.push(BlsScalar::from(1));
+            self.q_l.push(BlsScalar::from(1));
+            self.q_r.push(BlsScalar::from(1));
+            self.q_o.push(BlsScalar::from(1));
+            self.q_c.push(BlsScalar::from(127));
+            self.q_4.push(BlsScalar::zero());
+            self.q_arith.push(BlsScalar::one());
+            self.q_range.push(BlsScalar::zero());
+            self.q_logic.push(BlsScalar::zero());
+            self.q_fixed_group_add.push(BlsScalar::zero());
+            self.q_variable_group_add.push(BlsScalar::zero());
+            self.q_lookup.push(BlsScalar::zero());
+        }
         self.w_l.push(var_min_twenty);
         self.w_r.push(var_six);
         self.w_o.push(var_seven);
         self.w_4.push(Self::constant_zero());
-        self.perm.add_variables_to_map(
-            var_min_twenty,
-            var_six,
-            var_seven,
-            Self::constant_zero(),
-            self.n as usize,
-        );
+        if !self.witness_only {
+            self.perm.add_variables_to_map(
+                var_min_twenty,
+                var_six,
+                var_seven,
+                Self::constant_zero(),
+                self.n as usize,
+            );
 
-        // Add dummy rows to lookup table
-        // Notice two rows here match dummy wire values above
-        self.lookup_table.0.insert(
-            0,
-            [
-                BlsScalar::from(6),
-                BlsScalar::from(7),
-                -BlsScalar::from(20),
+            // Add dummy rows to lookup table, so the table is never empty.
+            // The dummy gates don't perform lookups, otherwise every circuit
+            // would have to carry the lookup argument in its proofs
+            let table = &mut self.lookup_tables[0];
+            table.insert_special_row(
+                BlsScalar::from(3),
                 BlsScalar::from(1),
-            ],
-        );
-
-        self.lookup_table.0.insert(
-            0,
-            [
+                BlsScalar::from(4),
+                BlsScalar::from(9),
+            );
+            table.insert_special_row(
                 -BlsScalar::from(20),
                 BlsScalar::from(6),
                 BlsScalar::from(7),
                 BlsScalar::from(0),
-            ],
-        );
-
-        self.lookup_table.0.insert(
-            0,
-            [
-                BlsScalar::from(3),
+            );
+            table.insert_special_row(
+                BlsScalar::from(6),
+                BlsScalar::from(7),
+                -BlsScalar::from(20),
                 BlsScalar::from(1),
-                BlsScalar::from(4),
-                BlsScalar::from(9),
-            ],
-        );
+            );
+        }
 
         self.n += 1;
     }
@@ -732,8 +928,12 @@ impl TurboComposer {
     /// the largest amount of performance and the minimum circuit-size
     /// possible. Since it allows the end-user to set every selector coefficient
     /// as scaling value on the gate eq.
+    ///
+    /// The gate is satisfied only if `(a, b, c, d)` is a row of the table
+    /// registered as `table`.
     pub fn append_plonkup_gate(
         &mut self,
+        table: LookupTableId,
         a: Witness,
         b: Witness,
         c: Witness,
@@ -745,41 +945,65 @@ impl TurboComposer {
         self.w_o.push(c);
         self.w_4.push(d);
 
-        // Add selector vectors
-        self.q_l.push(BlsScalar::zero());
-        self.q_r.push(BlsScalar::zero());
-        self.q_o.push(BlsScalar::zero());
-        self.q_c.push(BlsScalar::zero());
-        self.q_4.push(BlsScalar::zero());
-        self.q_arith.push(BlsScalar::zero());
-        self.q_m.push(BlsScalar::zero());
-        self.q_range.push(BlsScalar::zero());
-        self.q_logic.push(BlsScalar::zero());
-        self.q_fixed_group_add.push(BlsScalar::zero());
-        self.q_variable_group_add.push(BlsScalar::zero());
-
-        // For a lookup gate, only one selector poly is
-        // turned on as the output is inputted directly
-        self.q_lookup.push(BlsScalar::one());
-
         if let Some(pi) = pi {
             debug_assert!(self.public_inputs_sparse_store.get(&self.n).is_none(), "The invariant of already having a PI inserted for this position should never exist");
 
             self.public_inputs_sparse_store.insert(self.n, pi);
         }
 
-        self.perm.add_variables_to_map(a, b, c, d, self.n as usize);
+        if !self.witness_only {
+            // Add selector vectors
+            self.q_l.push(BlsScalar::zero());
+            self.q_r.push(BlsScalar::zero());
+            self.q_o.push(BlsScalar::zero());
+            self.q_4.push(BlsScalar::zero());
+            self.q_arith.push(BlsScalar::zero());
+            self.q_m.push(BlsScalar::zero());
+            self.q_range.push(BlsScalar::zero());
+            self.q_logic.push(BlsScalar::zero());
+            self.q_fixed_group_add.push(BlsScalar::zero());
+            self.q_variable_group_add.push(BlsScalar::zero());
+
+            // For a lookup gate, only one selector poly is
+            // turned on as the output is inputted directly
+            self.q_lookup.push(BlsScalar::one());
+
+            // The constant selector isn't used by lookup gates, so it holds the
+            // id of the queried table
+            self.q_c.push(table.into());
+
+            self.perm.add_variables_to_map(a, b, c, d, self.n as usize);
+        }
 
         self.n += 1;
 
         c
     }
 
-    /// When [`TurboComposer`] is initialised, it spawns a dummy table
-    /// with 3 entries that should not be removed. This function appends
-    /// its input table to the composer's dummy table
-    pub fn append_plonkup_table(&mut self, table: &LookupTable) {
-        table.0.iter().for_each(|k| self.lookup_table.0.push(*k))
+    /// Registers a lookup table in the circuit, returning the
+    /// [`LookupTableId`] plonkup gates use to query it.
+    ///
+    /// The tables of a circuit are kept apart from each other, so a gate
+    /// querying one of them can't be satisfied by a row of another. When
+    /// [`TurboComposer`] is initialised, it spawns a dummy table with 3
+    /// entries that should not be removed.
+    pub fn append_plonkup_table(
+        &mut self,
+        table: &LookupTable,
+    ) -> LookupTableId {
+        let id = LookupTableId(self.lookup_tables.len() as u32);
+
+        // The tables of a witness-only composer are held by its `ProverKey`
+        if !self.witness_only {
+            self.lookup_tables.push(table.clone());
+        }
+
+        id
+    }
+
+    /// Returns the number of rows of all the lookup tables of the circuit.
+    pub(crate) fn lookup_table_len(&self) -> usize {
+        self.lookup_tables.iter().map(|table| table.len()).sum()
     }
 }
 
@@ -790,6 +1014,7 @@ mod tests {
     use crate::commitment_scheme::PublicParameters;
     use crate::constraint_system::helper::*;
     use crate::error::Error;
+    use crate::plonkup::LookupArgument;
     use crate::proof_system::{Prover, Verifier};
     use rand_core::OsRng;
 
@@ -821,6 +1046,51 @@ mod tests {
         assert!(res.is_ok());
     }
 
+    #[test]
+    fn test_append_public() {
+        let res = gadget_tester(
+            |composer| {
+                let a = composer.append_public(BlsScalar::from(10u64));
+                let b = composer.append_witness(BlsScalar::from(20u64));
+                let c = composer.append_public(BlsScalar::from(30u64));
+
+                // The public witnesses are wired like any other witness
+                let constraint = Constraint::new().left(1).right(1).a(a).b(b);
+                let sum = composer.gate_add(constraint);
+                composer.assert_equal(sum, c);
+
+                let point = composer.append_public_point(
+                    dusk_jubjub::GENERATOR_EXTENDED
+                        * dusk_jubjub::JubJubScalar::from(2u64),
+                );
+                let two = composer.append_witness(BlsScalar::from(2u64));
+                let mul = composer.component_mul_generator(
+                    two,
+                    dusk_jubjub::GENERATOR_EXTENDED,
+                );
+                composer.assert_equal_point(mul, point);
+            },
+            1 << 10,
+        );
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn test_public_inputs_allocation_order() {
+        let mut composer = TurboComposer::new();
+
+        let values: Vec<BlsScalar> =
+            (1..5u64).rev().map(BlsScalar::from).collect();
+
+        values.iter().for_each(|value| {
+            composer.append_public(*value);
+            composer.append_constant(*value);
+        });
+
+        assert_eq!(composer.public_inputs(), values);
+        assert_eq!(composer.public_input_indexes().len(), values.len());
+    }
+
     #[test]
     fn test_component_select() {
         let res = gadget_tester(
@@ -866,7 +1136,7 @@ mod tests {
             BlsScalar::from(10),
         );
         let res = gadget_plonkup_tester(
-            |composer| {
+            |composer, _| {
                 let bit_1 = composer.append_witness(BlsScalar::one());
                 let bit_0 = TurboComposer::constant_zero();
 
@@ -898,12 +1168,13 @@ mod tests {
             BlsScalar::from(12),
         );
         let res = gadget_plonkup_tester(
-            |composer| {
+            |composer, table| {
                 let twelve = composer.append_constant(BlsScalar::from(12));
                 let three = composer.append_constant(BlsScalar::from(3));
 
-                composer
-                    .append_plonkup_gate(twelve, twelve, twelve, three, None);
+                composer.append_plonkup_gate(
+                    table, twelve, twelve, twelve, three, None,
+                );
             },
             65,
             t,
@@ -967,12 +1238,14 @@ mod tests {
         // Create a prover struct
         let mut prover = Prover::new(b"test");
 
-        prover.cs.lookup_table.insert_multi_mul(0, 3);
+        let mut table = LookupTable::new();
+        table.insert_multi_mul(0, 3);
+        let id = prover.cs.append_plonkup_table(&table);
 
         // add to trans
         prover.key_transcript(b"key", b"additional seed information");
 
-        let output = prover.cs.lookup_table.lookup(
+        let output = table.lookup(
             BlsScalar::from(2),
             BlsScalar::from(3),
             BlsScalar::one(),
@@ -983,11 +1256,21 @@ mod tests {
         let result = prover.cs.append_constant(output.unwrap());
         let one = prover.cs.append_constant(BlsScalar::one());
 
-        prover.cs.append_plonkup_gate(two, three, result, one, None);
-        prover.cs.append_plonkup_gate(two, three, result, one, None);
-        prover.cs.append_plonkup_gate(two, three, result, one, None);
-        prover.cs.append_plonkup_gate(two, three, result, one, None);
-        prover.cs.append_plonkup_gate(two, three, result, one, None);
+        prover
+            .cs
+            .append_plonkup_gate(id, two, three, result, one, None);
+        prover
+            .cs
+            .append_plonkup_gate(id, two, three, result, one, None);
+        prover
+            .cs
+            .append_plonkup_gate(id, two, three, result, one, None);
+        prover
+            .cs
+            .append_plonkup_gate(id, two, three, result, one, None);
+        prover
+            .cs
+            .append_plonkup_gate(id, two, three, result, one, None);
 
         let constraint = Constraint::new().left(1).right(1).a(two).b(three);
         prover.cs.gate_add(constraint);
@@ -1015,11 +1298,14 @@ mod tests {
         let mut verifier = Verifier::new(b"test");
 
         // Add gadgets
+        let mut table = LookupTable::new();
+        table.insert_multi_mul(0, 3);
+
         dummy_gadget_plonkup(4, prover.composer_mut());
-        prover.cs.lookup_table.insert_multi_mul(0, 3);
+        prover.cs.append_plonkup_table(&table);
 
         dummy_gadget_plonkup(4, verifier.composer_mut());
-        verifier.cs.lookup_table.insert_multi_mul(0, 3);
+        verifier.cs.append_plonkup_table(&table);
 
         // Commit and verifier key
         let (ck, vk) = public_parameters.trim(1 << 7)?;
@@ -1036,4 +1322,113 @@ mod tests {
 
         Ok(())
     }
+
+    fn replay_gadget(composer: &mut TurboComposer, a: u64, b: u64) {
+        let mut table = LookupTable::new();
+        table.insert_multi_mul(0, 3);
+        let id = composer.append_plonkup_table(&table);
+
+        let one = BlsScalar::one();
+        let product = table
+            .lookup(BlsScalar::from(a), BlsScalar::from(b), one)
+            .expect("the operands are in the table");
+
+        let a = composer.append_witness(BlsScalar::from(a));
+        let b = composer.append_witness(BlsScalar::from(b));
+        let one = composer.append_witness(one);
+        let product = composer.append_public(product);
+
+        composer.append_plonkup_gate(id, a, b, product, one, None);
+        composer.component_range_lookup(product, 6, 3);
+
+        composer.component_range(a, 4);
+        composer.component_xor(a, b, 4);
+        composer.component_and(a, b, 4);
+
+        let generator = composer.append_point(dusk_jubjub::GENERATOR);
+        let double = composer.component_add_point(generator, generator);
+        composer.assert_equal_public_point(
+            double,
+            dusk_jubjub::GENERATOR_EXTENDED.double(),
+        );
+    }
+
+    #[test]
+    fn test_witness_only_composer() {
+        let mut composer = TurboComposer::new();
+        replay_gadget(&mut composer, 6, 7);
+
+        let mut witness_only = TurboComposer::witness_only();
+        replay_gadget(&mut witness_only, 6, 7);
+
+        // The witnesses and the wires are recorded the same way
+        assert!(witness_only.is_witness_only());
+        assert_eq!(witness_only.gates(), composer.gates());
+        assert_eq!(witness_only.witnesses, composer.witnesses);
+        assert_eq!(witness_only.w_l, composer.w_l);
+        assert_eq!(witness_only.w_r, composer.w_r);
+        assert_eq!(witness_only.w_o, composer.w_o);
+        assert_eq!(witness_only.w_4, composer.w_4);
+        assert_eq!(witness_only.public_inputs(), composer.public_inputs());
+
+        // But the selectors, the permutation and the tables aren't
+        assert!(witness_only.q_m.is_empty());
+        assert!(witness_only.q_lookup.is_empty());
+        assert!(witness_only.perm.variable_map.is_empty());
+        assert_eq!(witness_only.lookup_table_len(), 0);
+    }
+
+    #[test]
+    fn test_witness_only_replay() -> Result<(), Error> {
+        let public_parameters = PublicParameters::setup(1 << 9, &mut OsRng)?;
+        let (ck, ok) = public_parameters.trim(1 << 8)?;
+
+        let arguments = [LookupArgument::Plookup, LookupArgument::LogUp];
+
+        for argument in arguments.iter().copied() {
+            let mut prover = Prover::new(b"replay");
+            prover.set_lookup_argument(argument);
+            replay_gadget(prover.composer_mut(), 3, 5);
+            let vk = prover.preprocess(&ck)?;
+            let prover_key = prover.prover_key.take().unwrap();
+
+            let mut verifier = Verifier::new(b"replay");
+            verifier.verifier_key = Some(vk);
+
+            // The argument is taken from the key
+            let mut replay = Prover::witness_only(b"replay");
+            replay.prover_key = Some(prover_key.clone());
+
+            replay_gadget(replay.composer_mut(), 6, 7);
+
+            let mut public_inputs = replay.cs.to_dense_public_inputs();
+            public_inputs
+                .resize(prover_key.padded_gates() as usize, BlsScalar::zero());
+
+            let proof = replay.prove(&ck)?;
+            assert_eq!(proof.lookup_argument(), argument);
+            verifier.verify(&proof, &ok, &public_inputs)?;
+
+            // The replay keeps recording only the witnesses
+            assert!(replay.cs.is_witness_only());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_witness_only_preprocess() {
+        let public_parameters =
+            PublicParameters::setup(1 << 6, &mut OsRng).unwrap();
+        let (ck, _) = public_parameters.trim(1 << 5).unwrap();
+
+        let mut prover = Prover::witness_only(b"replay");
+        dummy_gadget(4, prover.composer_mut());
+
+        assert!(matches!(
+            prover.preprocess(&ck),
+            Err(Error::WitnessOnlyComposer)
+        ));
+        assert!(matches!(prover.prove(&ck), Err(Error::WitnessOnlyComposer)));
+    }
 }
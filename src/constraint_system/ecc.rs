@@ -54,21 +54,11 @@ impl TurboComposer {
         affine: P,
     ) -> WitnessPoint {
         let affine = affine.into();
-        let point = self.append_point(affine);
 
-        self.assert_equal_constant(
-            point.x,
-            BlsScalar::zero(),
-            Some(-affine.get_x()),
-        );
-
-        self.assert_equal_constant(
-            point.y,
-            BlsScalar::zero(),
-            Some(-affine.get_y()),
-        );
+        let x = self.append_public(affine.get_x());
+        let y = self.append_public(affine.get_y());
 
-        point
+        WitnessPoint { x, y }
     }
 
     /// Constrain a point into the circuit description and return an allocated
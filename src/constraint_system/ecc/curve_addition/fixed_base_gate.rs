@@ -47,28 +47,30 @@ impl TurboComposer {
         self.w_o.push(wnaf_round.xy_alpha.into());
         self.w_4.push(wnaf_round.accumulated_bit.into());
 
-        self.q_l.push(wnaf_round.x_beta);
-        self.q_r.push(wnaf_round.y_beta);
+        if !self.witness_only {
+            self.q_l.push(wnaf_round.x_beta);
+            self.q_r.push(wnaf_round.y_beta);
 
-        self.q_c.push(wnaf_round.xy_beta);
-        self.q_o.push(BlsScalar::zero());
-        self.q_fixed_group_add.push(BlsScalar::one());
-        self.q_variable_group_add.push(BlsScalar::zero());
+            self.q_c.push(wnaf_round.xy_beta);
+            self.q_o.push(BlsScalar::zero());
+            self.q_fixed_group_add.push(BlsScalar::one());
+            self.q_variable_group_add.push(BlsScalar::zero());
 
-        self.q_m.push(BlsScalar::zero());
-        self.q_4.push(BlsScalar::zero());
-        self.q_arith.push(BlsScalar::zero());
-        self.q_range.push(BlsScalar::zero());
-        self.q_logic.push(BlsScalar::zero());
-        self.q_lookup.push(BlsScalar::zero());
+            self.q_m.push(BlsScalar::zero());
+            self.q_4.push(BlsScalar::zero());
+            self.q_arith.push(BlsScalar::zero());
+            self.q_range.push(BlsScalar::zero());
+            self.q_logic.push(BlsScalar::zero());
+            self.q_lookup.push(BlsScalar::zero());
 
-        self.perm.add_variables_to_map(
-            wnaf_round.acc_x,
-            wnaf_round.acc_y,
-            wnaf_round.xy_alpha,
-            wnaf_round.accumulated_bit,
-            self.n as usize,
-        );
+            self.perm.add_variables_to_map(
+                wnaf_round.acc_x,
+                wnaf_round.acc_y,
+                wnaf_round.xy_alpha,
+                wnaf_round.accumulated_bit,
+                self.n as usize,
+            );
+        }
 
         self.n += 1;
     }
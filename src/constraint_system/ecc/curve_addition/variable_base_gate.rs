@@ -55,36 +55,36 @@ impl TurboComposer {
         self.w_r.extend(&[y_1, y_3]);
         self.w_o.extend(&[x_2, Self::constant_zero()]);
         self.w_4.extend(&[y_2, x_1_y_2]);
-        let zeros = [BlsScalar::zero(), BlsScalar::zero()];
-
-        self.q_l.extend(&zeros);
-        self.q_r.extend(&zeros);
-        self.q_c.extend(&zeros);
-        self.q_o.extend(&zeros);
-        self.q_m.extend(&zeros);
-        self.q_4.extend(&zeros);
-        self.q_arith.extend(&zeros);
-        self.q_range.extend(&zeros);
-        self.q_logic.extend(&zeros);
-        self.q_fixed_group_add.extend(&zeros);
-        self.q_lookup.extend(&zeros);
-
-        self.q_variable_group_add.push(BlsScalar::one());
-        self.q_variable_group_add.push(BlsScalar::zero());
-
-        self.perm
-            .add_variables_to_map(x_1, y_1, x_2, y_2, self.n as usize);
-        self.n += 1;
-
-        self.perm.add_variables_to_map(
-            x_3,
-            y_3,
-            Self::constant_zero(),
-            x_1_y_2,
-            self.n as usize,
-        );
-
-        self.n += 1;
+        if !self.witness_only {
+            let zeros = [BlsScalar::zero(), BlsScalar::zero()];
+
+            self.q_l.extend(&zeros);
+            self.q_r.extend(&zeros);
+            self.q_c.extend(&zeros);
+            self.q_o.extend(&zeros);
+            self.q_m.extend(&zeros);
+            self.q_4.extend(&zeros);
+            self.q_arith.extend(&zeros);
+            self.q_range.extend(&zeros);
+            self.q_logic.extend(&zeros);
+            self.q_fixed_group_add.extend(&zeros);
+            self.q_lookup.extend(&zeros);
+
+            self.q_variable_group_add.push(BlsScalar::one());
+            self.q_variable_group_add.push(BlsScalar::zero());
+
+            self.perm
+                .add_variables_to_map(x_1, y_1, x_2, y_2, self.n as usize);
+            self.perm.add_variables_to_map(
+                x_3,
+                y_3,
+                Self::constant_zero(),
+                x_1_y_2,
+                self.n as usize + 1,
+            );
+        }
+
+        self.n += 2;
 
         WitnessPoint { x: x_3, y: y_3 }
     }
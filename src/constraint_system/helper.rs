@@ -8,7 +8,7 @@ use super::TurboComposer;
 use crate::commitment_scheme::PublicParameters;
 use crate::constraint_system::Constraint;
 use crate::error::Error;
-use crate::plonkup::LookupTable;
+use crate::plonkup::{LookupTable, LookupTableId};
 use crate::proof_system::{Prover, Verifier};
 use dusk_bls12_381::BlsScalar;
 use rand_core::OsRng;
@@ -95,10 +95,10 @@ pub(crate) fn gadget_tester(
 }
 
 /// Takes a generic gadget function with no auxillary input and
-/// tests whether it passes an end-to-end test. If using a lookup table,
-/// all plonkup gates must correspond to rows in lookup_table
+/// tests whether it passes an end-to-end test. The gadget is given the id
+/// of `lookup_table`, and all its plonkup gates must correspond to rows in it
 pub(crate) fn gadget_plonkup_tester(
-    gadget: fn(composer: &mut TurboComposer),
+    gadget: fn(composer: &mut TurboComposer, table: LookupTableId),
     n: usize,
     lookup_table: LookupTable,
 ) -> Result<(), Error> {
@@ -110,13 +110,13 @@ pub(crate) fn gadget_plonkup_tester(
         let mut prover = Prover::new(b"demo");
 
         // Add lookup table to the composer
-        prover.composer_mut().append_plonkup_table(&lookup_table);
+        let table = prover.composer_mut().append_plonkup_table(&lookup_table);
 
         // Additionally key the transcript
         prover.key_transcript(b"key", b"additional seed information");
 
         // Add gadgets
-        gadget(&mut prover.composer_mut());
+        gadget(&mut prover.composer_mut(), table);
 
         // Commit Key
         let (ck, _) = public_parameters
@@ -138,13 +138,13 @@ pub(crate) fn gadget_plonkup_tester(
     let mut verifier = Verifier::new(b"demo");
 
     // Add lookup table to the composer
-    verifier.composer_mut().append_plonkup_table(&lookup_table);
+    let table = verifier.composer_mut().append_plonkup_table(&lookup_table);
 
     // Additionally key the transcript
     verifier.key_transcript(b"key", b"additional seed information");
 
     // Add gadgets
-    gadget(&mut verifier.composer_mut());
+    gadget(&mut verifier.composer_mut(), table);
 
     // Compute Commit and Verifier Key
     let (ck, vk) = public_parameters
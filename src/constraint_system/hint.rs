@@ -0,0 +1,200 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use crate::constraint_system::{TurboComposer, Witness};
+use crate::error::Error;
+use dusk_bls12_381::BlsScalar;
+use sp_std::vec::Vec;
+
+impl TurboComposer {
+    /// Appends witnesses computed out of the circuit from the values of the
+    /// `inputs`, such as inverses, square roots, quotients or bit
+    /// decompositions.
+    ///
+    /// The `hint` receives the values of the `inputs` in the same order and
+    /// returns the values of the new witnesses. It only runs when the values
+    /// of all the `inputs` are present in the composer; otherwise the new
+    /// witnesses are allocated as zero.
+    ///
+    /// A hint doesn't constrain the witnesses it returns: they are
+    /// unconstrained until they take part in the constraint of a gate, and
+    /// the gadget must add the gates proving they're correct. Hints that
+    /// don't are listed by [`TurboComposer::unconstrained_hints`] and by the
+    /// [`ConstraintReport`](crate::constraint_system::ConstraintReport) of
+    /// the circuit, and [`TurboComposer::check_hints`] fails with
+    /// [`Error::UnconstrainedHint`] if there is any.
+    ///
+    /// The gadget may run with placeholder values when the circuit is
+    /// compiled, so the `hint` shouldn't panic on any input, e.g. an inverse
+    /// hint should map zero to zero.
+    pub fn append_hint<F, const N: usize>(
+        &mut self,
+        inputs: &[Witness],
+        hint: F,
+    ) -> [Witness; N]
+    where
+        F: FnOnce(&[BlsScalar]) -> [BlsScalar; N],
+    {
+        let values = inputs
+            .iter()
+            .map(|input| self.witnesses.get(input).copied())
+            .collect::<Option<Vec<_>>>()
+            .map(|values| hint(&values))
+            .unwrap_or([BlsScalar::zero(); N]);
+
+        let mut witnesses = [Self::constant_zero(); N];
+
+        witnesses
+            .iter_mut()
+            .zip(values.iter())
+            .for_each(|(w, value)| {
+                *w = self.append_witness(*value);
+                self.hints.push(*w);
+            });
+
+        witnesses
+    }
+
+    /// Returns the witnesses returned by [`TurboComposer::append_hint`] that
+    /// don't take part in any constraint of the circuit, either because
+    /// they aren't used in any gate or because the selectors of their wires
+    /// are zero.
+    ///
+    /// An unconstrained hint can take any value in a proof, so it's usually a
+    /// soundness bug of the gadget that appended it. The same hints are
+    /// reported by [`TurboComposer::analyze_constraints`].
+    pub fn unconstrained_hints(&self) -> Vec<Witness> {
+        self.analyze_constraints().unconstrained_hints
+    }
+
+    /// Checks that all the hints are constrained, returning
+    /// [`Error::UnconstrainedHint`] with the first one that isn't.
+    ///
+    /// Unconstrained hints don't stop a circuit from being preprocessed, so a
+    /// gadget can call this to refuse to build a circuit with any of them.
+    pub fn check_hints(&self) -> Result<(), Error> {
+        match self.unconstrained_hints().first() {
+            Some(hint) => Err(Error::UnconstrainedHint(*hint)),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constraint_system::helper::*;
+    use crate::constraint_system::Constraint;
+    use dusk_bytes::Serializable;
+
+    fn to_u64(scalar: &BlsScalar) -> u64 {
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&scalar.to_bytes()[..8]);
+
+        u64::from_le_bytes(bytes)
+    }
+
+    #[test]
+    fn test_hint_inverse() {
+        let res = gadget_tester(
+            |composer| {
+                let a = composer.append_witness(BlsScalar::from(7u64));
+
+                let [inv] = composer.append_hint(&[a], |values| {
+                    [values[0].invert().unwrap_or(BlsScalar::zero())]
+                });
+                assert_eq!(composer.unconstrained_hints(), vec![inv]);
+
+                // a · inv = 1
+                let constraint = Constraint::new().mult(1).a(a).b(inv);
+                let one = composer.gate_mul(constraint);
+                composer.assert_equal_constant(one, BlsScalar::one(), None);
+
+                assert!(composer.unconstrained_hints().is_empty());
+            },
+            32,
+        );
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn test_hint_division() {
+        let mut composer = TurboComposer::new();
+
+        let a = composer.append_witness(BlsScalar::from(47u64));
+        let b = composer.append_witness(BlsScalar::from(5u64));
+
+        let [q, r] = composer.append_hint(&[a, b], |values| {
+            let a = to_u64(&values[0]);
+            let b = to_u64(&values[1]);
+
+            [BlsScalar::from(a / b), BlsScalar::from(a % b)]
+        });
+
+        assert_eq!(composer.witnesses[&q], BlsScalar::from(9u64));
+        assert_eq!(composer.witnesses[&r], BlsScalar::from(2u64));
+        assert_eq!(composer.unconstrained_hints(), vec![q, r]);
+
+        // a = q · b + r
+        let constraint = Constraint::new().mult(1).fourth(1).a(q).b(b).d(r);
+        let result = composer.gate_mul(constraint);
+        composer.assert_equal(result, a);
+
+        assert!(composer.unconstrained_hints().is_empty());
+    }
+
+    #[test]
+    fn test_hint_missing_values() {
+        let mut composer = TurboComposer::new();
+        let foreign = Witness::new(1 << 20);
+
+        let [hint] = composer.append_hint::<_, 1>(&[foreign], |_| {
+            unreachable!("the hint can't run without its inputs")
+        });
+
+        assert_eq!(composer.witnesses[&hint], BlsScalar::zero());
+    }
+
+    #[test]
+    fn test_check_hints() {
+        let mut composer = TurboComposer::new();
+
+        let a = composer.append_witness(BlsScalar::from(3u64));
+        let [square] =
+            composer.append_hint(&[a], |values| [values[0].square()]);
+
+        assert!(matches!(
+            composer.check_hints(),
+            Err(Error::UnconstrainedHint(hint)) if hint == square
+        ));
+
+        // a · a = square
+        let constraint = Constraint::new().mult(1).a(a).b(a);
+        let result = composer.gate_mul(constraint);
+        composer.assert_equal(result, square);
+
+        assert!(composer.check_hints().is_ok());
+    }
+
+    #[test]
+    fn test_hint_zero_coefficient() {
+        let mut composer = TurboComposer::new();
+
+        let a = composer.append_witness(BlsScalar::from(3u64));
+        let [square] =
+            composer.append_hint(&[a], |values| [values[0].square()]);
+
+        // `square` is wired into a gate whose selectors are all zero
+        composer.append_gate(Constraint::new().a(a).b(square));
+
+        assert_eq!(composer.unconstrained_hints(), vec![square]);
+        assert!(matches!(
+            composer.check_hints(),
+            Err(Error::UnconstrainedHint(hint)) if hint == square
+        ));
+    }
+}
@@ -0,0 +1,537 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use crate::constraint_system::{Constraint, TurboComposer, Witness};
+use core::iter::Sum;
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use dusk_bls12_381::BlsScalar;
+use sp_std::vec;
+use sp_std::vec::Vec;
+
+/// Linear combination `Σ c_i · x_i + k` of [`Witness`]es with constant
+/// coefficients.
+///
+/// A linear combination is built with the `+`, `-` and scalar `*` operators,
+/// starting from [`Witness`]es and [`BlsScalar`] constants, and is lowered
+/// into gates by [`TurboComposer::component_linear_combination`],
+/// [`TurboComposer::assert_linear_combination`] and
+/// [`TurboComposer::component_mul_linear_combination`].
+///
+/// ```
+/// use dusk_plonk::prelude::*;
+///
+/// # let mut prover = Prover::new(b"linear-combination");
+/// # let composer = prover.composer_mut();
+/// let x = composer.append_witness(BlsScalar::from(2u64));
+/// let y = composer.append_witness(BlsScalar::from(3u64));
+/// let z = composer.append_witness(BlsScalar::from(4u64));
+///
+/// // 5·x + y - z + 1 = 8
+/// let lc = x * BlsScalar::from(5u64) + y - z + BlsScalar::one();
+/// let result = composer.component_linear_combination(&lc);
+///
+/// composer.assert_equal_constant(result, BlsScalar::from(8u64), None);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LinearCombination {
+    terms: Vec<(BlsScalar, Witness)>,
+    constant: BlsScalar,
+}
+
+impl LinearCombination {
+    /// Creates an empty linear combination, which equals zero.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the `(coefficient, witness)` terms of the linear combination.
+    pub fn terms(&self) -> &[(BlsScalar, Witness)] {
+        &self.terms
+    }
+
+    /// Returns the constant term of the linear combination.
+    pub const fn constant(&self) -> &BlsScalar {
+        &self.constant
+    }
+
+    /// Adds `coefficient · witness` to the linear combination.
+    pub fn append_term<T: Into<BlsScalar>>(
+        &mut self,
+        coefficient: T,
+        witness: Witness,
+    ) {
+        self.terms.push((coefficient.into(), witness));
+    }
+
+    /// Returns the terms with the coefficients of repeated witnesses added
+    /// together and the terms with a zero coefficient left out, in the order
+    /// the witnesses first appear.
+    pub(crate) fn simplified_terms(&self) -> Vec<(BlsScalar, Witness)> {
+        let mut terms: Vec<(BlsScalar, Witness)> =
+            Vec::with_capacity(self.terms.len());
+
+        self.terms.iter().for_each(|&(coefficient, witness)| {
+            match terms.iter_mut().find(|(_, w)| *w == witness) {
+                Some((c, _)) => *c += coefficient,
+                None => terms.push((coefficient, witness)),
+            }
+        });
+
+        terms.retain(|(coefficient, _)| coefficient != &BlsScalar::zero());
+
+        terms
+    }
+}
+
+impl From<Witness> for LinearCombination {
+    fn from(witness: Witness) -> Self {
+        Self {
+            terms: vec![(BlsScalar::one(), witness)],
+            constant: BlsScalar::zero(),
+        }
+    }
+}
+
+impl From<BlsScalar> for LinearCombination {
+    fn from(constant: BlsScalar) -> Self {
+        Self {
+            terms: Vec::new(),
+            constant,
+        }
+    }
+}
+
+impl From<(BlsScalar, Witness)> for LinearCombination {
+    fn from((coefficient, witness): (BlsScalar, Witness)) -> Self {
+        Self {
+            terms: vec![(coefficient, witness)],
+            constant: BlsScalar::zero(),
+        }
+    }
+}
+
+impl<T: Into<LinearCombination>> AddAssign<T> for LinearCombination {
+    fn add_assign(&mut self, other: T) {
+        let other = other.into();
+
+        self.terms.extend(other.terms);
+        self.constant += other.constant;
+    }
+}
+
+impl<T: Into<LinearCombination>> SubAssign<T> for LinearCombination {
+    fn sub_assign(&mut self, other: T) {
+        *self += -other.into();
+    }
+}
+
+impl MulAssign<BlsScalar> for LinearCombination {
+    fn mul_assign(&mut self, scalar: BlsScalar) {
+        self.terms
+            .iter_mut()
+            .for_each(|(coefficient, _)| *coefficient *= scalar);
+        self.constant *= scalar;
+    }
+}
+
+impl<T: Into<LinearCombination>> Add<T> for LinearCombination {
+    type Output = LinearCombination;
+
+    fn add(mut self, other: T) -> LinearCombination {
+        self += other;
+        self
+    }
+}
+
+impl<T: Into<LinearCombination>> Sub<T> for LinearCombination {
+    type Output = LinearCombination;
+
+    fn sub(mut self, other: T) -> LinearCombination {
+        self -= other;
+        self
+    }
+}
+
+impl Mul<BlsScalar> for LinearCombination {
+    type Output = LinearCombination;
+
+    fn mul(mut self, scalar: BlsScalar) -> LinearCombination {
+        self *= scalar;
+        self
+    }
+}
+
+impl Neg for LinearCombination {
+    type Output = LinearCombination;
+
+    fn neg(self) -> LinearCombination {
+        self * -BlsScalar::one()
+    }
+}
+
+impl Sum for LinearCombination {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(LinearCombination::new(), |acc, lc| acc + lc)
+    }
+}
+
+impl<T: Into<LinearCombination>> Add<T> for Witness {
+    type Output = LinearCombination;
+
+    fn add(self, other: T) -> LinearCombination {
+        LinearCombination::from(self) + other
+    }
+}
+
+impl<T: Into<LinearCombination>> Sub<T> for Witness {
+    type Output = LinearCombination;
+
+    fn sub(self, other: T) -> LinearCombination {
+        LinearCombination::from(self) - other
+    }
+}
+
+impl Mul<BlsScalar> for Witness {
+    type Output = LinearCombination;
+
+    fn mul(self, coefficient: BlsScalar) -> LinearCombination {
+        LinearCombination::from((coefficient, self))
+    }
+}
+
+impl Neg for Witness {
+    type Output = LinearCombination;
+
+    fn neg(self) -> LinearCombination {
+        -LinearCombination::from(self)
+    }
+}
+
+impl TurboComposer {
+    /// Evaluates a [`LinearCombination`] into a new [`Witness`], constraining
+    /// it with the fewest gates.
+    ///
+    /// The first gate adds up to three terms and the constant, and every
+    /// other gate adds up to two more terms to the result of the previous
+    /// one, so `n > 3` terms take `1 + ⌈(n - 3) / 2⌉` gates. A single term
+    /// with a coefficient of one and no constant is returned as is, without
+    /// any gate.
+    pub fn component_linear_combination(
+        &mut self,
+        lc: &LinearCombination,
+    ) -> Witness {
+        let terms = lc.simplified_terms();
+
+        match terms.as_slice() {
+            [(coefficient, witness)]
+                if coefficient == &BlsScalar::one()
+                    && lc.constant == BlsScalar::zero() =>
+            {
+                *witness
+            }
+            _ => self
+                .lower_linear_combination(&terms, lc.constant, true)
+                .expect("the output of the last gate is returned"),
+        }
+    }
+
+    /// Asserts that a [`LinearCombination`] equals zero.
+    ///
+    /// This is the same as evaluating it with
+    /// [`TurboComposer::component_linear_combination`] and asserting the
+    /// result is zero, but the last gate checks up to three terms and the
+    /// accumulated ones instead of outputting them, which can save a gate. To
+    /// assert `Σ c_i · x_i = y`, assert `Σ c_i · x_i - y` is zero.
+    pub fn assert_linear_combination(&mut self, lc: &LinearCombination) {
+        let terms = lc.simplified_terms();
+
+        self.lower_linear_combination(&terms, lc.constant, false);
+    }
+
+    /// Evaluates the product of two [`LinearCombination`]s into a new
+    /// [`Witness`].
+    ///
+    /// Each factor with more than one term is evaluated with
+    /// [`TurboComposer::component_linear_combination`], leaving its constant
+    /// out. The product `(c_a · a + k_a) · (c_b · b + k_b)` of the resulting
+    /// terms is then expanded into a single gate, so the product of two
+    /// factors with at most one term each takes one gate.
+    pub fn component_mul_linear_combination(
+        &mut self,
+        a: &LinearCombination,
+        b: &LinearCombination,
+    ) -> Witness {
+        let (c_a, w_a, k_a) = self.lower_factor(a);
+        let (c_b, w_b, k_b) = self.lower_factor(b);
+
+        let constraint = Constraint::new()
+            .mult(c_a * c_b)
+            .left(c_a * k_b)
+            .right(c_b * k_a)
+            .constant(k_a * k_b)
+            .a(w_a)
+            .b(w_b);
+
+        self.gate_mul(constraint)
+    }
+
+    /// Reduces a factor of a product to a single term and its constant.
+    fn lower_factor(
+        &mut self,
+        lc: &LinearCombination,
+    ) -> (BlsScalar, Witness, BlsScalar) {
+        let terms = lc.simplified_terms();
+
+        match terms.as_slice() {
+            [] => (BlsScalar::zero(), Self::constant_zero(), lc.constant),
+            [(coefficient, witness)] => (*coefficient, *witness, lc.constant),
+            _ => {
+                let terms = LinearCombination {
+                    terms,
+                    constant: BlsScalar::zero(),
+                };
+                let witness = self.component_linear_combination(&terms);
+
+                (BlsScalar::one(), witness, lc.constant)
+            }
+        }
+    }
+
+    /// Appends the gates of a linear combination, accumulating its terms
+    /// through the output wires.
+    ///
+    /// If `output` is set, the last gate outputs the evaluation of the linear
+    /// combination, which is returned. Otherwise the last gate asserts it's
+    /// zero, using its output wire as an input.
+    fn lower_linear_combination(
+        &mut self,
+        terms: &[(BlsScalar, Witness)],
+        constant: BlsScalar,
+        output: bool,
+    ) -> Option<Witness> {
+        let zero = Self::constant_zero();
+
+        let mut accumulator: Option<Witness> = None;
+        let mut constant = constant;
+        let mut terms = terms;
+
+        loop {
+            let mut inputs: Vec<(BlsScalar, Witness)> = Vec::with_capacity(4);
+            inputs.extend(accumulator.map(|acc| (BlsScalar::one(), acc)));
+
+            let last = inputs.len() + terms.len() <= if output { 3 } else { 4 };
+            let take = if last { terms.len() } else { 3 - inputs.len() };
+
+            inputs.extend_from_slice(&terms[..take]);
+            terms = &terms[take..];
+
+            inputs.resize(4, (BlsScalar::zero(), zero));
+
+            let constraint = Constraint::new()
+                .left(inputs[0].0)
+                .right(inputs[1].0)
+                .fourth(inputs[2].0)
+                .constant(constant)
+                .a(inputs[0].1)
+                .b(inputs[1].1)
+                .d(inputs[2].1);
+
+            // The constant is only added once
+            constant = BlsScalar::zero();
+
+            if last && !output {
+                let constraint = constraint.output(inputs[3].0).o(inputs[3].1);
+                self.append_gate(constraint);
+
+                return None;
+            }
+
+            let result = self.gate_add(constraint);
+
+            if last {
+                return Some(result);
+            }
+
+            accumulator.replace(result);
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constraint_system::helper::*;
+
+    fn evaluate(lc: &LinearCombination, composer: &TurboComposer) -> BlsScalar {
+        lc.terms()
+            .iter()
+            .fold(*lc.constant(), |acc, (coefficient, witness)| {
+                acc + coefficient * composer.witnesses[witness]
+            })
+    }
+
+    fn witnesses(composer: &mut TurboComposer, values: &[u64]) -> Vec<Witness> {
+        values
+            .iter()
+            .map(|v| composer.append_witness(BlsScalar::from(*v)))
+            .collect()
+    }
+
+    #[test]
+    fn test_linear_combination_operators() {
+        let mut composer = TurboComposer::new();
+        let w = witnesses(&mut composer, &[1, 2, 3]);
+
+        let two = BlsScalar::from(2u64);
+        let lc = (w[0] * two + w[1] - w[0] + BlsScalar::one()) * two - w[2];
+
+        assert_eq!(
+            lc.simplified_terms(),
+            vec![(two, w[0]), (two, w[1]), (-BlsScalar::one(), w[2])]
+        );
+        assert_eq!(lc.constant(), &two);
+        assert_eq!(evaluate(&lc, &composer), BlsScalar::from(5u64));
+
+        let lc = w[0] - w[0] + BlsScalar::one();
+        assert!(lc.simplified_terms().is_empty());
+
+        let lc: LinearCombination =
+            w.iter().map(|w| LinearCombination::from(*w)).sum();
+        assert_eq!(evaluate(&lc, &composer), BlsScalar::from(6u64));
+    }
+
+    #[test]
+    fn test_linear_combination_gates() {
+        // Number of terms and gates needed to evaluate and to assert them
+        let cases = [
+            (0, 1, 1),
+            (1, 1, 1),
+            (3, 1, 1),
+            (4, 2, 1),
+            (5, 2, 2),
+            (6, 3, 2),
+            (7, 3, 2),
+            (8, 4, 3),
+            (20, 10, 9),
+        ];
+
+        cases.iter().for_each(|&(n, evaluate_gates, assert_gates)| {
+            let mut composer = TurboComposer::new();
+            let values: Vec<u64> = (1..=n as u64).collect();
+            let w = witnesses(&mut composer, &values);
+
+            let lc = w
+                .iter()
+                .zip(values.iter())
+                .map(|(w, v)| *w * BlsScalar::from(*v))
+                .sum::<LinearCombination>()
+                + BlsScalar::from(7u64);
+            let expected = evaluate(&lc, &composer);
+
+            let gates = composer.gates();
+            let result = composer.component_linear_combination(&lc);
+            assert_eq!(composer.gates() - gates, evaluate_gates, "{}", n);
+            assert_eq!(composer.witnesses[&result], expected);
+
+            let gates = composer.gates();
+            composer.assert_linear_combination(&(lc - expected));
+            assert_eq!(composer.gates() - gates, assert_gates, "{}", n);
+        });
+    }
+
+    #[test]
+    fn test_linear_combination_single_term() {
+        let mut composer = TurboComposer::new();
+        let w = witnesses(&mut composer, &[5]);
+
+        let gates = composer.gates();
+        let result = composer.component_linear_combination(&w[0].into());
+
+        assert_eq!(result, w[0]);
+        assert_eq!(composer.gates(), gates);
+    }
+
+    #[test]
+    fn test_linear_combination_prove() {
+        let res = gadget_tester(
+            |composer| {
+                let w = witnesses(composer, &[1, 2, 3, 4, 5, 6, 7]);
+
+                let lc = w
+                    .iter()
+                    .enumerate()
+                    .map(|(i, w)| *w * BlsScalar::from(i as u64 + 1))
+                    .sum::<LinearCombination>()
+                    - BlsScalar::from(3u64);
+
+                // 1 + 4 + 9 + 16 + 25 + 36 + 49 - 3 = 137
+                let result = composer.component_linear_combination(&lc);
+                composer.assert_equal_constant(
+                    result,
+                    BlsScalar::from(137u64),
+                    None,
+                );
+
+                let y = composer.append_witness(BlsScalar::from(137u64));
+                composer.assert_linear_combination(&(lc - y));
+
+                // (w_0 + w_1 + 1) · (2 · w_2 - 1) = 4 · 5 = 20
+                let a = w[0] + w[1] + BlsScalar::one();
+                let b = w[2] * BlsScalar::from(2u64) - BlsScalar::one();
+                let product = composer.component_mul_linear_combination(&a, &b);
+                composer.assert_equal_constant(
+                    product,
+                    BlsScalar::from(20u64),
+                    None,
+                );
+            },
+            64,
+        );
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn test_linear_combination_prove_fail() {
+        let res = gadget_tester(
+            |composer| {
+                let w = witnesses(composer, &[1, 2, 3, 4, 5]);
+
+                let lc: LinearCombination =
+                    w.iter().map(|w| LinearCombination::from(*w)).sum();
+                let y = composer.append_witness(BlsScalar::from(16u64));
+
+                composer.assert_linear_combination(&(lc - y));
+            },
+            32,
+        );
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_mul_linear_combination_gates() {
+        let mut composer = TurboComposer::new();
+        let w = witnesses(&mut composer, &[2, 3, 4, 5]);
+
+        // (2 · 2 + 1) · (3 - 2) = 5
+        let gates = composer.gates();
+        let product = composer.component_mul_linear_combination(
+            &(w[0] * BlsScalar::from(2u64) + BlsScalar::one()),
+            &(w[1] - BlsScalar::from(2u64)),
+        );
+        assert_eq!(composer.gates() - gates, 1);
+        assert_eq!(composer.witnesses[&product], BlsScalar::from(5u64));
+
+        // (2 + 3 + 4) · (5 + 1) = 54
+        let gates = composer.gates();
+        let product = composer.component_mul_linear_combination(
+            &(w[0] + w[1] + w[2]),
+            &(w[3] + BlsScalar::one()),
+        );
+        assert_eq!(composer.gates() - gates, 2);
+        assert_eq!(composer.witnesses[&product], BlsScalar::from(54u64));
+    }
+}
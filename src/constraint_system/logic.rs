@@ -73,18 +73,20 @@ impl TurboComposer {
         // We increase the gate idx and assign w_4, w_l and w_r to `zero`.
         // Now we can add the first row as: `| 0 | 0 | -- | 0 |`.
         // Note that `w_1` will be set on the first loop iteration.
-        self.perm.add_variable_to_map(
-            Self::constant_zero(),
-            WireData::Left(self.n as usize),
-        );
-        self.perm.add_variable_to_map(
-            Self::constant_zero(),
-            WireData::Right(self.n as usize),
-        );
-        self.perm.add_variable_to_map(
-            Self::constant_zero(),
-            WireData::Fourth(self.n as usize),
-        );
+        if !self.witness_only {
+            self.perm.add_variable_to_map(
+                Self::constant_zero(),
+                WireData::Left(self.n as usize),
+            );
+            self.perm.add_variable_to_map(
+                Self::constant_zero(),
+                WireData::Right(self.n as usize),
+            );
+            self.perm.add_variable_to_map(
+                Self::constant_zero(),
+                WireData::Fourth(self.n as usize),
+            );
+        }
         self.w_l.push(Self::constant_zero());
         self.w_r.push(Self::constant_zero());
         self.w_4.push(Self::constant_zero());
@@ -203,16 +205,24 @@ impl TurboComposer {
             // Also note that here we're setting left, right and fourth
             // variables to the actual gate, meanwhile we set out to
             // the previous gate.
-            self.perm
-                .add_variable_to_map(var_a, WireData::Left(self.n as usize));
-            self.perm
-                .add_variable_to_map(var_b, WireData::Right(self.n as usize));
-            self.perm
-                .add_variable_to_map(var_4, WireData::Fourth(self.n as usize));
-            self.perm.add_variable_to_map(
-                var_c,
-                WireData::Output(self.n as usize - 1),
-            );
+            if !self.witness_only {
+                self.perm.add_variable_to_map(
+                    var_a,
+                    WireData::Left(self.n as usize),
+                );
+                self.perm.add_variable_to_map(
+                    var_b,
+                    WireData::Right(self.n as usize),
+                );
+                self.perm.add_variable_to_map(
+                    var_4,
+                    WireData::Fourth(self.n as usize),
+                );
+                self.perm.add_variable_to_map(
+                    var_c,
+                    WireData::Output(self.n as usize - 1),
+                );
+            }
             // Push the variables to it's actual wire vector storage
             self.w_l.push(var_a);
             self.w_r.push(var_b);
@@ -227,17 +237,43 @@ impl TurboComposer {
         // ahead. To fix this, we simply pad with a 0 so the last row of
         // the program memory will look like this:
         // | an  | bn  | --- | cn  |
-        self.perm.add_variable_to_map(
-            Self::constant_zero(),
-            WireData::Output(self.n as usize - 1),
-        );
+        if !self.witness_only {
+            self.perm.add_variable_to_map(
+                Self::constant_zero(),
+                WireData::Output(self.n as usize - 1),
+            );
+        }
         self.w_o.push(Self::constant_zero());
 
         // Now the wire values are set for each gate, indexed and mapped in the
         // `variable_map` inside of the `Permutation` struct.
         // Now we just need to extend the selector polynomials with the
         // appropriate coefficients to form complete logic gates.
-        for _ in 0..num_quads {
+        if !self.witness_only {
+            for _ in 0..num_quads {
+                self.q_m.push(BlsScalar::zero());
+                self.q_l.push(BlsScalar::zero());
+                self.q_r.push(BlsScalar::zero());
+                self.q_arith.push(BlsScalar::zero());
+                self.q_o.push(BlsScalar::zero());
+                self.q_4.push(BlsScalar::zero());
+                self.q_range.push(BlsScalar::zero());
+                self.q_fixed_group_add.push(BlsScalar::zero());
+                self.q_variable_group_add.push(BlsScalar::zero());
+                self.q_lookup.push(BlsScalar::zero());
+                match is_component_xor {
+                    true => {
+                        self.q_c.push(-BlsScalar::one());
+                        self.q_logic.push(-BlsScalar::one());
+                    }
+                    false => {
+                        self.q_c.push(BlsScalar::one());
+                        self.q_logic.push(BlsScalar::one());
+                    }
+                };
+            }
+            // For the last gate, `q_c` and `q_logic` we use no-op values
+            // (Zero).
             self.q_m.push(BlsScalar::zero());
             self.q_l.push(BlsScalar::zero());
             self.q_r.push(BlsScalar::zero());
@@ -248,31 +284,10 @@ impl TurboComposer {
             self.q_fixed_group_add.push(BlsScalar::zero());
             self.q_variable_group_add.push(BlsScalar::zero());
             self.q_lookup.push(BlsScalar::zero());
-            match is_component_xor {
-                true => {
-                    self.q_c.push(-BlsScalar::one());
-                    self.q_logic.push(-BlsScalar::one());
-                }
-                false => {
-                    self.q_c.push(BlsScalar::one());
-                    self.q_logic.push(BlsScalar::one());
-                }
-            };
-        }
-        // For the last gate, `q_c` and `q_logic` we use no-op values (Zero).
-        self.q_m.push(BlsScalar::zero());
-        self.q_l.push(BlsScalar::zero());
-        self.q_r.push(BlsScalar::zero());
-        self.q_arith.push(BlsScalar::zero());
-        self.q_o.push(BlsScalar::zero());
-        self.q_4.push(BlsScalar::zero());
-        self.q_range.push(BlsScalar::zero());
-        self.q_fixed_group_add.push(BlsScalar::zero());
-        self.q_variable_group_add.push(BlsScalar::zero());
-        self.q_lookup.push(BlsScalar::zero());
 
-        self.q_c.push(BlsScalar::zero());
-        self.q_logic.push(BlsScalar::zero());
+            self.q_c.push(BlsScalar::zero());
+            self.q_logic.push(BlsScalar::zero());
+        }
 
         // Now we need to assert that the sum of accumulated values
         // matches the original values provided to the fn.
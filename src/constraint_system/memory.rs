@@ -0,0 +1,628 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Memory components, to read and write arrays of witnesses at witness
+//! addresses.
+//!
+//! Every access to a [`Memory`] appends a record `(address, value, timestamp,
+//! is_write)` to it. Once the memory is finalized, its records are routed
+//! through a Beneš permutation network into a list sorted by address and
+//! timestamp, where each read is checked against the previous access to its
+//! address.
+
+use crate::constraint_system::{Constraint, TurboComposer, Witness};
+use dusk_bls12_381::BlsScalar;
+use dusk_bytes::Serializable;
+use sp_std::vec;
+use sp_std::vec::Vec;
+
+/// Access record of a [`Memory`], as `[address, value, timestamp, is_write]`.
+///
+/// The timestamp and write flag are only used by read/write memories.
+type Record = [Witness; 4];
+
+const ADDRESS: usize = 0;
+const VALUE: usize = 1;
+const TIMESTAMP: usize = 2;
+const IS_WRITE: usize = 3;
+
+/// An array of witnesses that circuits can access at witness addresses.
+///
+/// A read-only memory is created with [`TurboComposer::component_rom`] and a
+/// read/write one with [`TurboComposer::component_ram`]. The accesses to a
+/// memory are only checked once it's passed to
+/// [`TurboComposer::component_memory_finalize`].
+#[derive(Debug, Clone)]
+#[must_use = "the accesses to a memory are only checked once it's finalized"]
+pub struct Memory {
+    read_only: bool,
+    /// Constant one, flagging the writes of a read/write memory.
+    one: Witness,
+    /// Contents of the memory, as seen by the prover.
+    values: Vec<BlsScalar>,
+    records: Vec<Record>,
+}
+
+impl Memory {
+    /// Returns the number of values stored in the memory.
+    pub fn size(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if the memory can't be written to.
+    pub const fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// Returns the index of the value at `address`, if it's in the memory.
+    fn index(&self, address: BlsScalar) -> Option<usize> {
+        let bytes = address.to_bytes();
+        if bytes[8..].iter().any(|byte| *byte != 0) {
+            return None;
+        }
+
+        let mut index = [0u8; 8];
+        index.copy_from_slice(&bytes[..8]);
+        let index = u64::from_le_bytes(index) as usize;
+
+        (index < self.values.len()).then(|| index)
+    }
+}
+
+impl TurboComposer {
+    /// Creates a read-only [`Memory`] holding `values`, at addresses `0..n`.
+    ///
+    /// # Cost
+    /// The `m` records of the memory, one per value and one per access
+    /// padded to a power of two, are sorted by a Beneš network of about
+    /// `m·log(m)` switches of 7 gates each. Each access thus costs about
+    /// `7·log(m)` gates, plus 4 gates to check the sorted record.
+    ///
+    /// # Panics
+    /// This function will panic if `values` is empty.
+    pub fn component_rom(&mut self, values: &[Witness]) -> Memory {
+        self.append_memory(values, true)
+    }
+
+    /// Creates a read/write [`Memory`] initialized with `values`, at addresses
+    /// `0..n`.
+    ///
+    /// # Cost
+    /// The `m` records of the memory, one per value and one per access
+    /// padded to a power of two, are sorted by a Beneš network of about
+    /// `m·log(m)` switches of 13 gates each. Each access thus costs about
+    /// `13·log(m)` gates, plus 7 gates and a range check of its timestamp to
+    /// check the sorted record.
+    ///
+    /// # Panics
+    /// This function will panic if `values` is empty.
+    pub fn component_ram(&mut self, values: &[Witness]) -> Memory {
+        self.append_memory(values, false)
+    }
+
+    /// Reads the value at `address` of `memory`.
+    ///
+    /// Reading an address outside of the memory makes the circuit
+    /// unsatisfiable once the memory is finalized.
+    pub fn component_memory_read(
+        &mut self,
+        memory: &mut Memory,
+        address: Witness,
+    ) -> Witness {
+        let value = memory
+            .index(self.witnesses[&address])
+            .map(|index| memory.values[index])
+            .unwrap_or_else(BlsScalar::zero);
+        let value = self.append_witness(value);
+
+        self.append_memory_record(memory, address, value, false);
+
+        value
+    }
+
+    /// Writes `value` at `address` of `memory`.
+    ///
+    /// Writing an address outside of the memory makes the circuit
+    /// unsatisfiable once the memory is finalized.
+    ///
+    /// # Panics
+    /// This function will panic if the memory is read-only.
+    pub fn component_memory_write(
+        &mut self,
+        memory: &mut Memory,
+        address: Witness,
+        value: Witness,
+    ) {
+        assert!(!memory.read_only, "cannot write to a read-only memory");
+
+        if let Some(index) = memory.index(self.witnesses[&address]) {
+            memory.values[index] = self.witnesses[&value];
+        }
+
+        self.append_memory_record(memory, address, value, true);
+    }
+
+    /// Checks the consistency of all the accesses to `memory`.
+    ///
+    /// The records of the memory are padded with reads to a power of two, and
+    /// sorted by a Beneš network of `m·log(m)` switches. Each switch costs 7
+    /// gates for a read-only memory and 13 for a read/write one, which also
+    /// routes the timestamps of the accesses and their kinds.
+    pub fn component_memory_finalize(&mut self, mut memory: Memory) {
+        // The permutation network routes a power of two of records
+        while memory.records.len() < 2
+            || !memory.records.len().is_power_of_two()
+        {
+            self.component_memory_read(&mut memory, Self::constant_zero());
+        }
+
+        let records = memory.records.len();
+        let width = if memory.read_only { 2 } else { 4 };
+
+        // Sort the records by address and timestamp, according to the
+        // values of their witnesses
+        let key = |composer: &Self, record: &Record| {
+            let mut address = composer.witnesses[&record[ADDRESS]].to_bytes();
+            let mut timestamp =
+                composer.witnesses[&record[TIMESTAMP]].to_bytes();
+
+            address.reverse();
+            timestamp.reverse();
+
+            (address, timestamp)
+        };
+
+        let mut order: Vec<usize> = (0..records).collect();
+        order.sort_by_key(|i| key(self, &memory.records[*i]));
+
+        let mut permutation = vec![0; records];
+        order
+            .iter()
+            .enumerate()
+            .for_each(|(position, i)| permutation[*i] = position);
+
+        let sorted = self.append_permutation_network(
+            memory.records.clone(),
+            &permutation,
+            width,
+        );
+
+        // The addresses are bound to the ones of the memory, since they start
+        // at zero, end at its last address and increase one at a time
+        let last = BlsScalar::from((memory.size() - 1) as u64);
+        self.assert_equal_constant(sorted[0][ADDRESS], BlsScalar::zero(), None);
+        self.assert_equal_constant(sorted[records - 1][ADDRESS], last, None);
+
+        // Number of bits of the largest timestamp, as an even number
+        let accesses = (records - memory.size()) as u64;
+        let timestamp_bits = (64 - accesses.leading_zeros()) as usize;
+        let timestamp_bits =
+            core::cmp::max(2, timestamp_bits + timestamp_bits % 2);
+
+        sorted.windows(2).for_each(|pair| {
+            let (prev, next) = (pair[0], pair[1]);
+
+            // The address either stays the same or moves to the next one
+            let constraint = Constraint::new()
+                .left(1)
+                .right(-BlsScalar::one())
+                .a(next[ADDRESS])
+                .b(prev[ADDRESS]);
+            let address_delta = self.gate_add(constraint);
+            self.component_boolean(address_delta);
+
+            let constraint = Constraint::new()
+                .left(1)
+                .right(-BlsScalar::one())
+                .a(next[VALUE])
+                .b(prev[VALUE]);
+            let value_delta = self.gate_add(constraint);
+
+            if memory.read_only {
+                // All the records of an address have the same value
+                let constraint = Constraint::new()
+                    .mult(-BlsScalar::one())
+                    .right(1)
+                    .a(address_delta)
+                    .b(value_delta);
+                self.append_gate(constraint);
+
+                return;
+            }
+
+            // The accesses to an address are ordered by their timestamps,
+            // so its initial value is the first record of the address
+            let constraint = Constraint::new()
+                .left(1)
+                .right(-BlsScalar::one())
+                .constant(-BlsScalar::one())
+                .a(next[TIMESTAMP])
+                .b(prev[TIMESTAMP]);
+            let timestamp_delta = self.gate_add(constraint);
+
+            let constraint = Constraint::new()
+                .mult(-BlsScalar::one())
+                .right(1)
+                .a(address_delta)
+                .b(timestamp_delta);
+            let timestamp_delta = self.gate_mul(constraint);
+            self.component_range(timestamp_delta, timestamp_bits);
+
+            // A read returns the value of the previous access to its address
+            let constraint = Constraint::new()
+                .mult(-BlsScalar::one())
+                .right(1)
+                .a(address_delta)
+                .b(value_delta);
+            let value_delta = self.gate_mul(constraint);
+
+            let constraint = Constraint::new()
+                .mult(-BlsScalar::one())
+                .right(1)
+                .a(next[IS_WRITE])
+                .b(value_delta);
+            self.append_gate(constraint);
+        });
+    }
+
+    fn append_memory(&mut self, values: &[Witness], read_only: bool) -> Memory {
+        assert!(!values.is_empty(), "cannot create an empty memory");
+
+        // The initial values are written at timestamp zero
+        let one = if read_only {
+            Self::constant_zero()
+        } else {
+            self.append_constant(BlsScalar::one())
+        };
+
+        let records = values
+            .iter()
+            .enumerate()
+            .map(|(i, value)| {
+                let address = self.append_constant(BlsScalar::from(i as u64));
+
+                [address, *value, Self::constant_zero(), one]
+            })
+            .collect();
+
+        let values = values.iter().map(|value| self.witnesses[value]).collect();
+
+        Memory {
+            read_only,
+            one,
+            values,
+            records,
+        }
+    }
+
+    fn append_memory_record(
+        &mut self,
+        memory: &mut Memory,
+        address: Witness,
+        value: Witness,
+        is_write: bool,
+    ) {
+        // Read-only memories don't need to order their accesses
+        let (timestamp, is_write) = if memory.read_only {
+            (Self::constant_zero(), Self::constant_zero())
+        } else {
+            let timestamp = memory.records.len() - memory.size() + 1;
+            let timestamp =
+                self.append_constant(BlsScalar::from(timestamp as u64));
+
+            match is_write {
+                true => (timestamp, memory.one),
+                false => (timestamp, Self::constant_zero()),
+            }
+        };
+
+        memory.records.push([address, value, timestamp, is_write]);
+    }
+
+    /// Routes `records` through a Beneš network, so the record `i` is output
+    /// at position `permutation[i]`. Only the first `width` fields of the
+    /// records are routed, leaving the others zeroed.
+    fn append_permutation_network(
+        &mut self,
+        records: Vec<Record>,
+        permutation: &[usize],
+        width: usize,
+    ) -> Vec<Record> {
+        let size = records.len();
+
+        if size == 2 {
+            let (top, bottom) = self.append_switch(
+                permutation[0] == 1,
+                records[0],
+                records[1],
+                width,
+            );
+
+            return vec![top, bottom];
+        }
+
+        let half = size / 2;
+
+        let mut inverse = vec![0; size];
+        permutation
+            .iter()
+            .enumerate()
+            .for_each(|(i, position)| inverse[*position] = i);
+
+        // Route each input through the upper or the lower subnetwork, so the
+        // two inputs of a switch, and the two outputs of a switch, go through
+        // different subnetworks
+        let mut lower: Vec<Option<bool>> = vec![None; size];
+        for start in (0..size).step_by(2) {
+            let mut input = start;
+
+            while lower[input].is_none() {
+                lower[input] = Some(false);
+
+                let other = inverse[permutation[input] ^ 1];
+                lower[other] = Some(true);
+
+                input = other ^ 1;
+            }
+        }
+
+        let mut upper_records = Vec::with_capacity(half);
+        let mut lower_records = Vec::with_capacity(half);
+        let mut upper_permutation = vec![0; half];
+        let mut lower_permutation = vec![0; half];
+        let mut output_swaps = vec![false; half];
+
+        for i in 0..half {
+            let swap = lower[2 * i] == Some(true);
+            let (top, bottom) = self.append_switch(
+                swap,
+                records[2 * i],
+                records[2 * i + 1],
+                width,
+            );
+
+            upper_records.push(top);
+            lower_records.push(bottom);
+
+            let (up, down) = match swap {
+                true => (2 * i + 1, 2 * i),
+                false => (2 * i, 2 * i + 1),
+            };
+
+            upper_permutation[i] = permutation[up] / 2;
+            lower_permutation[i] = permutation[down] / 2;
+
+            // The output switch swaps if the upper subnetwork feeds its
+            // bottom output
+            output_swaps[permutation[up] / 2] = permutation[up] % 2 == 1;
+        }
+
+        let upper_records = self.append_permutation_network(
+            upper_records,
+            &upper_permutation,
+            width,
+        );
+        let lower_records = self.append_permutation_network(
+            lower_records,
+            &lower_permutation,
+            width,
+        );
+
+        let mut outputs = Vec::with_capacity(size);
+        for i in 0..half {
+            let (top, bottom) = self.append_switch(
+                output_swaps[i],
+                upper_records[i],
+                lower_records[i],
+                width,
+            );
+
+            outputs.push(top);
+            outputs.push(bottom);
+        }
+
+        outputs
+    }
+
+    /// Outputs `top` and `bottom`, swapped if `swap` is set. The swap bit is
+    /// constrained to be boolean.
+    fn append_switch(
+        &mut self,
+        swap: bool,
+        top: Record,
+        bottom: Record,
+        width: usize,
+    ) -> (Record, Record) {
+        let swap = self.append_witness(BlsScalar::from(swap as u64));
+        self.component_boolean(swap);
+
+        let mut outputs =
+            ([Self::constant_zero(); 4], [Self::constant_zero(); 4]);
+
+        for field in 0..width {
+            let constraint = Constraint::new()
+                .left(1)
+                .right(-BlsScalar::one())
+                .a(bottom[field])
+                .b(top[field]);
+            let delta = self.gate_add(constraint);
+
+            // top + swap · (bottom - top)
+            let constraint = Constraint::new()
+                .mult(1)
+                .fourth(1)
+                .a(swap)
+                .b(delta)
+                .d(top[field]);
+            outputs.0[field] = self.gate_mul(constraint);
+
+            // top + bottom - first output
+            let constraint = Constraint::new()
+                .left(1)
+                .right(1)
+                .fourth(-BlsScalar::one())
+                .a(top[field])
+                .b(bottom[field])
+                .d(outputs.0[field]);
+            outputs.1[field] = self.gate_add(constraint);
+        }
+
+        outputs
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use super::super::helper::*;
+    use super::*;
+
+    // Creates a ROM of [5, 7, 11, 13]
+    fn rom(composer: &mut TurboComposer) -> Memory {
+        let values: Vec<Witness> = [5u64, 7, 11, 13]
+            .iter()
+            .map(|value| composer.append_witness(BlsScalar::from(*value)))
+            .collect();
+
+        composer.component_rom(&values)
+    }
+
+    #[test]
+    fn test_rom() {
+        let res = gadget_tester(
+            |composer| {
+                let mut rom = rom(composer);
+
+                [(2u64, 11u64), (0, 5), (3, 13), (2, 11)].iter().for_each(
+                    |(address, value)| {
+                        let address =
+                            composer.append_witness(BlsScalar::from(*address));
+                        let read =
+                            composer.component_memory_read(&mut rom, address);
+
+                        composer.assert_equal_constant(
+                            read,
+                            BlsScalar::from(*value),
+                            None,
+                        );
+                    },
+                );
+
+                composer.component_memory_finalize(rom);
+            },
+            1024,
+        );
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn test_rom_address_out_of_range() {
+        let res = gadget_tester(
+            |composer| {
+                let mut rom = rom(composer);
+
+                let address = composer.append_witness(BlsScalar::from(4u64));
+                composer.component_memory_read(&mut rom, address);
+
+                composer.component_memory_finalize(rom);
+            },
+            1024,
+        );
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_rom_wrong_read() {
+        let res = gadget_tester(
+            |composer| {
+                let mut rom = rom(composer);
+
+                let address = composer.append_witness(BlsScalar::from(1u64));
+                let read = composer.component_memory_read(&mut rom, address);
+                composer.witnesses.insert(read, BlsScalar::from(11u64));
+
+                composer.component_memory_finalize(rom);
+            },
+            1024,
+        );
+        assert!(res.is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rom_write() {
+        let mut composer = TurboComposer::new();
+        let mut rom = rom(&mut composer);
+
+        let zero = TurboComposer::constant_zero();
+        composer.component_memory_write(&mut rom, zero, zero);
+    }
+
+    // Writes 9 at address 1 and 3 at address 0 of a RAM of [1, 2], returning
+    // the reads of address 1 and 0 in between
+    fn ram(composer: &mut TurboComposer) -> (Memory, Witness, Witness) {
+        let one = composer.append_witness(BlsScalar::one());
+        let two = composer.append_witness(BlsScalar::from(2u64));
+        let mut ram = composer.component_ram(&[one, two]);
+
+        let address_0 = composer.append_witness(BlsScalar::zero());
+        let address_1 = composer.append_witness(BlsScalar::one());
+        let nine = composer.append_witness(BlsScalar::from(9u64));
+        let three = composer.append_witness(BlsScalar::from(3u64));
+
+        composer.component_memory_write(&mut ram, address_1, nine);
+        let first = composer.component_memory_read(&mut ram, address_1);
+        let second = composer.component_memory_read(&mut ram, address_0);
+        composer.component_memory_write(&mut ram, address_0, three);
+
+        (ram, first, second)
+    }
+
+    #[test]
+    fn test_ram() {
+        let res = gadget_tester(
+            |composer| {
+                let (mut ram, first, second) = ram(composer);
+
+                composer.assert_equal_constant(
+                    first,
+                    BlsScalar::from(9u64),
+                    None,
+                );
+                composer.assert_equal_constant(second, BlsScalar::one(), None);
+
+                let zero = TurboComposer::constant_zero();
+                let third = composer.component_memory_read(&mut ram, zero);
+                composer.assert_equal_constant(
+                    third,
+                    BlsScalar::from(3u64),
+                    None,
+                );
+
+                composer.component_memory_finalize(ram);
+            },
+            1024,
+        );
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn test_ram_stale_read() {
+        let res = gadget_tester(
+            |composer| {
+                let (ram, first, _) = ram(composer);
+
+                // Read the initial value of the address instead of the
+                // written one
+                composer.witnesses.insert(first, BlsScalar::from(2u64));
+
+                composer.component_memory_finalize(ram);
+            },
+            1024,
+        );
+        assert!(res.is_err());
+    }
+}
@@ -0,0 +1,748 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use crate::constraint_system::{LinearCombination, TurboComposer, Witness};
+use crate::permutation::Permutation;
+use dusk_bls12_381::BlsScalar;
+use dusk_bytes::Serializable;
+use hashbrown::hash_map::Entry;
+use hashbrown::HashMap;
+use sp_std::collections::btree_map::BTreeMap;
+use sp_std::vec;
+use sp_std::vec::Vec;
+
+/// Gates and witnesses removed by [`TurboComposer::optimize`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OptimizationReport {
+    /// Gates constraining a witness to a constant that another gate already
+    /// constrains a witness to.
+    pub deduplicated_constants: usize,
+    /// Gates asserting two witnesses are equal, removed by replacing one of
+    /// the witnesses by the other.
+    pub collapsed_equalities: usize,
+    /// Linear gates merged into the linear gate following them.
+    pub merged_gates: usize,
+    /// Gates whose coefficients are all zero.
+    pub empty_gates: usize,
+    /// Witnesses no gate uses once the gates are optimized.
+    pub removed_witnesses: usize,
+    /// Hints that didn't take part in any constraint before the gates were
+    /// optimized. They're never removed, so
+    /// [`TurboComposer::unconstrained_hints`] still lists them afterwards.
+    pub unconstrained_hints: usize,
+}
+
+impl OptimizationReport {
+    /// Returns the number of gates removed from the circuit.
+    pub fn removed_gates(&self) -> usize {
+        self.deduplicated_constants
+            + self.collapsed_equalities
+            + self.merged_gates
+            + self.empty_gates
+    }
+}
+
+/// A gate of the circuit, with its selectors, wires and public input.
+#[derive(Debug, Clone, Copy)]
+struct Gate {
+    q_m: BlsScalar,
+    q_l: BlsScalar,
+    q_r: BlsScalar,
+    q_o: BlsScalar,
+    q_4: BlsScalar,
+    q_c: BlsScalar,
+    q_arith: BlsScalar,
+    q_range: BlsScalar,
+    q_logic: BlsScalar,
+    q_fixed_group_add: BlsScalar,
+    q_variable_group_add: BlsScalar,
+    q_lookup: BlsScalar,
+
+    a: Witness,
+    b: Witness,
+    o: Witness,
+    d: Witness,
+
+    pi: Option<BlsScalar>,
+
+    /// Gates that can't be removed or merged, either because they carry a
+    /// public input or because a custom gate reads them.
+    fixed: bool,
+}
+
+impl Gate {
+    /// Creates a linear gate asserting `Σ c_i · w_i + constant = 0`, for up
+    /// to four terms.
+    fn linear(terms: &[(BlsScalar, Witness)], constant: BlsScalar) -> Self {
+        let zero = BlsScalar::zero();
+        let term = |i: usize| {
+            terms
+                .get(i)
+                .copied()
+                .unwrap_or((zero, TurboComposer::constant_zero()))
+        };
+
+        let (q_l, a) = term(0);
+        let (q_r, b) = term(1);
+        let (q_4, d) = term(2);
+        let (q_o, o) = term(3);
+
+        Gate {
+            q_m: zero,
+            q_l,
+            q_r,
+            q_o,
+            q_4,
+            q_c: constant,
+            q_arith: BlsScalar::one(),
+            q_range: zero,
+            q_logic: zero,
+            q_fixed_group_add: zero,
+            q_variable_group_add: zero,
+            q_lookup: zero,
+            a,
+            b,
+            o,
+            d,
+            pi: None,
+            fixed: false,
+        }
+    }
+
+    /// Custom gates also constrain the wires of the next gate.
+    fn is_custom(&self) -> bool {
+        let zero = BlsScalar::zero();
+
+        self.q_range != zero
+            || self.q_logic != zero
+            || self.q_fixed_group_add != zero
+            || self.q_variable_group_add != zero
+            || self.q_lookup != zero
+    }
+
+    /// Returns `true` if the gate can be rewritten as a linear constraint.
+    fn is_linear(&self) -> bool {
+        !self.fixed
+            && self.q_arith == BlsScalar::one()
+            && self.q_m == BlsScalar::zero()
+    }
+
+    const fn wires(&self) -> [Witness; 4] {
+        [self.a, self.b, self.o, self.d]
+    }
+
+    fn wires_mut(&mut self) -> [&mut Witness; 4] {
+        [&mut self.a, &mut self.b, &mut self.o, &mut self.d]
+    }
+
+    /// Returns the terms of a linear gate with a non-zero coefficient.
+    fn terms(&self) -> Vec<(BlsScalar, Witness)> {
+        let mut lc = LinearCombination::new();
+
+        lc.append_term(self.q_l, self.a);
+        lc.append_term(self.q_r, self.b);
+        lc.append_term(self.q_o, self.o);
+        lc.append_term(self.q_4, self.d);
+
+        lc.simplified_terms()
+    }
+}
+
+/// Witnesses replaced by other witnesses with the same value in every
+/// satisfying assignment.
+#[derive(Debug, Default)]
+struct Substitutions(HashMap<Witness, Witness>);
+
+impl Substitutions {
+    fn find(&self, mut witness: Witness) -> Witness {
+        while let Some(w) = self.0.get(&witness) {
+            witness = *w;
+        }
+
+        witness
+    }
+
+    /// Replaces the witness with the greater index by the other one, so the
+    /// zero witness is never replaced.
+    fn union(&mut self, a: Witness, b: Witness) {
+        let a = self.find(a);
+        let b = self.find(b);
+
+        if a.index() < b.index() {
+            self.0.insert(b, a);
+        } else if b.index() < a.index() {
+            self.0.insert(a, b);
+        }
+    }
+
+    fn apply(&self, gate: &mut Gate) {
+        gate.wires_mut()
+            .iter_mut()
+            .for_each(|w| **w = self.find(**w));
+    }
+}
+
+impl TurboComposer {
+    /// Optimizes the gates of the circuit, returning what was removed.
+    ///
+    /// The optimizer removes the gates constraining a witness to a constant
+    /// another gate already constrains a witness to, and the gates asserting
+    /// two witnesses are equal, replacing one witness by the other. It then
+    /// merges each linear gate into the next one when the output of the first
+    /// is used only by the second and the result fits in a single gate, and
+    /// removes the gates with all zero coefficients and the witnesses no gate
+    /// uses, except for the unconstrained hints.
+    ///
+    /// Gates carrying public inputs, custom gates and the gates they read
+    /// are left as they are. The public inputs keep their order and are moved
+    /// to the new positions of their gates.
+    ///
+    /// The optimizer is deterministic, so a circuit optimized when compiling
+    /// it is optimized the same way when proving it. It should run at the end
+    /// of the gadget: the remaining witnesses are re-indexed, so the
+    /// [`Witness`]es returned before the optimization must not be used after
+    /// it.
+    ///
+    /// A witness-only composer, see [`TurboComposer::is_witness_only`], isn't
+    /// optimized, as it doesn't record the selectors of its gates. Proving
+    /// its circuit afterwards fails with
+    /// [`Error::WitnessOnlyOptimization`](crate::error::Error::
+    /// WitnessOnlyOptimization).
+    pub fn optimize(&mut self) -> OptimizationReport {
+        let mut report = OptimizationReport::default();
+
+        if self.witness_only {
+            self.witness_only_optimized = true;
+            return report;
+        }
+
+        // The hints are checked before their gates are removed
+        let unconstrained_hints = self.unconstrained_hints();
+        report.unconstrained_hints = unconstrained_hints.len();
+
+        let gates = self.gates_to_optimize();
+        let mut substitutions = Substitutions::default();
+
+        // Remove constant, equality and empty gates
+        let mut constants: HashMap<[u8; 32], Witness> = HashMap::new();
+        let mut kept = Vec::with_capacity(gates.len());
+
+        for mut gate in gates {
+            substitutions.apply(&mut gate);
+
+            if !gate.is_linear() {
+                kept.push(gate);
+                continue;
+            }
+
+            match gate.terms().as_slice() {
+                [] if gate.q_c == BlsScalar::zero() => {
+                    report.empty_gates += 1;
+                    continue;
+                }
+                [(coefficient, witness)] => {
+                    // The terms have non-zero coefficients
+                    let inverse = coefficient.invert().unwrap();
+                    let constant = -gate.q_c * inverse;
+
+                    match constants.entry(constant.to_bytes()) {
+                        Entry::Occupied(entry) => {
+                            substitutions.union(*entry.get(), *witness);
+                            report.deduplicated_constants += 1;
+                            continue;
+                        }
+                        Entry::Vacant(entry) => {
+                            entry.insert(*witness);
+                        }
+                    }
+                }
+                [(c_a, a), (c_b, b)]
+                    if gate.q_c == BlsScalar::zero() && *c_a == -c_b =>
+                {
+                    substitutions.union(*a, *b);
+                    report.collapsed_equalities += 1;
+                    continue;
+                }
+                _ => (),
+            }
+
+            kept.push(gate);
+        }
+
+        // Witnesses may have been replaced after their gates were kept
+        kept.iter_mut().for_each(|gate| substitutions.apply(gate));
+
+        // Merge linear gates into the next one
+        let mut uses: HashMap<Witness, usize> = HashMap::new();
+        let count = |gate: &Gate, uses: &mut HashMap<Witness, usize>| {
+            gate.wires()
+                .iter()
+                .for_each(|w| *uses.entry(*w).or_insert(0) += 1)
+        };
+        kept.iter().for_each(|gate| count(gate, &mut uses));
+
+        let mut optimized: Vec<Gate> = Vec::with_capacity(kept.len());
+
+        for gate in kept {
+            let merged = optimized
+                .last()
+                .and_then(|previous| Self::merge_gates(previous, &gate, &uses));
+
+            match merged {
+                Some(merged) => {
+                    let previous = optimized.pop().expect("merged a gate");
+
+                    previous
+                        .wires()
+                        .iter()
+                        .chain(gate.wires().iter())
+                        .for_each(|w| {
+                            if let Some(uses) = uses.get_mut(w) {
+                                *uses -= 1;
+                            }
+                        });
+                    count(&merged, &mut uses);
+
+                    optimized.push(merged);
+                    report.merged_gates += 1;
+                }
+                None => optimized.push(gate),
+            }
+        }
+
+        report.removed_witnesses =
+            self.rebuild(&optimized, &unconstrained_hints);
+
+        report
+    }
+
+    /// Returns the gates of the circuit, flagging the ones that can't be
+    /// optimized.
+    fn gates_to_optimize(&self) -> Vec<Gate> {
+        let mut gates: Vec<Gate> = (0..self.n as usize)
+            .map(|i| Gate {
+                q_m: self.q_m[i],
+                q_l: self.q_l[i],
+                q_r: self.q_r[i],
+                q_o: self.q_o[i],
+                q_4: self.q_4[i],
+                q_c: self.q_c[i],
+                q_arith: self.q_arith[i],
+                q_range: self.q_range[i],
+                q_logic: self.q_logic[i],
+                q_fixed_group_add: self.q_fixed_group_add[i],
+                q_variable_group_add: self.q_variable_group_add[i],
+                q_lookup: self.q_lookup[i],
+                a: self.w_l[i],
+                b: self.w_r[i],
+                o: self.w_o[i],
+                d: self.w_4[i],
+                pi: self.public_inputs_sparse_store.get(&(i as u32)).copied(),
+                fixed: false,
+            })
+            .collect();
+
+        // A custom gate reads the gate after it, so it must stay next to it
+        let custom: Vec<bool> = gates.iter().map(Gate::is_custom).collect();
+
+        gates.iter_mut().enumerate().for_each(|(i, gate)| {
+            gate.fixed =
+                gate.pi.is_some() || custom[i] || (i > 0 && custom[i - 1]);
+        });
+
+        gates
+    }
+
+    /// Merges two consecutive linear gates into one, by eliminating a witness
+    /// used only by them.
+    fn merge_gates(
+        previous: &Gate,
+        gate: &Gate,
+        uses: &HashMap<Witness, usize>,
+    ) -> Option<Gate> {
+        if !previous.is_linear() || !gate.is_linear() {
+            return None;
+        }
+
+        let previous_terms = previous.terms();
+        let gate_terms = gate.terms();
+
+        previous_terms.iter().find_map(|&(c_previous, x)| {
+            if x == Self::constant_zero() || uses.get(&x) != Some(&2) {
+                return None;
+            }
+
+            let (c_gate, _) = gate_terms.iter().find(|(_, w)| *w == x)?;
+
+            // x = -(Σ c_i · w_i + q_c) / c_previous in the previous gate
+            // The terms have non-zero coefficients
+            let inverse = c_previous.invert().unwrap();
+            let factor = -c_gate * inverse;
+
+            let mut lc = LinearCombination::new();
+
+            gate_terms
+                .iter()
+                .filter(|(_, w)| *w != x)
+                .for_each(|&(c, w)| lc.append_term(c, w));
+            previous_terms
+                .iter()
+                .filter(|(_, w)| *w != x)
+                .for_each(|&(c, w)| lc.append_term(c * factor, w));
+
+            let terms = lc.simplified_terms();
+
+            (terms.len() <= 4)
+                .then(|| Gate::linear(&terms, gate.q_c + previous.q_c * factor))
+        })
+    }
+
+    /// Replaces the gates of the circuit, re-indexing the witnesses they use,
+    /// the `kept` witnesses and their public inputs. Returns the number of
+    /// witnesses removed.
+    fn rebuild(&mut self, gates: &[Gate], kept: &[Witness]) -> usize {
+        let witnesses = self.perm.variable_map.len();
+
+        // The zero witness is always kept at index zero
+        let mut used = vec![false; witnesses];
+        used[Self::constant_zero().index()] = true;
+        kept.iter().for_each(|w| used[w.index()] = true);
+        gates.iter().for_each(|gate| {
+            gate.wires().iter().for_each(|w| used[w.index()] = true)
+        });
+
+        let mut indexes: Vec<Option<Witness>> = vec![None; witnesses];
+        let mut perm = Permutation::new();
+        let mut values = HashMap::with_capacity(witnesses);
+
+        used.iter().enumerate().filter(|(_, used)| **used).for_each(
+            |(index, _)| {
+                let old = Witness::new(index);
+                let new = perm.new_variable();
+
+                values.insert(new, self.witnesses[&old]);
+                indexes[index] = Some(new);
+            },
+        );
+
+        let removed = witnesses - values.len();
+        let reindex = |w: Witness| indexes[w.index()].expect("used witness");
+
+        self.n = 0;
+        self.q_m.clear();
+        self.q_l.clear();
+        self.q_r.clear();
+        self.q_o.clear();
+        self.q_4.clear();
+        self.q_c.clear();
+        self.q_arith.clear();
+        self.q_range.clear();
+        self.q_logic.clear();
+        self.q_fixed_group_add.clear();
+        self.q_variable_group_add.clear();
+        self.q_lookup.clear();
+        self.w_l.clear();
+        self.w_r.clear();
+        self.w_o.clear();
+        self.w_4.clear();
+        self.public_inputs_sparse_store = BTreeMap::new();
+
+        gates.iter().for_each(|gate| {
+            let [a, b, o, d] = gate.wires();
+            let (a, b, o, d) = (reindex(a), reindex(b), reindex(o), reindex(d));
+
+            self.q_m.push(gate.q_m);
+            self.q_l.push(gate.q_l);
+            self.q_r.push(gate.q_r);
+            self.q_o.push(gate.q_o);
+            self.q_4.push(gate.q_4);
+            self.q_c.push(gate.q_c);
+            self.q_arith.push(gate.q_arith);
+            self.q_range.push(gate.q_range);
+            self.q_logic.push(gate.q_logic);
+            self.q_fixed_group_add.push(gate.q_fixed_group_add);
+            self.q_variable_group_add.push(gate.q_variable_group_add);
+            self.q_lookup.push(gate.q_lookup);
+
+            self.w_l.push(a);
+            self.w_r.push(b);
+            self.w_o.push(o);
+            self.w_4.push(d);
+
+            if let Some(pi) = gate.pi {
+                self.public_inputs_sparse_store.insert(self.n, pi);
+            }
+
+            perm.add_variables_to_map(a, b, o, d, self.n as usize);
+
+            self.n += 1;
+        });
+
+        self.hints = self
+            .hints
+            .iter()
+            .filter_map(|hint| indexes[hint.index()])
+            .collect();
+        self.witnesses = values;
+        self.perm = perm;
+
+        removed
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constraint_system::helper::*;
+    use crate::constraint_system::Constraint;
+    use crate::plonkup::LookupTable;
+
+    /// Appends most of the gadgets of the composer, with a few gates the
+    /// optimizer removes. The circuit is satisfied if `a` is 10.
+    fn gadget_suite(composer: &mut TurboComposer, a: u64) {
+        let a = composer.append_witness(BlsScalar::from(a));
+        let b = composer.append_witness(BlsScalar::from(20u64));
+        let bit = composer.append_witness(BlsScalar::one());
+        let two = composer.append_witness(BlsScalar::from(2u64));
+
+        // Duplicated constants
+        let one = composer.append_constant(BlsScalar::one());
+        let one_again = composer.append_constant(BlsScalar::one());
+        let ten = composer.append_constant(BlsScalar::from(10u64));
+        composer.assert_equal(a, ten);
+
+        // Chained additions, a + b + 1 + 1 = 32
+        let constraint = Constraint::new().left(1).right(1).a(a).b(b);
+        let sum = composer.gate_add(constraint);
+        let constraint = Constraint::new().left(1).right(1).a(sum).b(one);
+        let sum = composer.gate_add(constraint);
+        let constraint = Constraint::new().left(1).right(1).a(sum).b(one_again);
+        let sum = composer.gate_add(constraint);
+        composer.assert_equal_constant(sum, BlsScalar::from(32u64), None);
+
+        composer.component_boolean(bit);
+        let choice = composer.component_select(bit, b, a);
+        composer.assert_equal(choice, b);
+
+        composer.component_range(a, 8);
+        composer.component_range_lookup(b, 8, 4);
+
+        let xor = composer.component_xor(a, b, 8);
+        composer.assert_equal_constant(xor, BlsScalar::from(10u64 ^ 20), None);
+
+        let bits = composer.component_decomposition::<8>(a);
+        composer.assert_equal(bits[1], one);
+
+        let generator = composer.append_point(dusk_jubjub::GENERATOR);
+        let double = composer.component_add_point(generator, generator);
+        let mul = composer
+            .component_mul_generator(two, dusk_jubjub::GENERATOR_EXTENDED);
+        composer.assert_equal_point(double, mul);
+
+        let public = composer.append_public(BlsScalar::from(30u64));
+        composer.assert_linear_combination(&(a + b - public));
+
+        let table = LookupTable::from_rows(vec![[
+            BlsScalar::from(10u64),
+            BlsScalar::from(20u64),
+            BlsScalar::from(30u64),
+            BlsScalar::zero(),
+        ]]);
+        let table = composer.append_plonkup_table(&table);
+        let zero = TurboComposer::constant_zero();
+        composer.append_plonkup_gate(table, a, b, public, zero, None);
+
+        let mut rom = composer.component_rom(&[a, b, sum]);
+        let value = composer.component_memory_read(&mut rom, one);
+        composer.assert_equal(value, b);
+        composer.component_memory_finalize(rom);
+
+        // Unused witness
+        composer.append_witness(BlsScalar::from(7u64));
+    }
+
+    #[test]
+    fn test_optimize_gadget_suite() {
+        let mut composer = TurboComposer::new();
+        gadget_suite(&mut composer, 10);
+
+        let gates = composer.gates() as usize;
+        let public_inputs = composer.public_inputs();
+        let report = composer.optimize();
+
+        assert_eq!(composer.gates() as usize, gates - report.removed_gates());
+        assert_eq!(composer.public_inputs(), public_inputs);
+
+        assert!(report.deduplicated_constants >= 1);
+        assert!(report.collapsed_equalities >= 3);
+        assert!(report.merged_gates >= 2);
+        assert!(report.removed_witnesses >= 1);
+
+        let res = gadget_tester(
+            |composer| {
+                gadget_suite(composer, 10);
+                composer.optimize();
+            },
+            1 << 11,
+        );
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn test_optimize_gadget_suite_fail() {
+        let res = gadget_tester(
+            |composer| {
+                gadget_suite(composer, 11);
+                composer.optimize();
+            },
+            1 << 11,
+        );
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_optimize_constants() {
+        let res = gadget_tester(
+            |composer| {
+                let gates = composer.gates();
+
+                let a = composer.append_witness(BlsScalar::from(5u64));
+                let k = composer.append_constant(BlsScalar::from(5u64));
+                let zero = composer.append_constant(BlsScalar::zero());
+                let k_again = composer.append_constant(BlsScalar::from(5u64));
+                let constraint = Constraint::new()
+                    .left(1)
+                    .right(1)
+                    .fourth(1)
+                    .a(k)
+                    .b(k_again)
+                    .d(zero);
+                let sum = composer.gate_add(constraint);
+                composer.assert_equal_constant(a, BlsScalar::from(5u64), None);
+                composer.assert_equal_constant(
+                    sum,
+                    BlsScalar::from(10u64),
+                    None,
+                );
+
+                let report = composer.optimize();
+                assert_eq!(report.deduplicated_constants, 3);
+                assert_eq!(report.merged_gates, 1);
+
+                // a = 5 and 2 · a = 10
+                assert_eq!(composer.gates(), gates + 2);
+            },
+            32,
+        );
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn test_optimize_merge_linear_gates() {
+        let mut composer = TurboComposer::new();
+        let w: Vec<Witness> = (1..=5u64)
+            .map(|v| composer.append_witness(BlsScalar::from(v)))
+            .collect();
+
+        // (((w0 + w1) + w2) + w3) + w4, one gate per addition
+        let sum = w[1..].iter().fold(w[0], |acc, w| {
+            let constraint = Constraint::new().left(1).right(1).a(acc).b(*w);
+            composer.gate_add(constraint)
+        });
+        composer.assert_equal_constant(sum, BlsScalar::from(15u64), None);
+
+        let gates = composer.gates();
+        let report = composer.optimize();
+
+        // w0 + w1 + w2 - s2 = 0, s2 + w3 + w4 - 15 = 0
+        assert_eq!(report.merged_gates, 3);
+        assert_eq!(report.removed_witnesses, 3);
+        assert_eq!(composer.gates(), gates - 3);
+    }
+
+    #[test]
+    fn test_optimize_public_inputs() {
+        let res = gadget_tester(
+            |composer| {
+                let a = composer.append_witness(BlsScalar::from(3u64));
+                let empty = Constraint::new().a(a);
+
+                composer.append_gate(empty);
+                let x = composer.append_public(BlsScalar::from(3u64));
+                composer.append_gate(empty);
+                let y = composer.append_public(BlsScalar::from(9u64));
+
+                composer.assert_equal(a, x);
+                let constraint = Constraint::new().mult(1).a(a).b(x);
+                let square = composer.gate_mul(constraint);
+                composer.assert_equal(square, y);
+
+                let indexes = composer.public_input_indexes();
+                let report = composer.optimize();
+
+                assert_eq!(report.empty_gates, 2);
+                assert_eq!(
+                    composer.public_input_indexes(),
+                    vec![indexes[0] - 1, indexes[1] - 2]
+                );
+                assert_eq!(
+                    composer.public_inputs(),
+                    vec![BlsScalar::from(3u64), BlsScalar::from(9u64)]
+                );
+            },
+            32,
+        );
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn test_optimize_hints() {
+        let mut composer = TurboComposer::new();
+
+        composer.append_witness(BlsScalar::from(2u64));
+        let a = composer.append_witness(BlsScalar::from(3u64));
+        let [square] = composer.append_hint(&[a], |v| [v[0].square()]);
+
+        let constraint = Constraint::new().mult(1).a(a).b(a);
+        let result = composer.gate_mul(constraint);
+        composer.assert_equal(result, square);
+
+        let report = composer.optimize();
+        assert_eq!(report.removed_witnesses, 2);
+        assert_eq!(report.unconstrained_hints, 0);
+
+        let hints = composer.hints.clone();
+        assert_eq!(hints.len(), 1);
+        assert_eq!(composer.witnesses[&hints[0]], BlsScalar::from(9u64));
+        assert!(composer.unconstrained_hints().is_empty());
+    }
+
+    #[test]
+    fn test_optimize_dead_hints() {
+        let mut composer = TurboComposer::new();
+
+        let a = composer.append_witness(BlsScalar::from(3u64));
+        let [double] = composer.append_hint(&[a], |v| [v[0].double()]);
+        let [square] = composer.append_hint(&[a], |v| [v[0].square()]);
+        composer.assert_equal_constant(a, BlsScalar::from(3u64), None);
+
+        // `double` is never used, and `square` only in an empty gate
+        composer.append_gate(Constraint::new().a(square));
+        assert_eq!(composer.unconstrained_hints(), vec![double, square]);
+
+        let report = composer.optimize();
+        assert_eq!(report.empty_gates, 1);
+        assert_eq!(report.unconstrained_hints, 2);
+        assert_eq!(report.removed_witnesses, 0);
+
+        // The dead hints are still reported once their gates are removed
+        let hints = composer.unconstrained_hints();
+        assert_eq!(hints, composer.hints);
+        assert_eq!(composer.witnesses[&hints[0]], BlsScalar::from(6u64));
+        assert_eq!(composer.witnesses[&hints[1]], BlsScalar::from(9u64));
+    }
+}
@@ -6,7 +6,8 @@
 
 use crate::bit_iterator::*;
 use crate::constraint_system::TurboComposer;
-use crate::constraint_system::{WireData, Witness};
+use crate::constraint_system::{Constraint, WireData, Witness};
+use crate::plonkup::{LookupTable, LookupTableId};
 use dusk_bls12_381::BlsScalar;
 use dusk_bytes::Serializable;
 use sp_std::vec;
@@ -51,7 +52,9 @@ impl TurboComposer {
                     _ => unreachable!(),
                 };
 
-                composer.perm.add_variable_to_map(witness, wire_data);
+                if !composer.witness_only {
+                    composer.perm.add_variable_to_map(witness, wire_data);
+                }
             };
 
         // Note: A quad is a quaternary digit
@@ -161,28 +164,32 @@ impl TurboComposer {
         }
 
         // Set the selector polynomials for all of the gates we used
-        let zeros = vec![BlsScalar::zero(); used_gates];
-        let ones = vec![BlsScalar::one(); used_gates];
-
-        self.q_m.extend(zeros.iter());
-        self.q_l.extend(zeros.iter());
-        self.q_r.extend(zeros.iter());
-        self.q_o.extend(zeros.iter());
-        self.q_c.extend(zeros.iter());
-        self.q_arith.extend(zeros.iter());
-        self.q_4.extend(zeros.iter());
-        self.q_fixed_group_add.extend(zeros.iter());
-        self.q_variable_group_add.extend(zeros.iter());
-        self.q_range.extend(ones.iter());
-        self.q_logic.extend(zeros.iter());
-        self.q_lookup.extend(zeros.iter());
+        if !self.witness_only {
+            let zeros = vec![BlsScalar::zero(); used_gates];
+            let ones = vec![BlsScalar::one(); used_gates];
+
+            self.q_m.extend(zeros.iter());
+            self.q_l.extend(zeros.iter());
+            self.q_r.extend(zeros.iter());
+            self.q_o.extend(zeros.iter());
+            self.q_c.extend(zeros.iter());
+            self.q_arith.extend(zeros.iter());
+            self.q_4.extend(zeros.iter());
+            self.q_fixed_group_add.extend(zeros.iter());
+            self.q_variable_group_add.extend(zeros.iter());
+            self.q_range.extend(ones.iter());
+            self.q_logic.extend(zeros.iter());
+            self.q_lookup.extend(zeros.iter());
+
+            // As mentioned above, we must switch off the range constraint for
+            // the last gate Remember; it will contain one quad in the fourth
+            // wire, which will be used in the gate before it
+            *self.q_range.last_mut().unwrap() = BlsScalar::zero();
+        }
+
         self.n += used_gates as u32;
 
-        // As mentioned above, we must switch off the range constraint for the
-        // last gate Remember; it will contain one quad in the fourth
-        // wire, which will be used in the gate before it
-        // Furthermore, we set the left, right and output wires to zero
-        *self.q_range.last_mut().unwrap() = BlsScalar::zero();
+        // The left, right and output wires of the last gate are set to zero
         self.w_l.push(Self::constant_zero());
         self.w_r.push(Self::constant_zero());
         self.w_o.push(Self::constant_zero());
@@ -194,12 +201,145 @@ impl TurboComposer {
         self.assert_equal(accumulators[last_accumulator], witness);
         accumulators[last_accumulator] = witness;
     }
+
+    /// Adds a lookup-backed range constraint that checks and constrains a
+    /// [`Witness`] to be inside of the range \[0, 2^num_bits\).
+    ///
+    /// The witness is decomposed into limbs of `limb_bits` bits, and each of
+    /// them is looked up in a range table of `2^limb_bits` rows. The table is
+    /// registered in the circuit the first time a limb size is used. When
+    /// `num_bits` is not a multiple of `limb_bits`, the top limb is also
+    /// looked up shifted to the top of a limb, so it can't exceed the bits
+    /// left for it.
+    ///
+    /// This function adds `⌈num_bits / limb_bits⌉` lookup gates, plus half as
+    /// many gates to accumulate the limbs and two more gates for the top limb
+    /// check. Since a circuit is padded to the size of its lookup tables,
+    /// `2^limb_bits` should not exceed the size of the circuit.
+    ///
+    /// The range tables are capped at `2^16` rows, so `limb_bits` can be at
+    /// most 16. `num_bits` can be at most 252, so that the accumulated limbs
+    /// never wrap around the 255 bits scalar field.
+    ///
+    ///# Panics
+    /// This function will panic if `limb_bits` is zero or larger than 16, or
+    /// if `num_bits` is larger than 252.
+    pub fn component_range_lookup(
+        &mut self,
+        witness: Witness,
+        num_bits: usize,
+        limb_bits: usize,
+    ) {
+        assert!(0 < limb_bits && limb_bits <= 16);
+        assert!(num_bits <= 252);
+
+        if num_bits == 0 {
+            self.assert_equal_constant(witness, BlsScalar::zero(), None);
+            return;
+        }
+
+        let table = self.range_table(limb_bits);
+        let num_limbs = (num_bits + limb_bits - 1) / limb_bits;
+
+        // A witness that fits in a single limb is looked up directly
+        let limbs: Vec<Witness> = if num_limbs == 1 {
+            vec![witness]
+        } else {
+            let bits = self.witnesses[&witness].to_bits();
+
+            bits.chunks(limb_bits)
+                .take(num_limbs)
+                .map(|chunk| {
+                    let limb = chunk
+                        .iter()
+                        .rev()
+                        .fold(0u64, |limb, bit| (limb << 1) | *bit as u64);
+
+                    self.append_witness(BlsScalar::from(limb))
+                })
+                .collect()
+        };
+
+        limbs
+            .iter()
+            .for_each(|limb| self.append_range_lookup(table, *limb));
+
+        // The top limb is in range if both itself and its value shifted by
+        // the bits it lacks to fill a limb are in the table
+        let top_bits = num_bits - (num_limbs - 1) * limb_bits;
+        if top_bits < limb_bits {
+            let shift = BlsScalar::pow_of_2((limb_bits - top_bits) as u64);
+            let constraint =
+                Constraint::new().left(shift).a(limbs[num_limbs - 1]);
+            let shifted = self.gate_add(constraint);
+
+            self.append_range_lookup(table, shifted);
+        }
+
+        if num_limbs == 1 {
+            return;
+        }
+
+        // Accumulate the limbs two at a time, constraining the output of the
+        // last gate to be the witness itself
+        let limb_shift = |i: usize| BlsScalar::pow_of_2((i * limb_bits) as u64);
+        let gates = num_limbs / 2;
+
+        let mut acc = limbs[0];
+        for gate in 0..gates {
+            let i = 1 + 2 * gate;
+            let (d, fourth) = match limbs.get(i + 1) {
+                Some(limb) => (*limb, limb_shift(i + 1)),
+                None => (Self::constant_zero(), BlsScalar::zero()),
+            };
+
+            let constraint = Constraint::new()
+                .left(1)
+                .right(limb_shift(i))
+                .fourth(fourth)
+                .a(acc)
+                .b(limbs[i])
+                .d(d);
+
+            if gate + 1 < gates {
+                acc = self.gate_add(constraint);
+            } else {
+                let constraint =
+                    constraint.output(-BlsScalar::one()).o(witness);
+                self.append_gate(constraint);
+            }
+        }
+    }
+
+    /// Returns the id of the range table of `limb_bits` bits, registering it
+    /// in the circuit if it's not there yet.
+    fn range_table(&mut self, limb_bits: usize) -> LookupTableId {
+        if let Some(table) = self.range_tables.get(&limb_bits) {
+            return *table;
+        }
+
+        let mut table = LookupTable::new();
+        table.insert_range(limb_bits as u8);
+
+        let table = self.append_plonkup_table(&table);
+        self.range_tables.insert(limb_bits, table);
+
+        table
+    }
+
+    /// Looks `witness` up in the range `table`.
+    fn append_range_lookup(&mut self, table: LookupTableId, witness: Witness) {
+        let zero = Self::constant_zero();
+
+        self.append_plonkup_gate(table, witness, zero, zero, zero, None);
+    }
 }
 
 #[cfg(feature = "std")]
 #[cfg(test)]
 mod tests {
     use super::super::helper::*;
+    use super::TurboComposer;
     use dusk_bls12_381::BlsScalar;
 
     #[test]
@@ -252,4 +392,100 @@ mod tests {
             200,
         );
     }
+
+    #[test]
+    fn test_range_lookup() {
+        // Should fail as the number is not 32 bits
+        let res = gadget_tester(
+            |composer| {
+                let witness = composer.append_witness(BlsScalar::from(
+                    (u32::max_value() as u64) + 1,
+                ));
+                composer.component_range_lookup(witness, 32, 4);
+            },
+            200,
+        );
+        assert!(res.is_err());
+
+        // Should pass as the number is within 33 bits
+        let res = gadget_tester(
+            |composer| {
+                let witness =
+                    composer.append_witness(BlsScalar::from(2u64.pow(33) - 1));
+                composer.component_range_lookup(witness, 33, 4);
+            },
+            200,
+        );
+        assert!(res.is_ok());
+
+        // Should fail as the top limb exceeds its single bit
+        let res = gadget_tester(
+            |composer| {
+                let witness =
+                    composer.append_witness(BlsScalar::from(2u64.pow(33)));
+                composer.component_range_lookup(witness, 33, 4);
+            },
+            200,
+        );
+        assert!(res.is_err());
+
+        // Should pass as the number is within 3 bits, in a single limb
+        let res = gadget_tester(
+            |composer| {
+                let witness = composer.append_witness(BlsScalar::from(7u64));
+                composer.component_range_lookup(witness, 3, 4);
+            },
+            200,
+        );
+        assert!(res.is_ok());
+
+        // Should fail as the number is greater than 3 bits
+        let res = gadget_tester(
+            |composer| {
+                let witness = composer.append_witness(BlsScalar::from(8u64));
+                composer.component_range_lookup(witness, 3, 4);
+            },
+            200,
+        );
+        assert!(res.is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_range_lookup_large_limbs() {
+        let mut composer = TurboComposer::new();
+        let witness = composer.append_witness(BlsScalar::one());
+
+        composer.component_range_lookup(witness, 32, 17);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_range_lookup_large_range() {
+        let mut composer = TurboComposer::new();
+        let witness = composer.append_witness(BlsScalar::one());
+
+        composer.component_range_lookup(witness, 253, 4);
+    }
+
+    #[test]
+    fn test_range_lookup_gates() {
+        let mut composer = TurboComposer::new();
+        let witness = composer.append_witness(BlsScalar::from(u64::MAX));
+
+        let gates = composer.gates();
+        composer.component_range(witness, 64);
+        let range_gates = composer.gates() - gates;
+
+        let gates = composer.gates();
+        composer.component_range_lookup(witness, 64, 16);
+        let lookup_gates = composer.gates() - gates;
+
+        assert_eq!(range_gates, 10);
+        assert_eq!(lookup_gates, 6);
+
+        // The range table is registered only once
+        composer.component_range_lookup(witness, 64, 16);
+        assert_eq!(composer.lookup_tables.len(), 2);
+    }
 }
@@ -6,6 +6,7 @@
 
 //! A collection of all possible errors encountered in PLONK.
 
+use crate::constraint_system::Witness;
 use dusk_bytes::Error as DuskBytesError;
 
 /// Defines all possible errors that can be encountered in PLONK.
@@ -24,6 +25,10 @@ pub enum Error {
     // Prover/Verifier errors
     /// This error occurs when a proof verification fails.
     ProofVerificationError,
+    /// This error occurs when a proof of a circuit with lookups is made with
+    /// another [`LookupArgument`](crate::plonkup::LookupArgument) than the
+    /// one recorded in the verifier key.
+    LookupArgumentMismatch,
     /// This error occurs when the circuit is not provided with all of the
     /// required inputs.
     CircuitInputsNotFound,
@@ -32,14 +37,53 @@ pub enum Error {
     UninitializedPIGenerator,
     /// PublicInput serialization error
     InvalidPublicInputBytes,
+    /// This error occurs when the number of public inputs given to verify a
+    /// proof doesn't match the number of public input positions of the
+    /// circuit.
+    PublicInputsLengthMismatch {
+        /// Number of public input positions of the circuit
+        expected: usize,
+        /// Number of public inputs given
+        found: usize,
+    },
+    /// This error occurs when a public input position lies outside of the
+    /// circuit.
+    PublicInputIndexOutOfBounds {
+        /// Offending position
+        index: u32,
+    },
+    /// This error occurs when a public input position is given twice.
+    PublicInputIndexDuplicated {
+        /// Duplicated position
+        index: u32,
+    },
+    /// This error occurs when the public input positions are not sorted in
+    /// increasing order.
+    PublicInputIndexesUnsorted,
     /// This error occurs when the Prover structure already contains a
     /// preprocessed circuit inside, but you call preprocess again.
     CircuitAlreadyPreprocessed,
+    /// This error occurs when a circuit is proven with a different shape than
+    /// the one it was compiled with, which happens when its gadget branches
+    /// on the value of a witness.
+    CircuitShapeMismatch,
+    /// This error occurs when the circuit of a witness-only composer, which
+    /// doesn't record the selectors of its gates, is preprocessed.
+    WitnessOnlyComposer,
+    /// This error occurs when a witness-only composer that was asked to
+    /// optimize its circuit is proven, as the optimized wires can't be
+    /// replayed without the selectors of the gates.
+    WitnessOnlyOptimization,
 
     // Preprocessing errors
     /// This error occurs when an error triggers during the preprocessing
     /// stage.
     MismatchedPolyLen,
+    /// This error occurs when a witness returned by
+    /// [`append_hint`](crate::constraint_system::TurboComposer::append_hint)
+    /// doesn't take part in any constraint of a circuit checked with
+    /// [`check_hints`](crate::constraint_system::TurboComposer::check_hints).
+    UnconstrainedHint(Witness),
 
     // KZG10 errors
     /// This error occurs when the user tries to create PublicParameters
@@ -60,6 +104,50 @@ pub enum Error {
     /// This error occurs when the pairing check fails at being equal to the
     /// Identity point.
     PairingCheckFailure,
+    /// This error occurs when an opening proof is created for, or checked
+    /// against, no polynomials or a number of commitments different from its
+    /// number of evaluations.
+    OpeningProofMismatch,
+    /// This error occurs when the first power of the PublicParameters is not
+    /// the generator of their OpeningKey.
+    PublicParametersGeneratorMismatch,
+    /// This error occurs when one of the generators of the PublicParameters
+    /// or their `beta_h` is the identity point.
+    PublicParametersIdentityPoint,
+    /// This error occurs when a power of the PublicParameters is not beta
+    /// times the previous one.
+    PublicParametersPowerMismatch {
+        /// Index of the first offending power
+        index: usize,
+    },
+
+    // Ceremony import errors
+    /// This error occurs when a powers-of-tau ceremony transcript cannot be
+    /// parsed.
+    CeremonyTranscriptMalformed,
+    /// This error occurs when a powers-of-tau ceremony transcript does not
+    /// contain enough powers for the requested degree.
+    CeremonyNotEnoughPowers,
+    /// This error occurs when the powers of a ceremony transcript are not
+    /// consistent with each other.
+    CeremonyInconsistentPowers,
+    /// This error occurs when the proof of knowledge of a ceremony
+    /// contribution secret is invalid.
+    ContributionProofInvalid,
+    /// This error occurs when a ceremony contribution was not applied on top
+    /// of the previous one.
+    ContributionChainBroken,
+    /// This error occurs when reading or writing the parameters of a
+    /// ceremony fails.
+    CeremonyIoError,
+
+    // IPA errors
+    /// This error occurs when the final check of an inner product argument
+    /// opening fails.
+    InnerProductCheckFailure,
+    /// This error occurs when the number of rounds of an inner product
+    /// argument opening does not match the size of the opening key.
+    InnerProductRoundsMismatch,
 
     // Serialization errors
     /// Dusk-bytes serialization error
@@ -72,6 +160,9 @@ pub enum Error {
     /// This error occurs when a malformed BLS scalar is decoded from a byte
     /// array.
     BlsScalarMalformed,
+    /// This error occurs when a proof is encoded with a version this library
+    /// doesn't know how to decode.
+    UnsupportedProofVersion(u8),
 
     // Plonkup errors
     /// Query element not found in lookup table
@@ -96,6 +187,9 @@ impl std::fmt::Display for Error {
             Self::ProofVerificationError => {
                 write!(f, "proof verification failed")
             }
+            Self::LookupArgumentMismatch => {
+                write!(f, "proof lookup argument differs from the key one")
+            }
             Self::CircuitInputsNotFound => {
                 write!(f, "circuit inputs not found")
             }
@@ -105,12 +199,44 @@ impl std::fmt::Display for Error {
             Self::InvalidPublicInputBytes => {
                 write!(f, "invalid public input bytes")
             }
+            Self::PublicInputsLengthMismatch { expected, found } => write!(
+                f,
+                "expected {} public inputs, found {}",
+                expected, found
+            ),
+            Self::PublicInputIndexOutOfBounds { index } => {
+                write!(f, "public input position {} out of bounds", index)
+            }
+            Self::PublicInputIndexDuplicated { index } => {
+                write!(f, "public input position {} is duplicated", index)
+            }
+            Self::PublicInputIndexesUnsorted => {
+                write!(f, "public input positions are not sorted")
+            }
+            Self::UnconstrainedHint(hint) => write!(
+                f,
+                "hint witness {:?} doesn't take part in any constraint, so \
+                 it's unconstrained",
+                hint
+            ),
             Self::MismatchedPolyLen => {
                 write!(f, "the length of the wires is not the same")
             }
             Self::CircuitAlreadyPreprocessed => {
                 write!(f, "circuit has already been preprocessed")
             }
+            Self::CircuitShapeMismatch => write!(
+                f,
+                "circuit shape differs from the one it was compiled with"
+            ),
+            Self::WitnessOnlyComposer => write!(
+                f,
+                "the circuit of a witness-only composer can't be preprocessed"
+            ),
+            Self::WitnessOnlyOptimization => write!(
+                f,
+                "the circuit of a witness-only composer can't be optimized"
+            ),
             Self::DegreeIsZero => {
                 write!(f, "cannot create PublicParameters with max degree 0")
             }
@@ -129,9 +255,54 @@ impl std::fmt::Display for Error {
                 write!(f, "cannot commit to polynomial of zero degree")
             }
             Self::PairingCheckFailure => write!(f, "pairing check failed"),
+            Self::OpeningProofMismatch => write!(
+                f,
+                "opening proof evaluations do not match the commitments"
+            ),
+            Self::PublicParametersGeneratorMismatch => write!(
+                f,
+                "first power of PublicParameters is not the G1 generator"
+            ),
+            Self::PublicParametersIdentityPoint => {
+                write!(f, "PublicParameters contain an identity generator")
+            }
+            Self::PublicParametersPowerMismatch { index } => write!(
+                f,
+                "power {} of PublicParameters is not beta times the previous",
+                index
+            ),
+            Self::CeremonyTranscriptMalformed => {
+                write!(f, "ceremony transcript malformed")
+            }
+            Self::CeremonyNotEnoughPowers => {
+                write!(f, "ceremony transcript does not contain enough powers")
+            }
+            Self::CeremonyInconsistentPowers => {
+                write!(f, "ceremony transcript powers are inconsistent")
+            }
+            Self::ContributionProofInvalid => {
+                write!(f, "contribution proof of knowledge is invalid")
+            }
+            Self::ContributionChainBroken => write!(
+                f,
+                "contribution was not applied on top of the previous one"
+            ),
+            Self::CeremonyIoError => {
+                write!(f, "failed to read or write the ceremony parameters")
+            }
+            Self::InnerProductCheckFailure => {
+                write!(f, "inner product argument check failed")
+            }
+            Self::InnerProductRoundsMismatch => write!(
+                f,
+                "inner product argument rounds do not match the opening key"
+            ),
             Self::NotEnoughBytes => write!(f, "not enough bytes left to read"),
             Self::PointMalformed => write!(f, "BLS point bytes malformed"),
             Self::BlsScalarMalformed => write!(f, "BLS scalar bytes malformed"),
+            Self::UnsupportedProofVersion(version) => {
+                write!(f, "unsupported proof encoding version {}", version)
+            }
             Self::BytesError(err) => write!(f, "{:?}", err),
             Self::ElementNotIndexed => write!(
                 f,
@@ -6,6 +6,7 @@
 
 use crate::constraint_system::{WireData, Witness};
 use crate::fft::{EvaluationDomain, Polynomial};
+use crate::util::batch_inversion;
 use constants::{K1, K2, K3};
 use dusk_bls12_381::BlsScalar;
 use hashbrown::HashMap;
@@ -356,6 +357,49 @@ impl Permutation {
 
         Polynomial::from_coefficients_vec(domain.ifft(&p))
     }
+
+    /// Computes the running sum polynomial of the logUp lookup argument.
+    /// Starting from zero, every gate adds `q_lookup / (ε + f)` for its query
+    /// and subtracts `m / (ε + t)` for the table row with the same index, so
+    /// the sum wraps around to zero if every query is in the table.
+    pub(crate) fn compute_logup_running_sum_poly(
+        &self,
+        domain: &EvaluationDomain,
+        f: &[BlsScalar],
+        q_lookup: &[BlsScalar],
+        t: &[BlsScalar],
+        m: &[BlsScalar],
+        epsilon: &BlsScalar,
+    ) -> Polynomial {
+        let n = domain.size();
+
+        assert_eq!(f.len(), domain.size());
+        assert_eq!(q_lookup.len(), domain.size());
+        assert_eq!(t.len(), domain.size());
+        assert_eq!(m.len(), domain.size());
+
+        // Invert all of the denominators at once
+        let mut f_inv: Vec<BlsScalar> = f.iter().map(|f| epsilon + f).collect();
+        let mut t_inv: Vec<BlsScalar> = t.iter().map(|t| epsilon + t).collect();
+        batch_inversion(&mut f_inv);
+        batch_inversion(&mut t_inv);
+
+        let mut state = BlsScalar::zero();
+        let mut phi = Vec::with_capacity(n);
+        phi.push(state);
+
+        for (q, f_inv, m, t_inv) in izip!(q_lookup, f_inv, m, t_inv) {
+            state += q * f_inv - m * t_inv;
+            phi.push(state);
+        }
+
+        // remove the last element
+        phi.remove(n);
+
+        assert_eq!(n, phi.len());
+
+        Polynomial::from_coefficients_vec(domain.ifft(&phi))
+    }
 }
 
 fn plonkup_numerator_irreducible(
@@ -11,12 +11,15 @@
 //! output of gates within a circuit, without
 //! computing them.
 
+pub(crate) mod argument;
 pub(crate) mod multiset;
 pub(crate) mod table;
 
+pub use argument::LookupArgument;
 pub use multiset::MultiSet;
 pub use table::hash_tables::constants;
 pub use table::{
-    lookup_table::LookupTable, preprocess::PreprocessedLookupTable,
+    lookup_table::{LookupTable, LookupTableId},
+    preprocess::PreprocessedLookupTable,
     witness_table::WitnessTable,
 };
@@ -0,0 +1,59 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use parity_scale_codec::{Decode, Encode};
+
+/// Argument used to prove that the queries of the lookup gates of a circuit
+/// are rows of its lookup tables.
+///
+/// The argument is chosen when the circuit is preprocessed and recorded in
+/// its [`ProverKey`](crate::proof_system::ProverKey) and
+/// [`VerifierKey`](crate::proof_system::VerifierKey). A proof made with
+/// another argument than the one of the verifier key is rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Decode, Encode)]
+pub enum LookupArgument {
+    /// The plookup argument, which commits to the compressed queries and to
+    /// the sorted concatenation of the queries and the table.
+    Plookup,
+    /// A logarithmic derivative argument, which commits to the number of
+    /// times every table row is queried and to a running sum proving that
+    /// `Σ 1 / (ε + f_i) = Σ m_i / (ε + t_i)`.
+    ///
+    /// Unlike plookup it doesn't sort the queries together with the table
+    /// on every proof, and every row of a multi-column table is a single
+    /// element of the sums.
+    ///
+    /// The table takes a row of the evaluation domain per row, so circuits
+    /// are padded to at least the number of rows of their tables. Tables
+    /// larger than the circuit grow the domain, and with it the degree of
+    /// the public parameters needed to prove it.
+    LogUp,
+}
+
+impl LookupArgument {
+    /// Encodes the argument as a single byte.
+    pub(crate) const fn to_byte(self) -> u8 {
+        match self {
+            LookupArgument::Plookup => 0,
+            LookupArgument::LogUp => 1,
+        }
+    }
+
+    /// Decodes the argument encoded with [`LookupArgument::to_byte`].
+    pub(crate) const fn from_byte(byte: u8) -> Option<Self> {
+        match byte {
+            0 => Some(LookupArgument::Plookup),
+            1 => Some(LookupArgument::LogUp),
+            _ => None,
+        }
+    }
+}
+
+impl Default for LookupArgument {
+    fn default() -> Self {
+        LookupArgument::Plookup
+    }
+}
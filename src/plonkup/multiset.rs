@@ -9,6 +9,7 @@ use crate::fft::{EvaluationDomain, Polynomial};
 use core::ops::{Add, Mul};
 use dusk_bls12_381::BlsScalar;
 use dusk_bytes::{DeserializableSlice, Serializable};
+use hashbrown::HashMap;
 use sp_std::vec;
 use sp_std::vec::Vec;
 
@@ -110,6 +111,28 @@ impl MultiSet {
         Ok(s)
     }
 
+    /// Counts how many times every element of the multiset is queried by
+    /// `f`, as used by the logUp lookup argument.
+    /// If an element appears more than once, all of its queries are counted
+    /// in its first position.
+    /// Returns an error if an element of `f` is not in the multiset.
+    pub fn multiplicities(&self, f: &MultiSet) -> Result<MultiSet, Error> {
+        let mut positions = HashMap::with_capacity(self.len());
+        self.0.iter().enumerate().rev().for_each(|(i, element)| {
+            positions.insert(element.to_bytes(), i);
+        });
+
+        let mut m = vec![BlsScalar::zero(); self.len()];
+        for element in f.0.iter() {
+            let index = positions
+                .get(&element.to_bytes())
+                .ok_or(Error::ElementNotIndexed)?;
+            m[*index] += BlsScalar::one();
+        }
+
+        Ok(MultiSet(m))
+    }
+
     /// Checks whether one mutltiset is a subset of another.
     /// This function will be used to check if the all elements
     /// in set f, from the paper, are contained inside t.
@@ -207,6 +230,34 @@ impl MultiSet {
                 .collect::<Vec<BlsScalar>>(),
         )
     }
+
+    /// Turn five multisets into a single multiset using
+    /// a random challenge, Alpha. Alpha is dervived by hashing
+    /// the transcript.
+    /// The function iterates over the given sets and mutiplies by alpha:
+    /// a + (b * alpha) + (c * alpha^2) + (d * alpha^3) + (e * alpha^4)
+    pub fn compress_five_arity(
+        multisets: [&MultiSet; 5],
+        alpha: BlsScalar,
+    ) -> MultiSet {
+        let alpha_sq = alpha.square();
+        let alpha_cu = alpha_sq * alpha;
+        let alpha_qu = alpha_sq.square();
+
+        MultiSet(
+            multisets[0]
+                .0
+                .iter()
+                .zip(multisets[1].0.iter())
+                .zip(multisets[2].0.iter())
+                .zip(multisets[3].0.iter())
+                .zip(multisets[4].0.iter())
+                .map(|((((a, b), c), d), e)| {
+                    a + b * alpha + c * alpha_sq + d * alpha_cu + e * alpha_qu
+                })
+                .collect::<Vec<BlsScalar>>(),
+        )
+    }
 }
 
 impl Add for MultiSet {
@@ -319,6 +370,39 @@ mod test {
         assert!(!t.contains_all(&n));
     }
 
+    #[test]
+    fn test_multiplicities() {
+        let t = MultiSet(vec![
+            BlsScalar::from(1),
+            BlsScalar::from(2),
+            BlsScalar::from(3),
+            BlsScalar::from(2),
+        ]);
+        let f = MultiSet(vec![
+            BlsScalar::from(2),
+            BlsScalar::from(3),
+            BlsScalar::from(2),
+        ]);
+
+        // Repeated rows of the table only count in their first position
+        let m = t.multiplicities(&f).expect("All elements are in the table");
+        assert_eq!(
+            m,
+            MultiSet(vec![
+                BlsScalar::zero(),
+                BlsScalar::from(2),
+                BlsScalar::one(),
+                BlsScalar::zero(),
+            ])
+        );
+
+        let n = MultiSet(vec![BlsScalar::from(8)]);
+        assert!(matches!(
+            t.multiplicities(&n),
+            Err(Error::ElementNotIndexed)
+        ));
+    }
+
     #[test]
     fn test_full_compression_into_s() {
         let mut t = MultiSet::new();
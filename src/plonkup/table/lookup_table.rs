@@ -11,9 +11,15 @@ use super::hash_tables::constants::{BLS_SCALAR_REAL, DECOMPOSITION_S_I, SBOX};
 use crate::error::Error;
 use crate::plonkup::MultiSet;
 use crate::prelude::BlsScalar;
+use core::iter::FromIterator;
+use dusk_bytes::{DeserializableSlice, Serializable};
+use hashbrown::HashMap;
 use sp_std::vec;
 use sp_std::vec::Vec;
 
+/// Canonical bytes of the `(a, b, d)` values a row is queried by.
+type QueryKey = [[u8; BlsScalar::SIZE]; 3];
+
 /// This struct is a table, contaning a vector,
 /// of arity 4 where each of the values is a
 /// BlsScalar. The elements of the table are
@@ -23,8 +29,16 @@ use sp_std::vec::Vec;
 /// This struct will be used to determine
 /// the outputs of gates within arithmetic
 /// circuits.
-#[derive(Clone, Eq, PartialEq, Debug)]
-pub struct LookupTable(pub Vec<[BlsScalar; 4]>);
+///
+/// The rows are indexed by the `(a, b, d)` values they are queried by, so
+/// [`LookupTable::lookup`] and [`LookupTable::contains`] run in constant
+/// time.
+#[derive(Clone, Debug)]
+pub struct LookupTable {
+    rows: Vec<[BlsScalar; 4]>,
+    /// Positions of the rows of every query, in insertion order.
+    queries: HashMap<QueryKey, Vec<usize>>,
+}
 
 impl Default for LookupTable {
     fn default() -> Self {
@@ -32,18 +46,135 @@ impl Default for LookupTable {
     }
 }
 
+impl PartialEq for LookupTable {
+    fn eq(&self, other: &Self) -> bool {
+        self.rows == other.rows
+    }
+}
+
+impl Eq for LookupTable {}
+
+impl FromIterator<[BlsScalar; 4]> for LookupTable {
+    fn from_iter<I: IntoIterator<Item = [BlsScalar; 4]>>(rows: I) -> Self {
+        let mut table = LookupTable::new();
+        table.extend(rows);
+
+        table
+    }
+}
+
+impl Extend<[BlsScalar; 4]> for LookupTable {
+    /// Inserts the rows that are not in the table yet.
+    fn extend<I: IntoIterator<Item = [BlsScalar; 4]>>(&mut self, rows: I) {
+        rows.into_iter().for_each(|row| {
+            self.insert_row(row);
+        });
+    }
+}
+
+/// Handle to a [`LookupTable`] registered in a
+/// [`TurboComposer`](crate::constraint_system::TurboComposer).
+///
+/// The rows of every table of a circuit are tagged with the id of the table
+/// they belong to, so a plonkup gate only matches rows of the table it
+/// queries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct LookupTableId(pub(crate) u32);
+
+impl From<LookupTableId> for BlsScalar {
+    fn from(id: LookupTableId) -> BlsScalar {
+        BlsScalar::from(id.0 as u64)
+    }
+}
+
 impl LookupTable {
     /// Create a new, empty Plonkup table, with arity 4.
     pub fn new() -> Self {
-        LookupTable(vec![])
+        LookupTable {
+            rows: vec![],
+            queries: HashMap::new(),
+        }
+    }
+
+    /// Creates a table out of `rows`, skipping the duplicated ones.
+    pub fn from_rows<I>(rows: I) -> Self
+    where
+        I: IntoIterator<Item = [BlsScalar; 4]>,
+    {
+        rows.into_iter().collect()
+    }
+
+    /// Creates a table with a row `(a, b, f(a, b), d)` for all the values `a`
+    /// and `b` of `n` bits, skipping the duplicated ones.
+    ///
+    /// The fourth column `d` is used to tell the rows of different operations
+    /// apart when they share a table.
+    pub fn from_fn<F>(n: u8, d: BlsScalar, f: F) -> Self
+    where
+        F: Fn(u64, u64) -> BlsScalar,
+    {
+        let upper_bound = 2u64.pow(n.into());
+
+        (0..upper_bound)
+            .flat_map(|a| (0..upper_bound).map(move |b| (a, b)))
+            .map(|(a, b)| [BlsScalar::from(a), BlsScalar::from(b), f(a, b), d])
+            .collect()
+    }
+
+    /// Returns the rows of the table.
+    pub fn rows(&self) -> &[[BlsScalar; 4]] {
+        &self.rows
+    }
+
+    /// Returns the number of rows of the table.
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns `true` if the table has no rows.
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Returns `true` if `row` is in the table.
+    pub fn contains(&self, row: &[BlsScalar; 4]) -> bool {
+        self.queries
+            .get(&Self::query_key(row[0], row[1], row[3]))
+            .map(|positions| positions.iter().any(|i| self.rows[*i] == *row))
+            .unwrap_or(false)
+    }
+
+    /// Inserts `row` if it's not in the table yet, returning whether it was
+    /// inserted.
+    pub fn insert_row(&mut self, row: [BlsScalar; 4]) -> bool {
+        let inserted = !self.contains(&row);
+        if inserted {
+            self.push(row);
+        }
+
+        inserted
+    }
+
+    /// Appends `row` to the table, indexing it.
+    fn push(&mut self, row: [BlsScalar; 4]) {
+        self.queries
+            .entry(Self::query_key(row[0], row[1], row[3]))
+            .or_insert_with(Vec::new)
+            .push(self.rows.len());
+        self.rows.push(row);
+    }
+
+    fn query_key(a: BlsScalar, b: BlsScalar, d: BlsScalar) -> QueryKey {
+        [a.to_bytes(), b.to_bytes(), d.to_bytes()]
     }
 
     /// Insert a new row for an addition operation.
     /// This function needs to know the upper bound of the amount of addition
     /// operations that will be done in the plonkup table.
+    /// The row is skipped if it's already in the table.
     pub fn insert_add_row(&mut self, a: u64, b: u64, upper_bound: u64) {
         let c = (a + b) % upper_bound;
-        self.0.push([
+        self.insert_row([
             BlsScalar::from(a),
             BlsScalar::from(b),
             BlsScalar::from(c),
@@ -54,6 +185,7 @@ impl LookupTable {
     /// Insert a new row for an addition operation.
     /// This function needs to know the upper bound of the amount of addition
     /// operations that will be done in the plonkup table.
+    /// The row is skipped if it's already in the table.
     pub fn insert_special_row(
         &mut self,
         a: BlsScalar,
@@ -61,15 +193,16 @@ impl LookupTable {
         c: BlsScalar,
         d: BlsScalar,
     ) {
-        self.0.push([a, b, c, d]);
+        self.insert_row([a, b, c, d]);
     }
 
     /// Insert a new row for an multiplication operation.
     /// This function needs to know the upper bound of the amount of
     /// multiplication operations that will be done in the plonkup table.
+    /// The row is skipped if it's already in the table.
     pub fn insert_mul_row(&mut self, a: u64, b: u64, upper_bound: u64) {
         let c = (a * b) % upper_bound;
-        self.0.push([
+        self.insert_row([
             BlsScalar::from(a),
             BlsScalar::from(b),
             BlsScalar::from(c),
@@ -80,9 +213,10 @@ impl LookupTable {
     /// Insert a new row for an XOR operation.
     /// This function needs to know the upper bound of the amount of XOR
     /// operations that will be done in the plonkup table.
+    /// The row is skipped if it's already in the table.
     pub fn insert_xor_row(&mut self, a: u64, b: u64, upper_bound: u64) {
         let c = (a ^ b) % upper_bound;
-        self.0.push([
+        self.insert_row([
             BlsScalar::from(a),
             BlsScalar::from(b),
             BlsScalar::from(c),
@@ -93,9 +227,10 @@ impl LookupTable {
     /// Insert a new row for an AND operation.
     /// This function needs to know the upper bound of the amount of XOR
     /// operations that will be done in the plonkup table.
+    /// The row is skipped if it's already in the table.
     pub fn insert_and_row(&mut self, a: u64, b: u64, upper_bound: u64) {
         let c = (a & b) % upper_bound;
-        self.0.push([
+        self.insert_row([
             BlsScalar::from(a),
             BlsScalar::from(b),
             BlsScalar::from(c),
@@ -183,6 +318,22 @@ impl LookupTable {
         }
     }
 
+    /// Function builds a range table, with a row `(x, 0, 0, 0)` for every
+    /// value `x` of `n` bits.
+    /// Looking a value up in this table proves it lies in \[0, 2^n\).
+    pub fn insert_range(&mut self, n: u8) {
+        let upper_bound = 2u64.pow(n.into());
+
+        (0..upper_bound).for_each(|x| {
+            self.insert_special_row(
+                BlsScalar::from(x),
+                BlsScalar::zero(),
+                BlsScalar::zero(),
+                BlsScalar::zero(),
+            )
+        });
+    }
+
     /// Takes in a table, which is a list of vectors containing
     /// 4 elements, and turns them into 4 distinct multisets for
     /// a, b, c and d.
@@ -192,7 +343,7 @@ impl LookupTable {
         let mut multiset_c = MultiSet::new();
         let mut multiset_d = MultiSet::new();
 
-        self.0.iter().for_each(|row| {
+        self.rows.iter().for_each(|row| {
             multiset_a.push(row[0]);
             multiset_b.push(row[1]);
             multiset_c.push(row[2]);
@@ -214,12 +365,52 @@ impl LookupTable {
         d: BlsScalar,
     ) -> Result<BlsScalar, Error> {
         let pos = self
-            .0
-            .iter()
-            .position(|row| row[0] == a && row[1] == b && row[3] == d)
+            .queries
+            .get(&Self::query_key(a, b, d))
+            .and_then(|positions| positions.first())
             .ok_or(Error::ElementNotIndexed)?;
 
-        Ok(self.0[pos][2])
+        Ok(self.rows[*pos][2])
+    }
+
+    /// Serializes the table into a vector of bytes, holding the number of
+    /// rows as a `u64` followed by the rows.
+    ///
+    /// Big tables can be built once and shipped along with the keys of their
+    /// circuits.
+    pub fn to_var_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(
+            u64::SIZE + self.rows.len() * 4 * BlsScalar::SIZE,
+        );
+
+        bytes.extend_from_slice(&(self.rows.len() as u64).to_bytes());
+        self.rows.iter().flatten().for_each(|scalar| {
+            bytes.extend_from_slice(&scalar.to_bytes());
+        });
+
+        bytes
+    }
+
+    /// Deserializes a table serialized with [`LookupTable::to_var_bytes`].
+    pub fn from_slice(bytes: &[u8]) -> Result<LookupTable, Error> {
+        let mut buffer = bytes;
+        let len = u64::from_reader(&mut buffer)? as usize;
+
+        if buffer.len() / (4 * BlsScalar::SIZE) < len {
+            return Err(Error::NotEnoughBytes);
+        }
+
+        let mut table = LookupTable::new();
+        for _ in 0..len {
+            let a = BlsScalar::from_reader(&mut buffer)?;
+            let b = BlsScalar::from_reader(&mut buffer)?;
+            let c = BlsScalar::from_reader(&mut buffer)?;
+            let d = BlsScalar::from_reader(&mut buffer)?;
+
+            table.push([a, b, c, d]);
+        }
+
+        Ok(table)
     }
 
     /// Function that creates the table needed for reinforced concrete.
@@ -371,7 +562,10 @@ impl LookupTable {
             }
         }
 
-        LookupTable(table)
+        let mut lookup_table = LookupTable::new();
+        table.into_iter().for_each(|row| lookup_table.push(row));
+
+        lookup_table
     }
 }
 
@@ -397,15 +591,12 @@ mod test {
         (0..p).for_each(|a| {
             (0..p).for_each(|b| {
                 let c = (a + b) % p;
-                assert_eq!(BlsScalar::from(c), table.0[i][2]);
+                assert_eq!(BlsScalar::from(c), table.rows()[i][2]);
                 i += 1;
             })
         });
 
-        assert_eq!(
-            table.0.len() as u64,
-            2u64.pow(n as u32) * 2u64.pow(n as u32)
-        );
+        assert_eq!(table.len() as u64, 2u64.pow(n as u32) * 2u64.pow(n as u32));
     }
 
     #[test]
@@ -424,15 +615,12 @@ mod test {
         (0..p).for_each(|a| {
             (0..p).for_each(|b| {
                 let c = (a ^ b) % p;
-                assert_eq!(BlsScalar::from(c), table.0[i][2]);
+                assert_eq!(BlsScalar::from(c), table.rows()[i][2]);
                 i += 1;
             })
         });
 
-        assert_eq!(
-            table.0.len() as u64,
-            2u64.pow(n as u32) * 2u64.pow(n as u32)
-        );
+        assert_eq!(table.len() as u64, 2u64.pow(n as u32) * 2u64.pow(n as u32));
     }
 
     #[test]
@@ -451,15 +639,12 @@ mod test {
         (0..p).for_each(|a| {
             (0..p).for_each(|b| {
                 let c = (a * b) % p;
-                assert_eq!(BlsScalar::from(c), table.0[i][2]);
+                assert_eq!(BlsScalar::from(c), table.rows()[i][2]);
                 i += 1;
             })
         });
 
-        assert_eq!(
-            table.0.len() as u64,
-            2u64.pow(n as u32) * 2u64.pow(n as u32)
-        );
+        assert_eq!(table.len() as u64, 2u64.pow(n as u32) * 2u64.pow(n as u32));
     }
 
     #[test]
@@ -474,12 +659,15 @@ mod test {
             .lookup(BlsScalar::from(2), BlsScalar::from(3), BlsScalar::zero())
             .is_ok());
 
-        let output = add_table.0[1][0] + add_table.0[1][1] + add_table.0[1][2]; // TODO are we sure this is right
+        let output = add_table.rows()[1][0]
+            + add_table.rows()[1][1]
+            + add_table.rows()[1][2]; // TODO are we sure this is right
 
         assert_eq!(output, BlsScalar::from(2));
 
-        let second_output =
-            add_table.0[12][0] + add_table.0[12][1] + add_table.0[12][2]; // TODO are we sure this is right
+        let second_output = add_table.rows()[12][0]
+            + add_table.rows()[12][1]
+            + add_table.rows()[12][2]; // TODO are we sure this is right
 
         assert_eq!(second_output, BlsScalar::from(10));
     }
@@ -508,8 +696,82 @@ mod test {
         table.insert_multi_xor(0, 5);
         table.insert_multi_add(4, 7);
 
-        assert_eq!(table.0.last().unwrap()[2], BlsScalar::from(126u64));
-        let xor = table.0[36][0] ^ table.0[36][1] ^ table.0[36][2];
+        assert_eq!(table.rows().last().unwrap()[2], BlsScalar::from(126u64));
+        let xor =
+            table.rows()[36][0] ^ table.rows()[36][1] ^ table.rows()[36][2];
         assert_eq!(xor, BlsScalar::zero());
     }
+
+    #[test]
+    fn test_from_rows_deduplicates() {
+        let one = BlsScalar::one();
+        let two = BlsScalar::from(2u64);
+
+        let table = LookupTable::from_rows(vec![
+            [one; 4], [two; 4], [one; 4], [two; 4],
+        ]);
+
+        assert_eq!(table.len(), 2);
+        assert!(table.contains(&[one; 4]));
+        assert!(!table.contains(&[one, one, two, one]));
+    }
+
+    #[test]
+    fn test_insert_deduplicates() {
+        let mut table = LookupTable::new();
+        table.insert_multi_xor(0, 3);
+        table.insert_multi_xor(0, 3);
+        table.insert_range(3);
+        table.insert_range(2);
+
+        assert_eq!(table.len(), 64 + 8);
+
+        // Rows queried by the same values are kept if their output differs
+        let one = BlsScalar::one();
+        table.insert_special_row(one, one, BlsScalar::zero(), one);
+        table.insert_special_row(one, one, one, one);
+
+        assert_eq!(table.len(), 64 + 8 + 2);
+        assert!(table.contains(&[one, one, BlsScalar::zero(), one]));
+        assert!(table.contains(&[one; 4]));
+
+        // The first row of a query is the one it's looked up by
+        let c = table
+            .lookup(one, one, one)
+            .expect("The row is in the table");
+        assert_eq!(c, BlsScalar::zero());
+    }
+
+    #[test]
+    fn test_from_fn() {
+        let table = LookupTable::from_fn(3, BlsScalar::one(), |a, b| {
+            BlsScalar::from((a * b) % 8)
+        });
+
+        let mut mul_table = LookupTable::new();
+        mul_table.insert_multi_mul(0, 3);
+
+        assert_eq!(table, mul_table);
+        let c = table
+            .lookup(BlsScalar::from(3), BlsScalar::from(5), BlsScalar::one())
+            .expect("The row is in the table");
+        assert_eq!(c, BlsScalar::from(7u64));
+    }
+
+    #[test]
+    fn test_table_serialization() {
+        let mut table = LookupTable::new();
+        table.insert_multi_xor(0, 3);
+        table.insert_multi_and(0, 3);
+
+        let bytes = table.to_var_bytes();
+        let decoded = LookupTable::from_slice(&bytes).expect("Valid table");
+        assert_eq!(decoded, table);
+        let c = decoded
+            .lookup(BlsScalar::from(6), BlsScalar::from(3), -BlsScalar::one())
+            .expect("The row is in the table");
+        assert_eq!(c, BlsScalar::from(5u64));
+
+        assert!(LookupTable::from_slice(&bytes[..bytes.len() - 1]).is_err());
+    }
 }
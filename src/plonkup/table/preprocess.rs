@@ -4,15 +4,22 @@
 //
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
-use crate::commitment_scheme::{CommitKey, Commitment};
+use crate::commitment_scheme::{
+    CommitKey, Commitment, CommitmentScheme, Kzg10,
+};
 use crate::error::Error;
 use crate::fft::{EvaluationDomain, Polynomial};
 use crate::plonkup::{LookupTable, MultiSet};
+use dusk_bls12_381::BlsScalar;
 
 /// This table will be the preprocessed version of the
 /// precomputed table, T, with arity 4. This structure
 /// is passed to the proof alongside the table of witness
 /// values.
+///
+/// A circuit can use several tables, which are preprocessed together into a
+/// single combined table with an additional column holding the id of the
+/// table each row belongs to.
 #[derive(Clone, Eq, PartialEq, Debug)]
 pub struct PreprocessedLookupTable {
     /// This is the circuit size
@@ -37,6 +44,12 @@ pub struct PreprocessedLookupTable {
     /// table containing a MultiSet, Commitments to the
     /// MultiSet and the coefficients as a Polynomial
     pub(crate) t_4: (MultiSet, Commitment, Polynomial),
+
+    /// This is the column in the preprocessed table holding
+    /// the id of the table each row belongs to, containing a
+    /// MultiSet, Commitments to the MultiSet and the
+    /// coefficients as a Polynomial
+    pub(crate) t_id: (MultiSet, Commitment, Polynomial),
 }
 
 impl PreprocessedLookupTable {
@@ -50,30 +63,71 @@ impl PreprocessedLookupTable {
         table: &LookupTable,
         commit_key: &CommitKey,
         n: u32,
+    ) -> Result<Self, Error> {
+        Self::preprocess_with_scheme::<Kzg10>(table, commit_key, n)
+    }
+
+    /// Preprocesses the table as [`PreprocessedLookupTable::preprocess`],
+    /// committing to it with the [`CommitmentScheme`] `S`.
+    pub fn preprocess_with_scheme<S: CommitmentScheme>(
+        table: &LookupTable,
+        commit_key: &S::CommitKey,
+        n: u32,
+    ) -> Result<Self, Error> {
+        Self::preprocess_tables::<S>(
+            core::slice::from_ref(table),
+            commit_key,
+            n,
+        )
+    }
+
+    /// Preprocesses several tables into a single combined table, committing
+    /// to it with the [`CommitmentScheme`] `S`.
+    ///
+    /// The rows of each table are tagged with its index in `tables`, which
+    /// is the id plonkup gates use to query it.
+    pub fn preprocess_tables<S: CommitmentScheme>(
+        tables: &[LookupTable],
+        commit_key: &S::CommitKey,
+        n: u32,
     ) -> Result<Self, Error> {
         let domain: EvaluationDomain =
             EvaluationDomain::new(n as usize).unwrap();
 
-        let columned_table = table.vec_to_multiset();
-        let mut t_1 = columned_table.0;
-        let mut t_2 = columned_table.1;
-        let mut t_3 = columned_table.2;
-        let mut t_4 = columned_table.3;
+        let mut t_1 = MultiSet::new();
+        let mut t_2 = MultiSet::new();
+        let mut t_3 = MultiSet::new();
+        let mut t_4 = MultiSet::new();
+        let mut t_id = MultiSet::new();
+
+        tables.iter().enumerate().for_each(|(id, table)| {
+            let id = BlsScalar::from(id as u64);
+            table.rows().iter().for_each(|row| {
+                t_1.push(row[0]);
+                t_2.push(row[1]);
+                t_3.push(row[2]);
+                t_4.push(row[3]);
+                t_id.push(id);
+            });
+        });
 
         t_1.pad(n);
         t_2.pad(n);
         t_3.pad(n);
         t_4.pad(n);
+        t_id.pad(n);
 
         let t_1_poly = t_1.to_polynomial(&domain);
         let t_2_poly = t_2.to_polynomial(&domain);
         let t_3_poly = t_3.to_polynomial(&domain);
         let t_4_poly = t_4.to_polynomial(&domain);
+        let t_id_poly = t_id.to_polynomial(&domain);
 
-        let t_1_commit = commit_key.commit(&t_1_poly)?;
-        let t_2_commit = commit_key.commit(&t_2_poly)?;
-        let t_3_commit = commit_key.commit(&t_3_poly)?;
-        let t_4_commit = commit_key.commit(&t_4_poly)?;
+        let t_1_commit = S::commit(commit_key, &t_1_poly)?;
+        let t_2_commit = S::commit(commit_key, &t_2_poly)?;
+        let t_3_commit = S::commit(commit_key, &t_3_poly)?;
+        let t_4_commit = S::commit(commit_key, &t_4_poly)?;
+        let t_id_commit = S::commit(commit_key, &t_id_poly)?;
 
         Ok(PreprocessedLookupTable {
             n,
@@ -81,6 +135,7 @@ impl PreprocessedLookupTable {
             t_2: (t_2, t_2_commit, t_2_poly),
             t_3: (t_3, t_3_commit, t_3_poly),
             t_4: (t_4, t_4_commit, t_4_poly),
+            t_id: (t_id, t_id_commit, t_id_poly),
         })
     }
 }
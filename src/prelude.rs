@@ -10,12 +10,19 @@
 //! with the principal data structures of the plonk library.
 
 pub use crate::{
-    circuit::{self, Circuit, PublicInputValue, VerifierData},
-    commitment_scheme::{CommitKey, OpeningKey, PublicParameters},
-    constraint_system::{Constraint, TurboComposer, Witness, WitnessPoint},
+    circuit::{self, Circuit, PublicInputValue, PublicInputs, VerifierData},
+    commitment_scheme::{
+        CommitKey, CommitmentScheme, OpeningKey, PublicParameters,
+    },
+    constraint_system::{
+        Constraint, LinearCombination, Memory, TurboComposer, Witness,
+        WitnessPoint,
+    },
     proof_system::{Prover, ProverKey, Verifier},
 };
 
+#[cfg(feature = "std")]
+pub use crate::circuit::ProverPool;
 pub use crate::error::Error;
 pub use crate::proof_system::{Proof, VerifierKey};
 pub use dusk_bls12_381::BlsScalar;
@@ -6,12 +6,14 @@
 
 use crate::{
     fft::{EvaluationDomain, Polynomial},
+    plonkup::LookupArgument,
     proof_system::ProverKey,
 };
 
 use dusk_bls12_381::BlsScalar;
 use dusk_bytes::{DeserializableSlice, Serializable};
 use parity_scale_codec::{Decode, Encode};
+use sp_std::vec::Vec;
 
 #[allow(dead_code)]
 /// Evaluations at points `z` or and `z * root of unity`
@@ -184,6 +186,134 @@ impl Serializable<{ 24 * BlsScalar::SIZE }> for ProofEvaluations {
     }
 }
 
+impl ProofEvaluations {
+    /// Size of the evaluations of a proof without lookups, which don't carry
+    /// any of the evaluations of the lookup argument.
+    pub(crate) const SIZE_WITHOUT_LOOKUPS: usize = 16 * BlsScalar::SIZE;
+
+    /// Size of the evaluations of a proof of the logUp argument, which don't
+    /// carry the evaluations only opened by the plookup argument.
+    pub(crate) const SIZE_LOGUP: usize = 19 * BlsScalar::SIZE;
+
+    /// Returns `true` if all of the evaluations of the lookup argument are
+    /// zero, as is the case for proofs of circuits without lookups.
+    pub(crate) fn lookup_is_empty(&self) -> bool {
+        [
+            &self.q_lookup_eval,
+            &self.lookup_perm_eval,
+            &self.h_1_eval,
+            &self.h_1_next_eval,
+            &self.h_2_eval,
+            &self.f_eval,
+            &self.table_eval,
+            &self.table_next_eval,
+        ]
+        .iter()
+        .all(|eval| **eval == BlsScalar::zero())
+    }
+
+    /// Serializes the evaluations leaving out the ones of the lookup
+    /// argument.
+    pub(crate) fn to_bytes_without_lookups(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(Self::SIZE_WITHOUT_LOOKUPS);
+        [
+            &self.a_eval,
+            &self.b_eval,
+            &self.c_eval,
+            &self.d_eval,
+            &self.a_next_eval,
+            &self.b_next_eval,
+            &self.d_next_eval,
+            &self.q_arith_eval,
+            &self.q_c_eval,
+            &self.q_l_eval,
+            &self.q_r_eval,
+            &self.left_sigma_eval,
+            &self.right_sigma_eval,
+            &self.out_sigma_eval,
+            &self.lin_poly_eval,
+            &self.perm_eval,
+        ]
+        .iter()
+        .for_each(|eval| bytes.extend_from_slice(&eval.to_bytes()));
+
+        bytes
+    }
+
+    /// Deserializes evaluations serialized with
+    /// [`ProofEvaluations::to_bytes_without_lookups`], setting the ones of
+    /// the lookup argument to zero.
+    pub(crate) fn from_reader_without_lookups(
+        buffer: &mut &[u8],
+    ) -> Result<ProofEvaluations, dusk_bytes::Error> {
+        let a_eval = BlsScalar::from_reader(buffer)?;
+        let b_eval = BlsScalar::from_reader(buffer)?;
+        let c_eval = BlsScalar::from_reader(buffer)?;
+        let d_eval = BlsScalar::from_reader(buffer)?;
+        let a_next_eval = BlsScalar::from_reader(buffer)?;
+        let b_next_eval = BlsScalar::from_reader(buffer)?;
+        let d_next_eval = BlsScalar::from_reader(buffer)?;
+        let q_arith_eval = BlsScalar::from_reader(buffer)?;
+        let q_c_eval = BlsScalar::from_reader(buffer)?;
+        let q_l_eval = BlsScalar::from_reader(buffer)?;
+        let q_r_eval = BlsScalar::from_reader(buffer)?;
+        let left_sigma_eval = BlsScalar::from_reader(buffer)?;
+        let right_sigma_eval = BlsScalar::from_reader(buffer)?;
+        let out_sigma_eval = BlsScalar::from_reader(buffer)?;
+        let lin_poly_eval = BlsScalar::from_reader(buffer)?;
+        let perm_eval = BlsScalar::from_reader(buffer)?;
+
+        Ok(ProofEvaluations {
+            a_eval,
+            b_eval,
+            c_eval,
+            d_eval,
+            a_next_eval,
+            b_next_eval,
+            d_next_eval,
+            q_arith_eval,
+            q_c_eval,
+            q_l_eval,
+            q_r_eval,
+            left_sigma_eval,
+            right_sigma_eval,
+            out_sigma_eval,
+            lin_poly_eval,
+            perm_eval,
+            ..Default::default()
+        })
+    }
+
+    /// Serializes the evaluations opened by the logUp argument, leaving out
+    /// the ones of the plookup argument.
+    pub(crate) fn to_bytes_logup(&self) -> Vec<u8> {
+        let mut bytes = self.to_bytes_without_lookups();
+        [
+            &self.q_lookup_eval,
+            &self.lookup_perm_eval,
+            &self.table_eval,
+        ]
+        .iter()
+        .for_each(|eval| bytes.extend_from_slice(&eval.to_bytes()));
+
+        bytes
+    }
+
+    /// Deserializes evaluations serialized with
+    /// [`ProofEvaluations::to_bytes_logup`], setting the ones of the plookup
+    /// argument to zero.
+    pub(crate) fn from_reader_logup(
+        buffer: &mut &[u8],
+    ) -> Result<ProofEvaluations, dusk_bytes::Error> {
+        let mut evaluations = Self::from_reader_without_lookups(buffer)?;
+        evaluations.q_lookup_eval = BlsScalar::from_reader(buffer)?;
+        evaluations.lookup_perm_eval = BlsScalar::from_reader(buffer)?;
+        evaluations.table_eval = BlsScalar::from_reader(buffer)?;
+
+        Ok(evaluations)
+    }
+}
+
 /// Compute the linearisation polynomial.
 // TODO: Improve the method signature
 #[allow(clippy::type_complexity)]
@@ -228,6 +358,8 @@ pub(crate) fn compute(
     h_2_poly: &Polynomial,
     table_poly: &Polynomial,
     p_poly: &Polynomial,
+    m_poly: &Polynomial,
+    lookup_argument: LookupArgument,
 ) -> (Polynomial, Evaluations) {
     // Compute evaluations
     let quot_eval = t_x_poly.evaluate(z_challenge);
@@ -257,8 +389,13 @@ pub(crate) fn compute(
     let perm_eval = z_poly.evaluate(&(z_challenge * domain.group_gen));
     let lookup_perm_eval = p_poly.evaluate(&(z_challenge * domain.group_gen));
     let h_1_next_eval = h_1_poly.evaluate(&(z_challenge * domain.group_gen));
-    let table_next_eval =
-        table_poly.evaluate(&(z_challenge * domain.group_gen));
+    // The logUp argument only opens the table at `z`
+    let table_next_eval = match lookup_argument {
+        LookupArgument::Plookup => {
+            table_poly.evaluate(&(z_challenge * domain.group_gen))
+        }
+        LookupArgument::LogUp => BlsScalar::zero(),
+    };
 
     let l_coeffs = domain.evaluate_all_lagrange_coefficients(*z_challenge);
     let l1_eval = l_coeffs[0];
@@ -288,6 +425,8 @@ pub(crate) fn compute(
         &l1_eval,
         p_poly,
         h_2_poly,
+        m_poly,
+        lookup_argument,
         (delta, epsilon),
         zeta,
         &q_c_eval,
@@ -369,6 +508,8 @@ fn compute_circuit_satisfiability(
     l1_eval: &BlsScalar,
     p_poly: &Polynomial,
     h_2_poly: &Polynomial,
+    m_poly: &Polynomial,
+    lookup_argument: LookupArgument,
     (delta, epsilon): (&BlsScalar, &BlsScalar),
     zeta: &BlsScalar,
     q_c_eval: &BlsScalar,
@@ -429,24 +570,45 @@ fn compute_circuit_satisfiability(
         d_eval,
         d_next_eval,
     );
-    let f = prover_key.lookup.compute_linearisation(
-        a_eval,
-        b_eval,
-        c_eval,
-        d_eval,
-        f_eval,
-        table_eval,
-        table_next_eval,
-        h_1_eval,
-        h_2_eval,
-        p_next_eval,
-        l1_eval,
-        p_poly,
-        h_2_poly,
-        (delta, epsilon),
-        zeta,
-        lookup_separation_challenge,
-    );
+    let f = match (prover_key.lookups_enabled(), lookup_argument) {
+        (true, LookupArgument::Plookup) => {
+            prover_key.lookup.compute_linearisation(
+                a_eval,
+                b_eval,
+                c_eval,
+                d_eval,
+                q_c_eval,
+                f_eval,
+                table_eval,
+                table_next_eval,
+                h_1_eval,
+                h_2_eval,
+                p_next_eval,
+                l1_eval,
+                p_poly,
+                h_2_poly,
+                (delta, epsilon),
+                zeta,
+                lookup_separation_challenge,
+            )
+        }
+        (true, LookupArgument::LogUp) => {
+            prover_key.lookup.compute_logup_linearisation(
+                a_eval,
+                b_eval,
+                c_eval,
+                d_eval,
+                q_c_eval,
+                table_eval,
+                p_poly,
+                m_poly,
+                epsilon,
+                zeta,
+                lookup_separation_challenge,
+            )
+        }
+        (false, _) => Polynomial::zero(),
+    };
 
     let mut linearisation_poly = &a + &b;
     linearisation_poly += &c;
@@ -469,4 +631,22 @@ mod evaluations_tests {
             .expect("Deserialization error");
         assert_eq!(proof_evals.to_bytes(), obtained_evals.to_bytes())
     }
+
+    #[test]
+    fn proof_evaluations_without_lookups_serde() {
+        let proof_evals = ProofEvaluations {
+            a_eval: BlsScalar::from(1),
+            perm_eval: BlsScalar::from(2),
+            ..Default::default()
+        };
+        assert!(proof_evals.lookup_is_empty());
+
+        let bytes = proof_evals.to_bytes_without_lookups();
+        assert_eq!(bytes.len(), ProofEvaluations::SIZE_WITHOUT_LOOKUPS);
+
+        let obtained_evals =
+            ProofEvaluations::from_reader_without_lookups(&mut &bytes[..])
+                .expect("Deserialization error");
+        assert_eq!(proof_evals, obtained_evals)
+    }
 }
@@ -6,13 +6,14 @@
 
 //! Methods to preprocess the constraint system for use in a proof
 
-use crate::commitment_scheme::CommitKey;
+use crate::commitment_scheme::CommitmentScheme;
 use crate::constraint_system::TurboComposer;
-use crate::plonkup::PreprocessedLookupTable;
+use crate::plonkup::{LookupArgument, PreprocessedLookupTable};
 
 use crate::error::Error;
 use crate::fft::{EvaluationDomain, Evaluations, Polynomial};
 use crate::proof_system::{widget, ProverKey};
+use core::marker::PhantomData;
 use dusk_bls12_381::BlsScalar;
 use merlin::Transcript;
 use sp_std::vec;
@@ -103,14 +104,27 @@ impl TurboComposer {
     /// These are the parts of preprocessing that the prover must compute
     /// Although the prover does not need the verification key, he must compute
     /// the commitments in order to seed the transcript, allowing both the
-    /// prover and verifier to have the same view
-    pub(crate) fn preprocess_prover(
+    /// prover and verifier to have the same view.
+    ///
+    /// The verification key is returned together with the [`ProverKey`], so
+    /// a circuit only needs to be preprocessed once to obtain both of them.
+    /// Both keys record the `lookup_argument` the lookups of the circuit are
+    /// proven with.
+    pub(crate) fn preprocess_prover<S: CommitmentScheme>(
         &mut self,
-        commit_key: &CommitKey,
+        commit_key: &S::CommitKey,
         transcript: &mut Transcript,
-    ) -> Result<ProverKey, Error> {
-        let (_, selectors, preprocessed_table, domain) =
-            self.preprocess_shared(commit_key, transcript)?;
+        lookup_argument: LookupArgument,
+    ) -> Result<(ProverKey, widget::VerifierKey<S>), Error> {
+        // The fingerprint is taken before the circuit is padded, so it
+        // matches the one of a circuit that is built again to be proven
+        let fingerprint = self.fingerprint();
+        let wires_digest = self.wires_digest();
+        let gates = self.n;
+        let public_input_indexes = self.public_input_indexes();
+
+        let (verifier_key, selectors, preprocessed_table, domain) = self
+            .preprocess_shared::<S>(commit_key, transcript, lookup_argument)?;
 
         let domain_4n = EvaluationDomain::new(4 * domain.size())?;
         let q_m_eval_4n = Evaluations::from_vec_and_domain(
@@ -195,6 +209,10 @@ impl TurboComposer {
             domain_4n.coset_fft(&preprocessed_table.t_4.2),
             domain_4n,
         );
+        let table_id_eval_4n = Evaluations::from_vec_and_domain(
+            domain_4n.coset_fft(&preprocessed_table.t_id.2),
+            domain_4n,
+        );
         // XXX: Remove this and compute it on the fly
         let linear_eval_4n = Evaluations::from_vec_and_domain(
             domain_4n.coset_fft(&[BlsScalar::zero(), BlsScalar::one()]),
@@ -275,10 +293,16 @@ impl TurboComposer {
                 preprocessed_table.t_4.2,
                 table_4_eval_4n,
             ),
+            table_id: (
+                preprocessed_table.t_id.0,
+                preprocessed_table.t_id.2,
+                table_id_eval_4n,
+            ),
         };
 
         let prover_key = ProverKey {
             n: domain.size(),
+            lookup_argument,
             arithmetic: arithmetic_prover_key,
             logic: logic_prover_key,
             range: range_prover_key,
@@ -289,21 +313,29 @@ impl TurboComposer {
             // Compute 4n evaluations for X^n -1
             v_h_coset_4n: domain_4n
                 .compute_vanishing_poly_over_coset(domain.size() as u64),
+            fingerprint,
+            wires_digest,
+            gates,
+            public_input_indexes,
         };
 
-        Ok(prover_key)
+        Ok((prover_key, verifier_key))
     }
 
     /// The verifier only requires the commitments in order to verify a
     /// [`Proof`](super::Proof) We can therefore speed up preprocessing for the
     /// verifier by skipping the FFTs needed to compute the 4n evaluations.
-    pub(crate) fn preprocess_verifier(
+    pub(crate) fn preprocess_verifier<S: CommitmentScheme>(
         &mut self,
-        commit_key: &CommitKey,
+        commit_key: &S::CommitKey,
         transcript: &mut Transcript,
-    ) -> Result<widget::VerifierKey, Error> {
-        let (verifier_key, _, _, _) =
-            self.preprocess_shared(commit_key, transcript)?;
+        lookup_argument: LookupArgument,
+    ) -> Result<widget::VerifierKey<S>, Error> {
+        let (verifier_key, _, _, _) = self.preprocess_shared::<S>(
+            commit_key,
+            transcript,
+            lookup_argument,
+        )?;
         Ok(verifier_key)
     }
 
@@ -311,25 +343,27 @@ impl TurboComposer {
     /// must perform IFFTs on the selector polynomials and permutation
     /// polynomials in order to commit to them and have the same transcript
     /// view.
-    fn preprocess_shared(
+    fn preprocess_shared<S: CommitmentScheme>(
         &mut self,
-        commit_key: &CommitKey,
+        commit_key: &S::CommitKey,
         transcript: &mut Transcript,
+        lookup_argument: LookupArgument,
     ) -> Result<
         (
-            widget::VerifierKey,
+            widget::VerifierKey<S>,
             SelectorPolynomials,
             PreprocessedLookupTable,
             EvaluationDomain,
         ),
         Error,
     > {
-        // FIXME total_size requires documentation
-        // https://github.com/dusk-network/plonk/issues/580
-        let total_size =
-            core::cmp::max(self.n, self.lookup_table.0.len() as u32);
+        if self.witness_only {
+            return Err(Error::WitnessOnlyComposer);
+        }
 
-        let domain = EvaluationDomain::new(total_size as usize)?;
+        // The domain must fit both the gates and the rows of the lookup
+        // tables
+        let domain = EvaluationDomain::new(self.padded_gates())?;
 
         // Check that the length of the wires is consistent.
         self.check_poly_same_len()?;
@@ -369,35 +403,30 @@ impl TurboComposer {
             self.perm
                 .compute_sigma_polynomials(self.n as usize, &domain);
 
-        let q_m_poly_commit = commit_key.commit(&q_m_poly).unwrap_or_default();
-        let q_l_poly_commit = commit_key.commit(&q_l_poly).unwrap_or_default();
-        let q_r_poly_commit = commit_key.commit(&q_r_poly).unwrap_or_default();
-        let q_o_poly_commit = commit_key.commit(&q_o_poly).unwrap_or_default();
-        let q_c_poly_commit = commit_key.commit(&q_c_poly).unwrap_or_default();
-        let q_4_poly_commit = commit_key.commit(&q_4_poly).unwrap_or_default();
-        let q_arith_poly_commit =
-            commit_key.commit(&q_arith_poly).unwrap_or_default();
-        let q_range_poly_commit =
-            commit_key.commit(&q_range_poly).unwrap_or_default();
-        let q_logic_poly_commit =
-            commit_key.commit(&q_logic_poly).unwrap_or_default();
-        let q_fixed_group_add_poly_commit = commit_key
-            .commit(&q_fixed_group_add_poly)
-            .unwrap_or_default();
-        let q_variable_group_add_poly_commit = commit_key
-            .commit(&q_variable_group_add_poly)
-            .unwrap_or_default();
-        let q_lookup_poly_commit =
-            commit_key.commit(&q_lookup_poly).unwrap_or_default();
-
-        let left_sigma_poly_commit = commit_key.commit(&left_sigma_poly)?;
-        let right_sigma_poly_commit = commit_key.commit(&right_sigma_poly)?;
-        let out_sigma_poly_commit = commit_key.commit(&out_sigma_poly)?;
-        let fourth_sigma_poly_commit = commit_key.commit(&fourth_sigma_poly)?;
-
-        // Preprocess the lookup table
-        let preprocessed_table = PreprocessedLookupTable::preprocess(
-            &self.lookup_table,
+        let q_m_poly_commit = S::commit(commit_key, &q_m_poly)?;
+        let q_l_poly_commit = S::commit(commit_key, &q_l_poly)?;
+        let q_r_poly_commit = S::commit(commit_key, &q_r_poly)?;
+        let q_o_poly_commit = S::commit(commit_key, &q_o_poly)?;
+        let q_c_poly_commit = S::commit(commit_key, &q_c_poly)?;
+        let q_4_poly_commit = S::commit(commit_key, &q_4_poly)?;
+        let q_arith_poly_commit = S::commit(commit_key, &q_arith_poly)?;
+        let q_range_poly_commit = S::commit(commit_key, &q_range_poly)?;
+        let q_logic_poly_commit = S::commit(commit_key, &q_logic_poly)?;
+        let q_fixed_group_add_poly_commit =
+            S::commit(commit_key, &q_fixed_group_add_poly)?;
+        let q_variable_group_add_poly_commit =
+            S::commit(commit_key, &q_variable_group_add_poly)?;
+        let q_lookup_poly_commit = S::commit(commit_key, &q_lookup_poly)?;
+
+        let left_sigma_poly_commit = S::commit(commit_key, &left_sigma_poly)?;
+        let right_sigma_poly_commit = S::commit(commit_key, &right_sigma_poly)?;
+        let out_sigma_poly_commit = S::commit(commit_key, &out_sigma_poly)?;
+        let fourth_sigma_poly_commit =
+            S::commit(commit_key, &fourth_sigma_poly)?;
+
+        // Preprocess the lookup tables into a single combined table
+        let preprocessed_table = PreprocessedLookupTable::preprocess_tables::<S>(
+            &self.lookup_tables,
             commit_key,
             domain.size() as u32,
         )?;
@@ -441,6 +470,7 @@ impl TurboComposer {
             table_2: preprocessed_table.t_2.1,
             table_3: preprocessed_table.t_3.1,
             table_4: preprocessed_table.t_4.1,
+            table_id: preprocessed_table.t_id.1,
         };
         // Verifier Key for permutation argument
         let permutation_verifier_key = widget::permutation::VerifierKey {
@@ -452,6 +482,7 @@ impl TurboComposer {
 
         let verifier_key = widget::VerifierKey {
             n: self.gates(),
+            lookup_argument,
             arithmetic: arithmetic_verifier_key,
             logic: logic_verifier_key,
             range: range_verifier_key,
@@ -459,6 +490,7 @@ impl TurboComposer {
             variable_base: curve_addition_verifier_key,
             permutation: permutation_verifier_key,
             lookup: lookup_verifier_key,
+            scheme: PhantomData,
         };
 
         let selectors = SelectorPolynomials {
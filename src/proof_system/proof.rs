@@ -11,7 +11,8 @@
 //! `Proof` structure and it's methods.
 
 use super::linearisation_poly::ProofEvaluations;
-use crate::commitment_scheme::Commitment;
+use crate::commitment_scheme::{Commitment, CommitmentScheme, Kzg10};
+use crate::plonkup::LookupArgument;
 use dusk_bytes::{DeserializableSlice, Serializable};
 use parity_scale_codec::{Decode, Encode};
 
@@ -25,8 +26,11 @@ use parity_scale_codec::{Decode, Encode};
 /// [`Verifier`](super::Verifier) have in common succintly and without any
 /// capabilities of adquiring any kind of knowledge about the witness used to
 /// construct the Proof.
+///
+/// The openings of the committed polynomials are proven with the
+/// [`CommitmentScheme`] `S`, KZG10 by default.
 #[derive(Debug, Eq, PartialEq, Clone, Default, Decode, Encode)]
-pub struct Proof {
+pub struct Proof<S: CommitmentScheme = Kzg10> {
     /// Commitment to the witness polynomial for the left wires.
     pub(crate) a_comm: Commitment,
     /// Commitment to the witness polynomial for the right wires.
@@ -48,9 +52,16 @@ pub struct Proof {
     /// Commitment to the permutation polynomial.
     pub(crate) z_comm: Commitment,
 
-    /// Commitment to the plonkup permutation polynomial.
+    /// Commitment to the plonkup permutation polynomial, or to the running
+    /// sum of the logUp argument.
     pub(crate) p_comm: Commitment,
 
+    /// Commitment to the multiplicities of the table rows of the logUp
+    /// argument.
+    pub(crate) m_comm: Commitment,
+    /// Argument used to prove the lookups of the circuit.
+    pub(crate) lookup_argument: LookupArgument,
+
     /// Commitment to the quotient polynomial.
     pub(crate) t_1_comm: Commitment,
     /// Commitment to the quotient polynomial.
@@ -60,15 +71,16 @@ pub struct Proof {
     /// Commitment to the quotient polynomial.
     pub(crate) t_4_comm: Commitment,
 
-    /// Commitment to the opening polynomial.
-    pub(crate) w_z_comm: Commitment,
-    /// Commitment to the shifted opening polynomial.
-    pub(crate) w_zw_comm: Commitment,
+    /// Proof of the opening of the polynomials at the evaluation challenge.
+    pub(crate) w_z: S::OpeningProof,
+    /// Proof of the opening of the polynomials at the shifted evaluation
+    /// challenge.
+    pub(crate) w_zw: S::OpeningProof,
     /// Subset of all of the evaluations added to the proof.
     pub(crate) evaluations: ProofEvaluations,
 }
 
-impl Serializable<{ 15 * Commitment::SIZE + ProofEvaluations::SIZE }>
+impl Serializable<{ 1 + 16 * Commitment::SIZE + ProofEvaluations::SIZE }>
     for Proof
 {
     type Error = dusk_bytes::Error;
@@ -79,6 +91,7 @@ impl Serializable<{ 15 * Commitment::SIZE + ProofEvaluations::SIZE }>
 
         let mut buf = [0u8; Self::SIZE];
         let mut writer = &mut buf[..];
+        writer.write(&[self.lookup_argument.to_byte()]);
         writer.write(&self.a_comm.to_bytes());
         writer.write(&self.b_comm.to_bytes());
         writer.write(&self.c_comm.to_bytes());
@@ -88,20 +101,23 @@ impl Serializable<{ 15 * Commitment::SIZE + ProofEvaluations::SIZE }>
         writer.write(&self.h_2_comm.to_bytes());
         writer.write(&self.z_comm.to_bytes());
         writer.write(&self.p_comm.to_bytes());
+        writer.write(&self.m_comm.to_bytes());
         writer.write(&self.t_1_comm.to_bytes());
         writer.write(&self.t_2_comm.to_bytes());
         writer.write(&self.t_3_comm.to_bytes());
         writer.write(&self.t_4_comm.to_bytes());
-        writer.write(&self.w_z_comm.to_bytes());
-        writer.write(&self.w_zw_comm.to_bytes());
+        writer.write(&self.w_z.to_bytes());
+        writer.write(&self.w_zw.to_bytes());
         writer.write(&self.evaluations.to_bytes());
 
         buf
     }
 
     fn from_bytes(buf: &[u8; Self::SIZE]) -> Result<Self, Self::Error> {
-        let mut buffer = &buf[..];
+        let (argument, mut buffer) = buf.split_first().unwrap();
 
+        let lookup_argument = LookupArgument::from_byte(*argument)
+            .ok_or(dusk_bytes::Error::InvalidData)?;
         let a_comm = Commitment::from_reader(&mut buffer)?;
         let b_comm = Commitment::from_reader(&mut buffer)?;
         let c_comm = Commitment::from_reader(&mut buffer)?;
@@ -111,12 +127,13 @@ impl Serializable<{ 15 * Commitment::SIZE + ProofEvaluations::SIZE }>
         let h_2_comm = Commitment::from_reader(&mut buffer)?;
         let z_comm = Commitment::from_reader(&mut buffer)?;
         let p_comm = Commitment::from_reader(&mut buffer)?;
+        let m_comm = Commitment::from_reader(&mut buffer)?;
         let t_1_comm = Commitment::from_reader(&mut buffer)?;
         let t_2_comm = Commitment::from_reader(&mut buffer)?;
         let t_3_comm = Commitment::from_reader(&mut buffer)?;
         let t_4_comm = Commitment::from_reader(&mut buffer)?;
-        let w_z_comm = Commitment::from_reader(&mut buffer)?;
-        let w_zw_comm = Commitment::from_reader(&mut buffer)?;
+        let w_z = Commitment::from_reader(&mut buffer)?;
+        let w_zw = Commitment::from_reader(&mut buffer)?;
         let evaluations = ProofEvaluations::from_reader(&mut buffer)?;
 
         Ok(Proof {
@@ -129,40 +146,221 @@ impl Serializable<{ 15 * Commitment::SIZE + ProofEvaluations::SIZE }>
             h_2_comm,
             z_comm,
             p_comm,
+            m_comm,
+            lookup_argument,
             t_1_comm,
             t_2_comm,
             t_3_comm,
             t_4_comm,
-            w_z_comm,
-            w_zw_comm,
+            w_z,
+            w_zw,
             evaluations,
         })
     }
 }
 
 use crate::{
-    commitment_scheme::{AggregateProof, OpeningKey},
+    commitment_scheme::Opening,
     error::Error,
     fft::EvaluationDomain,
-    proof_system::widget::VerifierKey,
+    proof_system::widget::{lookup::compress, VerifierKey},
     transcript::TranscriptProtocol,
     util::batch_inversion,
 };
 use dusk_bls12_381::{multiscalar_mul::msm_variable_base, BlsScalar, G1Affine};
 use merlin::Transcript;
+use sp_std::vec;
 use sp_std::vec::Vec;
 
-impl Proof {
+/// Version of the [`Proof::to_var_bytes`] encoding of proofs carrying the
+/// commitments and evaluations of the lookup argument.
+const PROOF_VERSION_LOOKUP: u8 = 0;
+
+/// Version of the [`Proof::to_var_bytes`] encoding of proofs of circuits
+/// without lookups, which leaves the lookup argument out.
+const PROOF_VERSION_NO_LOOKUP: u8 = 1;
+
+/// Version of the [`Proof::to_var_bytes`] encoding of proofs carrying the
+/// commitments and evaluations of the logUp lookup argument, which leaves
+/// out the ones only used by plookup.
+const PROOF_VERSION_LOGUP: u8 = 2;
+
+impl<S: CommitmentScheme> Proof<S> {
+    /// Returns `true` if the proof carries no element of the lookup
+    /// argument, as is the case for proofs of circuits without lookups.
+    pub(crate) fn lookup_is_empty(&self) -> bool {
+        [
+            &self.f_comm,
+            &self.h_1_comm,
+            &self.h_2_comm,
+            &self.p_comm,
+            &self.m_comm,
+        ]
+        .iter()
+        .all(|comm| **comm == Commitment::default())
+            && self.lookup_argument == LookupArgument::default()
+            && self.evaluations.lookup_is_empty()
+    }
+
+    /// Returns the [`LookupArgument`] used to prove the lookups of the
+    /// circuit.
+    pub fn lookup_argument(&self) -> LookupArgument {
+        self.lookup_argument
+    }
+
+    /// Serializes the proof into a vector of bytes.
+    ///
+    /// Unlike [`Serializable::to_bytes`], which is only implemented for the
+    /// constant size KZG10 proofs, this supports any [`CommitmentScheme`].
+    ///
+    /// The encoding starts with a version byte. Proofs of circuits without
+    /// lookups leave out the lookup commitments and evaluations, which makes
+    /// them smaller, and proofs of the logUp argument only carry the
+    /// commitments and evaluations of that argument.
+    pub fn to_var_bytes(&self) -> Vec<u8> {
+        let lookups = !self.lookup_is_empty();
+        let logup = self.lookup_argument == LookupArgument::LogUp;
+        let version = match (lookups, logup) {
+            (true, true) => PROOF_VERSION_LOGUP,
+            (true, false) => PROOF_VERSION_LOOKUP,
+            (false, _) => PROOF_VERSION_NO_LOOKUP,
+        };
+
+        let mut bytes = Vec::with_capacity(
+            1 + 13 * Commitment::SIZE + ProofEvaluations::SIZE,
+        );
+        bytes.push(version);
+        [&self.a_comm, &self.b_comm, &self.c_comm, &self.d_comm]
+            .iter()
+            .for_each(|comm| bytes.extend_from_slice(&comm.to_bytes()));
+        if lookups && logup {
+            bytes.extend_from_slice(&self.m_comm.to_bytes());
+        } else if lookups {
+            [&self.f_comm, &self.h_1_comm, &self.h_2_comm]
+                .iter()
+                .for_each(|comm| bytes.extend_from_slice(&comm.to_bytes()));
+        }
+        bytes.extend_from_slice(&self.z_comm.to_bytes());
+        if lookups {
+            bytes.extend_from_slice(&self.p_comm.to_bytes());
+        }
+        [
+            &self.t_1_comm,
+            &self.t_2_comm,
+            &self.t_3_comm,
+            &self.t_4_comm,
+        ]
+        .iter()
+        .for_each(|comm| bytes.extend_from_slice(&comm.to_bytes()));
+        bytes.extend(S::opening_proof_to_var_bytes(&self.w_z));
+        bytes.extend(S::opening_proof_to_var_bytes(&self.w_zw));
+        if lookups && logup {
+            bytes.extend(self.evaluations.to_bytes_logup());
+        } else if lookups {
+            bytes.extend_from_slice(&self.evaluations.to_bytes());
+        } else {
+            bytes.extend(self.evaluations.to_bytes_without_lookups());
+        }
+
+        bytes
+    }
+
+    /// Deserializes a proof serialized with [`Proof::to_var_bytes`].
+    pub fn from_var_bytes(bytes: &[u8]) -> Result<Proof<S>, Error> {
+        let (version, mut buffer) =
+            bytes.split_first().ok_or(Error::NotEnoughBytes)?;
+        let (lookups, lookup_argument) = match *version {
+            PROOF_VERSION_LOOKUP => (true, LookupArgument::Plookup),
+            PROOF_VERSION_NO_LOOKUP => (false, LookupArgument::Plookup),
+            PROOF_VERSION_LOGUP => (true, LookupArgument::LogUp),
+            version => return Err(Error::UnsupportedProofVersion(version)),
+        };
+        let logup = lookup_argument == LookupArgument::LogUp;
+
+        let a_comm = Commitment::from_reader(&mut buffer)?;
+        let b_comm = Commitment::from_reader(&mut buffer)?;
+        let c_comm = Commitment::from_reader(&mut buffer)?;
+        let d_comm = Commitment::from_reader(&mut buffer)?;
+        let m_comm = if logup {
+            Commitment::from_reader(&mut buffer)?
+        } else {
+            Commitment::default()
+        };
+        let (f_comm, h_1_comm, h_2_comm) = if lookups && !logup {
+            (
+                Commitment::from_reader(&mut buffer)?,
+                Commitment::from_reader(&mut buffer)?,
+                Commitment::from_reader(&mut buffer)?,
+            )
+        } else {
+            Default::default()
+        };
+        let z_comm = Commitment::from_reader(&mut buffer)?;
+        let p_comm = if lookups {
+            Commitment::from_reader(&mut buffer)?
+        } else {
+            Commitment::default()
+        };
+        let t_1_comm = Commitment::from_reader(&mut buffer)?;
+        let t_2_comm = Commitment::from_reader(&mut buffer)?;
+        let t_3_comm = Commitment::from_reader(&mut buffer)?;
+        let t_4_comm = Commitment::from_reader(&mut buffer)?;
+        let w_z = S::opening_proof_from_reader(&mut buffer)?;
+        let w_zw = S::opening_proof_from_reader(&mut buffer)?;
+        let evaluations = if logup {
+            ProofEvaluations::from_reader_logup(&mut buffer)?
+        } else if lookups {
+            ProofEvaluations::from_reader(&mut buffer)?
+        } else {
+            ProofEvaluations::from_reader_without_lookups(&mut buffer)?
+        };
+
+        Ok(Proof {
+            a_comm,
+            b_comm,
+            c_comm,
+            d_comm,
+            f_comm,
+            h_1_comm,
+            h_2_comm,
+            z_comm,
+            p_comm,
+            m_comm,
+            lookup_argument,
+            t_1_comm,
+            t_2_comm,
+            t_3_comm,
+            t_4_comm,
+            w_z,
+            w_zw,
+            evaluations,
+        })
+    }
+
     /// Performs the verification of a [`Proof`] returning a boolean result.
     pub(crate) fn verify(
         &self,
-        verifier_key: &VerifierKey,
+        verifier_key: &VerifierKey<S>,
         transcript: &mut Transcript,
-        opening_key: &OpeningKey,
+        opening_key: &S::OpeningKey,
         pub_inputs: &[BlsScalar],
     ) -> Result<(), Error> {
         let domain = EvaluationDomain::new(verifier_key.n as usize)?;
 
+        // Proofs of circuits without lookups must not carry any element of
+        // the lookup argument
+        let lookups = verifier_key.lookups_enabled();
+        if !lookups && !self.lookup_is_empty() {
+            return Err(Error::ProofVerificationError);
+        }
+        // The lookups are proven with the argument of the key, which the
+        // proof must have been made with
+        if lookups && self.lookup_argument != verifier_key.lookup_argument {
+            return Err(Error::LookupArgumentMismatch);
+        }
+        let lookup_argument = lookups.then(|| verifier_key.lookup_argument);
+        let logup = lookup_argument == Some(LookupArgument::LogUp);
+
         // Subgroup checks are done when the proof is deserialised.
 
         // In order for the Verifier and Prover to have the same view in the
@@ -172,6 +370,12 @@ impl Proof {
         // that the prover added into the transcript, hence generating the
         // same challenges
         //
+        // Bind the lookup argument of the key to the transcript
+        transcript.append_message(
+            b"lookup_argument",
+            &[lookup_argument.unwrap_or_default().to_byte()],
+        );
+
         // Add commitment to witness polynomials to transcript
         transcript.append_commitment(b"w_l", &self.a_comm);
         transcript.append_commitment(b"w_r", &self.b_comm);
@@ -181,8 +385,13 @@ impl Proof {
         // Compute zeta compression challenge
         let zeta = transcript.challenge_scalar(b"zeta");
 
-        // Add f_poly commitment to transcript
-        transcript.append_commitment(b"f", &self.f_comm);
+        // Add f_poly commitment, or the multiplicities of the logUp
+        // argument, to transcript
+        if logup {
+            transcript.append_commitment(b"m", &self.m_comm);
+        } else if lookups {
+            transcript.append_commitment(b"f", &self.f_comm);
+        }
 
         // Compute beta and gamma challenges
         let beta = transcript.challenge_scalar(b"beta");
@@ -198,12 +407,17 @@ impl Proof {
         // Compute evaluation challenge
         let z_challenge = transcript.challenge_scalar(b"z_challenge");
 
-        // Add h polynomials to transcript
-        transcript.append_commitment(b"h1", &self.h_1_comm);
-        transcript.append_commitment(b"h2", &self.h_2_comm);
+        if logup {
+            // Add running sum polynomial commitment to transcript
+            transcript.append_commitment(b"p", &self.p_comm);
+        } else if lookups {
+            // Add h polynomials to transcript
+            transcript.append_commitment(b"h1", &self.h_1_comm);
+            transcript.append_commitment(b"h2", &self.h_2_comm);
 
-        // Add permutation polynomial commitment to transcript
-        transcript.append_commitment(b"p", &self.p_comm);
+            // Add permutation polynomial commitment to transcript
+            transcript.append_commitment(b"p", &self.p_comm);
+        }
 
         // Compute quotient challenge
         let alpha = transcript.challenge_scalar(b"alpha");
@@ -231,15 +445,9 @@ impl Proof {
         let l1_eval =
             compute_first_lagrange_evaluation(&domain, &z_h_eval, &z_challenge);
 
-        let table_comm = Commitment(G1Affine::from(
-            verifier_key.lookup.table_1.0
-                + verifier_key.lookup.table_2.0 * zeta
-                + verifier_key.lookup.table_3.0 * zeta * zeta
-                + verifier_key.lookup.table_4.0 * zeta * zeta * zeta,
-        ));
-
         // Compute quotient polynomial evaluated at `z_challenge`
         let t_eval = self.compute_quotient_evaluation(
+            lookup_argument,
             &domain,
             pub_inputs,
             &alpha,
@@ -247,6 +455,7 @@ impl Proof {
             &gamma,
             &delta,
             &epsilon,
+            &zeta,
             &z_challenge,
             &z_h_eval,
             &l1_eval,
@@ -281,22 +490,38 @@ impl Proof {
         transcript.append_scalar(b"q_c_eval", &self.evaluations.q_c_eval);
         transcript.append_scalar(b"q_l_eval", &self.evaluations.q_l_eval);
         transcript.append_scalar(b"q_r_eval", &self.evaluations.q_r_eval);
-        transcript
-            .append_scalar(b"q_lookup_eval", &self.evaluations.q_lookup_eval);
+        if lookups {
+            transcript.append_scalar(
+                b"q_lookup_eval",
+                &self.evaluations.q_lookup_eval,
+            );
+        }
         transcript.append_scalar(b"perm_eval", &self.evaluations.perm_eval);
-        transcript.append_scalar(
-            b"lookup_perm_eval",
-            &self.evaluations.lookup_perm_eval,
-        );
-        transcript.append_scalar(b"h_1_eval", &self.evaluations.h_1_eval);
-        transcript
-            .append_scalar(b"h_1_next_eval", &self.evaluations.h_1_next_eval);
-        transcript.append_scalar(b"h_2_eval", &self.evaluations.h_2_eval);
+        if lookups {
+            transcript.append_scalar(
+                b"lookup_perm_eval",
+                &self.evaluations.lookup_perm_eval,
+            );
+            if logup {
+                transcript
+                    .append_scalar(b"table_eval", &self.evaluations.table_eval);
+            } else {
+                transcript
+                    .append_scalar(b"h_1_eval", &self.evaluations.h_1_eval);
+                transcript.append_scalar(
+                    b"h_1_next_eval",
+                    &self.evaluations.h_1_next_eval,
+                );
+                transcript
+                    .append_scalar(b"h_2_eval", &self.evaluations.h_2_eval);
+            }
+        }
         transcript.append_scalar(b"t_eval", &t_eval);
         transcript.append_scalar(b"r_eval", &self.evaluations.lin_poly_eval);
 
         // Compute linearisation commitment
         let r_comm = self.compute_linearisation_commitment(
+            lookup_argument,
             &alpha,
             &beta,
             &gamma,
@@ -319,70 +544,96 @@ impl Proof {
 
         // Commitment Scheme
         // Now we delegate computation to the commitment scheme by batch
-        // checking two proofs The `AggregateProof`, which is a
-        // proof that all the necessary polynomials evaluated at
-        // `z_challenge` are correct and a `SingleProof` which
-        // is proof that the permutation polynomial evaluated at the shifted
-        // root of unity is correct
-
-        // Compose the Aggregated Proof
-        //
-        let mut aggregate_proof = AggregateProof::with_witness(self.w_z_comm);
-        aggregate_proof.add_part((t_eval, t_comm));
-        aggregate_proof.add_part((self.evaluations.lin_poly_eval, r_comm));
-        aggregate_proof.add_part((self.evaluations.a_eval, self.a_comm));
-        aggregate_proof.add_part((self.evaluations.b_eval, self.b_comm));
-        aggregate_proof.add_part((self.evaluations.c_eval, self.c_comm));
-        aggregate_proof.add_part((self.evaluations.d_eval, self.d_comm));
-        aggregate_proof.add_part((
-            self.evaluations.left_sigma_eval,
+        // checking two openings: one of all the necessary polynomials
+        // evaluated at `z_challenge` and one of the polynomials evaluated at
+        // the shifted root of unity
+        let mut z_commitments = vec![
+            t_comm,
+            r_comm,
+            self.a_comm,
+            self.b_comm,
+            self.c_comm,
+            self.d_comm,
             verifier_key.permutation.left_sigma,
-        ));
-        aggregate_proof.add_part((
-            self.evaluations.right_sigma_eval,
             verifier_key.permutation.right_sigma,
-        ));
-        aggregate_proof.add_part((
-            self.evaluations.out_sigma_eval,
             verifier_key.permutation.out_sigma,
-        ));
-        aggregate_proof.add_part((self.evaluations.f_eval, self.f_comm));
-        aggregate_proof.add_part((self.evaluations.h_1_eval, self.h_1_comm));
-        aggregate_proof.add_part((self.evaluations.h_2_eval, self.h_2_comm));
-        aggregate_proof.add_part((self.evaluations.table_eval, table_comm));
-        // Flatten proof with opening challenge
-        let flattened_proof_a = aggregate_proof.flatten(transcript);
-
-        // Compose the shifted aggregate proof
-        let mut shifted_aggregate_proof =
-            AggregateProof::with_witness(self.w_zw_comm);
-        shifted_aggregate_proof
-            .add_part((self.evaluations.perm_eval, self.z_comm));
-        shifted_aggregate_proof
-            .add_part((self.evaluations.a_next_eval, self.a_comm));
-        shifted_aggregate_proof
-            .add_part((self.evaluations.b_next_eval, self.b_comm));
-        shifted_aggregate_proof
-            .add_part((self.evaluations.d_next_eval, self.d_comm));
-        shifted_aggregate_proof
-            .add_part((self.evaluations.h_1_next_eval, self.h_1_comm));
-        shifted_aggregate_proof
-            .add_part((self.evaluations.lookup_perm_eval, self.p_comm));
-        shifted_aggregate_proof
-            .add_part((self.evaluations.table_next_eval, table_comm));
-        let flattened_proof_b = shifted_aggregate_proof.flatten(transcript);
-        // Add commitment to openings to transcript
-        transcript.append_commitment(b"w_z", &self.w_z_comm);
-        transcript.append_commitment(b"w_z_w", &self.w_zw_comm);
-        // Batch check
-        if opening_key
-            .batch_check(
-                &[z_challenge, (z_challenge * domain.group_gen)],
-                &[flattened_proof_a, flattened_proof_b],
-                transcript,
-            )
-            .is_err()
-        {
+        ];
+        let mut z_evaluations = vec![
+            t_eval,
+            self.evaluations.lin_poly_eval,
+            self.evaluations.a_eval,
+            self.evaluations.b_eval,
+            self.evaluations.c_eval,
+            self.evaluations.d_eval,
+            self.evaluations.left_sigma_eval,
+            self.evaluations.right_sigma_eval,
+            self.evaluations.out_sigma_eval,
+        ];
+
+        let mut zw_commitments =
+            vec![self.z_comm, self.a_comm, self.b_comm, self.d_comm];
+        let mut zw_evaluations = vec![
+            self.evaluations.perm_eval,
+            self.evaluations.a_next_eval,
+            self.evaluations.b_next_eval,
+            self.evaluations.d_next_eval,
+        ];
+
+        // The lookup polynomials are only opened if the circuit uses lookups
+        if lookups {
+            let zeta_sq = zeta.square();
+            let table_comm = Commitment(G1Affine::from(
+                verifier_key.lookup.table_1.0
+                    + verifier_key.lookup.table_2.0 * zeta
+                    + verifier_key.lookup.table_3.0 * zeta_sq
+                    + verifier_key.lookup.table_4.0 * (zeta_sq * zeta)
+                    + verifier_key.lookup.table_id.0 * zeta_sq.square(),
+            ));
+
+            if logup {
+                z_commitments.push(table_comm);
+                z_evaluations.push(self.evaluations.table_eval);
+
+                zw_commitments.push(self.p_comm);
+                zw_evaluations.push(self.evaluations.lookup_perm_eval);
+            } else {
+                z_commitments.extend([
+                    self.f_comm,
+                    self.h_1_comm,
+                    self.h_2_comm,
+                    table_comm,
+                ]);
+                z_evaluations.extend([
+                    self.evaluations.f_eval,
+                    self.evaluations.h_1_eval,
+                    self.evaluations.h_2_eval,
+                    self.evaluations.table_eval,
+                ]);
+
+                zw_commitments.extend([self.h_1_comm, self.p_comm, table_comm]);
+                zw_evaluations.extend([
+                    self.evaluations.h_1_next_eval,
+                    self.evaluations.lookup_perm_eval,
+                    self.evaluations.table_next_eval,
+                ]);
+            }
+        }
+
+        let openings = [
+            Opening {
+                point: z_challenge,
+                commitments: &z_commitments,
+                evaluations: &z_evaluations,
+                proof: &self.w_z,
+            },
+            Opening {
+                point: z_challenge * domain.group_gen,
+                commitments: &zw_commitments,
+                evaluations: &zw_evaluations,
+                proof: &self.w_zw,
+            },
+        ];
+        if S::verify(opening_key, &openings, transcript).is_err() {
             return Err(Error::ProofVerificationError);
         }
 
@@ -392,6 +643,7 @@ impl Proof {
     #[allow(clippy::too_many_arguments)]
     fn compute_quotient_evaluation(
         &self,
+        lookup_argument: Option<LookupArgument>,
         domain: &EvaluationDomain,
         pub_inputs: &[BlsScalar],
         alpha: &BlsScalar,
@@ -399,6 +651,7 @@ impl Proof {
         gamma: &BlsScalar,
         delta: &BlsScalar,
         epsilon: &BlsScalar,
+        zeta: &BlsScalar,
         z_challenge: &BlsScalar,
         z_h_eval: &BlsScalar,
         l1_eval: &BlsScalar,
@@ -441,17 +694,46 @@ impl Proof {
         // l_1(z) * alpha_0^2
         let c = l1_eval * alpha_sq;
 
-        // l_1(z) * alpha_1^2
-        let e = l1_eval * l_sep_2;
-
-        // p_eval * (epsilon( 1+ delta) + h_1_eval + delta *
-        // h_2_eval)(epsilon( 1+ delta) + delta * h_1_next_eval) * alpha_1^3
-        let f_0 = epsilon_one_plus_delta
-            + self.evaluations.h_1_eval
-            + (delta * self.evaluations.h_2_eval);
-        let f_1 =
-            epsilon_one_plus_delta + (delta * self.evaluations.h_1_next_eval);
-        let f = self.evaluations.lookup_perm_eval * f_0 * f_1 * l_sep_3;
+        // The lookup terms are only present if the circuit uses lookups
+        let (e, f) = match lookup_argument {
+            Some(LookupArgument::Plookup) => {
+                // l_1(z) * alpha_1^2
+                let e = l1_eval * l_sep_2;
+
+                // p_eval * (epsilon( 1+ delta) + h_1_eval + delta *
+                // h_2_eval)(epsilon( 1+ delta) + delta * h_1_next_eval) *
+                // alpha_1^3
+                let f_0 = epsilon_one_plus_delta
+                    + self.evaluations.h_1_eval
+                    + (delta * self.evaluations.h_2_eval);
+                let f_1 = epsilon_one_plus_delta
+                    + (delta * self.evaluations.h_1_next_eval);
+                let f = self.evaluations.lookup_perm_eval * f_0 * f_1 * l_sep_3;
+
+                (e, f)
+            }
+            Some(LookupArgument::LogUp) => {
+                //
+                // - φ(zω) * (ε + f(z)) * (ε + t(z)) * alpha_1
+                let f_eval = epsilon
+                    + compress(
+                        self.evaluations.a_eval,
+                        self.evaluations.b_eval,
+                        self.evaluations.c_eval,
+                        self.evaluations.d_eval,
+                        self.evaluations.q_c_eval,
+                        *zeta,
+                    );
+                let t_eval = epsilon + self.evaluations.table_eval;
+                let e = -self.evaluations.lookup_perm_eval
+                    * f_eval
+                    * t_eval
+                    * lookup_sep_challenge;
+
+                (e, BlsScalar::zero())
+            }
+            None => (BlsScalar::zero(), BlsScalar::zero()),
+        };
 
         // Return t_eval
         (a - b - c //+ d
@@ -478,6 +760,7 @@ impl Proof {
     #[allow(clippy::too_many_arguments)]
     fn compute_linearisation_commitment(
         &self,
+        lookup_argument: Option<LookupArgument>,
         alpha: &BlsScalar,
         beta: &BlsScalar,
         gamma: &BlsScalar,
@@ -495,7 +778,7 @@ impl Proof {
         l1_eval: BlsScalar,
         t_eval: BlsScalar,
         t_next_eval: BlsScalar,
-        verifier_key: &VerifierKey,
+        verifier_key: &VerifierKey<S>,
     ) -> Commitment {
         let mut scalars: Vec<_> = Vec::with_capacity(6);
         let mut points: Vec<G1Affine> = Vec::with_capacity(6);
@@ -534,19 +817,36 @@ impl Proof {
             &self.evaluations,
         );
 
-        verifier_key.lookup.compute_linearisation_commitment(
-            lookup_sep_challenge,
-            &mut scalars,
-            &mut points,
-            &self.evaluations,
-            (delta, epsilon),
-            zeta,
-            &l1_eval,
-            &t_eval,
-            &t_next_eval,
-            self.h_2_comm.0,
-            self.p_comm.0,
-        );
+        match lookup_argument {
+            Some(LookupArgument::Plookup) => {
+                verifier_key.lookup.compute_linearisation_commitment(
+                    lookup_sep_challenge,
+                    &mut scalars,
+                    &mut points,
+                    &self.evaluations,
+                    (delta, epsilon),
+                    zeta,
+                    &l1_eval,
+                    &t_eval,
+                    &t_next_eval,
+                    self.h_2_comm.0,
+                    self.p_comm.0,
+                );
+            }
+            Some(LookupArgument::LogUp) => {
+                verifier_key.lookup.compute_logup_linearisation_commitment(
+                    lookup_sep_challenge,
+                    &mut scalars,
+                    &mut points,
+                    &self.evaluations,
+                    epsilon,
+                    zeta,
+                    self.p_comm.0,
+                    self.m_comm.0,
+                );
+            }
+            None => (),
+        }
 
         verifier_key.permutation.compute_linearisation_commitment(
             &mut scalars,
@@ -650,12 +950,14 @@ mod proof_tests {
             h_2_comm: Commitment::default(),
             z_comm: Commitment::default(),
             p_comm: Commitment::default(),
+            m_comm: Commitment::default(),
+            lookup_argument: LookupArgument::Plookup,
             t_1_comm: Commitment::default(),
             t_2_comm: Commitment::default(),
             t_3_comm: Commitment::default(),
             t_4_comm: Commitment::default(),
-            w_z_comm: Commitment::default(),
-            w_zw_comm: Commitment::default(),
+            w_z: Commitment::default(),
+            w_zw: Commitment::default(),
             evaluations: ProofEvaluations {
                 a_eval: BlsScalar::random(rng.clone()),
                 b_eval: BlsScalar::random(rng.clone()),
@@ -687,5 +989,69 @@ mod proof_tests {
         let proof_bytes = proof.to_bytes();
         let got_proof = Proof::from_bytes(&proof_bytes).unwrap();
         assert_eq!(got_proof, proof);
+
+        let proof_bytes = proof.to_var_bytes();
+        assert_eq!(proof_bytes[0], PROOF_VERSION_LOOKUP);
+        let got_proof: Proof = Proof::from_var_bytes(&proof_bytes).unwrap();
+        assert_eq!(got_proof, proof);
+    }
+
+    #[test]
+    fn test_var_bytes_serde_proof_without_lookups() {
+        let proof: Proof = Proof {
+            a_comm: Commitment(G1Affine::generator()),
+            z_comm: Commitment(G1Affine::generator()),
+            evaluations: ProofEvaluations {
+                a_eval: BlsScalar::one(),
+                perm_eval: BlsScalar::one(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert!(proof.lookup_is_empty());
+
+        let proof_bytes = proof.to_var_bytes();
+        assert_eq!(proof_bytes[0], PROOF_VERSION_NO_LOOKUP);
+        assert_eq!(
+            proof_bytes.len(),
+            1 + 11 * Commitment::SIZE + ProofEvaluations::SIZE_WITHOUT_LOOKUPS
+        );
+        let got_proof: Proof = Proof::from_var_bytes(&proof_bytes).unwrap();
+        assert_eq!(got_proof, proof);
+
+        let mut unknown = proof_bytes;
+        unknown[0] = 0xff;
+        assert!(matches!(
+            Proof::<Kzg10>::from_var_bytes(&unknown),
+            Err(Error::UnsupportedProofVersion(0xff))
+        ));
+    }
+
+    #[test]
+    fn test_var_bytes_serde_logup_proof() {
+        let proof: Proof = Proof {
+            a_comm: Commitment(G1Affine::generator()),
+            m_comm: Commitment(G1Affine::generator()),
+            p_comm: Commitment(G1Affine::generator()),
+            lookup_argument: LookupArgument::LogUp,
+            evaluations: ProofEvaluations {
+                a_eval: BlsScalar::one(),
+                q_lookup_eval: BlsScalar::one(),
+                lookup_perm_eval: BlsScalar::from(2u64),
+                table_eval: BlsScalar::from(3u64),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        // The plookup commitments and evaluations are left out
+        let proof_bytes = proof.to_var_bytes();
+        assert_eq!(proof_bytes[0], PROOF_VERSION_LOGUP);
+        assert_eq!(
+            proof_bytes.len(),
+            1 + 13 * Commitment::SIZE + ProofEvaluations::SIZE_LOGUP
+        );
+        let got_proof: Proof = Proof::from_var_bytes(&proof_bytes).unwrap();
+        assert_eq!(got_proof, proof);
     }
 }
@@ -5,16 +5,17 @@
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
 use crate::{
-    commitment_scheme::CommitKey,
+    commitment_scheme::{Commitment, CommitmentScheme, Kzg10},
     constraint_system::{TurboComposer, Witness},
     error::Error,
     fft::{EvaluationDomain, Polynomial},
-    plonkup::MultiSet,
+    plonkup::{LookupArgument, MultiSet},
     proof_system::{
-        linearisation_poly, proof::Proof, quotient_poly, ProverKey,
+        linearisation_poly, proof::Proof, quotient_poly, ProverKey, VerifierKey,
     },
     transcript::TranscriptProtocol,
 };
+use core::marker::PhantomData;
 use dusk_bls12_381::BlsScalar;
 use merlin::Transcript;
 use sp_std::vec;
@@ -22,8 +23,11 @@ use sp_std::vec::Vec;
 
 /// Abstraction structure designed to construct a circuit and generate
 /// [`Proof`]s for it.
+///
+/// The polynomials of the circuit are committed to with the
+/// [`CommitmentScheme`] `S`, KZG10 by default.
 #[allow(missing_debug_implementations)]
-pub struct Prover {
+pub struct Prover<S = Kzg10> {
     /// ProverKey which is used to create proofs about a specific PLONK circuit
     pub prover_key: Option<ProverKey>,
 
@@ -31,56 +35,127 @@ pub struct Prover {
     /// Store the messages exchanged during the preprocessing stage
     /// This is copied each time, we make a proof
     pub preprocessed_transcript: Transcript,
+
+    lookup_argument: LookupArgument,
+
+    scheme: PhantomData<S>,
 }
 
-impl Prover {
+impl<S: CommitmentScheme> Prover<S> {
     /// Mutable borrow of [`TurboComposer`].
     pub fn composer_mut(&mut self) -> &mut TurboComposer {
         &mut self.cs
     }
 
     /// Preprocesses the underlying constraint system.
-    pub fn preprocess(&mut self, commit_key: &CommitKey) -> Result<(), Error> {
+    ///
+    /// Returns the [`VerifierKey`] of the circuit, which is computed in the
+    /// same pass as the [`ProverKey`], so the circuit doesn't need to be
+    /// preprocessed again by a [`Verifier`](super::Verifier).
+    pub fn preprocess(
+        &mut self,
+        commit_key: &S::CommitKey,
+    ) -> Result<VerifierKey<S>, Error> {
         if self.prover_key.is_some() {
             return Err(Error::CircuitAlreadyPreprocessed);
         }
-        let pk = self
-            .cs
-            .preprocess_prover(commit_key, &mut self.preprocessed_transcript)?;
+        let (pk, vk) = self.cs.preprocess_prover::<S>(
+            commit_key,
+            &mut self.preprocessed_transcript,
+            self.lookup_argument,
+        )?;
         self.prover_key = Some(pk);
-        Ok(())
+        Ok(vk)
     }
 }
 
-impl Default for Prover {
-    fn default() -> Prover {
-        Prover::new(b"plonk")
+impl<S> Prover<S> {
+    /// Returns the number of gates in the circuit thet the `Prover` actually
+    /// stores inside.
+    pub const fn gates(&self) -> u32 {
+        self.cs.gates()
+    }
+
+    /// Returns the [`LookupArgument`] used to prove the lookups of the
+    /// circuit.
+    pub const fn lookup_argument(&self) -> LookupArgument {
+        self.lookup_argument
+    }
+
+    /// Sets the [`LookupArgument`] used to prove the lookups of the circuit.
+    ///
+    /// The argument is recorded in the [`ProverKey`] and [`VerifierKey`] when
+    /// the circuit is preprocessed, so it must be set before. Proofs are
+    /// always made with the argument of the [`ProverKey`].
+    pub fn set_lookup_argument(&mut self, argument: LookupArgument) {
+        self.lookup_argument = argument;
+    }
+}
+
+impl<S: CommitmentScheme> Default for Prover<S> {
+    fn default() -> Prover<S> {
+        Prover::with_label(b"plonk")
     }
 }
 
 impl Prover {
     /// Creates a new `Prover` instance.
     pub fn new(label: &'static [u8]) -> Prover {
+        Prover::with_label(label)
+    }
+
+    /// Creates a new `Prover` object with some expected size.
+    pub fn with_size(label: &'static [u8], size: usize) -> Prover {
+        Prover::with_label_and_size(label, size)
+    }
+
+    /// Creates a new `Prover` whose composer only records the witnesses of
+    /// the circuit.
+    pub fn witness_only(label: &'static [u8]) -> Prover {
+        Prover::with_label_witness_only(label)
+    }
+}
+
+impl<S: CommitmentScheme> Prover<S> {
+    /// Creates a new `Prover` instance for the [`CommitmentScheme`] `S`.
+    pub fn with_label(label: &'static [u8]) -> Prover<S> {
         Prover {
             prover_key: None,
             cs: TurboComposer::new(),
             preprocessed_transcript: Transcript::new(label),
+            lookup_argument: LookupArgument::default(),
+            scheme: PhantomData,
         }
     }
 
-    /// Creates a new `Prover` object with some expected size.
-    pub fn with_size(label: &'static [u8], size: usize) -> Prover {
+    /// Creates a new `Prover` object for the [`CommitmentScheme`] `S` with
+    /// some expected size.
+    pub fn with_label_and_size(label: &'static [u8], size: usize) -> Prover<S> {
         Prover {
             prover_key: None,
             cs: TurboComposer::with_size(size),
             preprocessed_transcript: Transcript::new(label),
+            lookup_argument: LookupArgument::default(),
+            scheme: PhantomData,
         }
     }
 
-    /// Returns the number of gates in the circuit thet the `Prover` actually
-    /// stores inside.
-    pub const fn gates(&self) -> u32 {
-        self.cs.gates()
+    /// Creates a new `Prover` for the [`CommitmentScheme`] `S` whose
+    /// composer only records the witnesses of the circuit, as described in
+    /// [`TurboComposer::is_witness_only`].
+    ///
+    /// The circuit can't be preprocessed by the `Prover`, so the
+    /// [`ProverKey`] it was compiled to must be set before proving it. The
+    /// selectors and the permutation of the circuit are then taken from the
+    /// key, which makes building the circuit again for every proof cheaper.
+    pub fn with_label_witness_only(label: &'static [u8]) -> Prover<S> {
+        Prover {
+            prover_key: None,
+            cs: TurboComposer::witness_only(),
+            preprocessed_transcript: Transcript::new(label),
+            lookup_argument: LookupArgument::default(),
+            scheme: PhantomData,
+        }
     }
 
     /// Split `t(X)` poly into 4 degree `n` polynomials.
@@ -119,6 +194,32 @@ impl Prover {
         &abc + &d
     }
 
+    /// Checks the circuit of a witness-only composer, which can't be
+    /// fingerprinted without its selectors, against the shape recorded by the
+    /// `prover_key`, including the digest of its wires.
+    fn check_witness_only_shape(
+        &self,
+        prover_key: &ProverKey,
+    ) -> Result<(), Error> {
+        if self.cs.witness_only_optimized {
+            return Err(Error::WitnessOnlyOptimization);
+        }
+
+        let gates = self.cs.gates();
+        let wires = [&self.cs.w_l, &self.cs.w_r, &self.cs.w_o, &self.cs.w_4];
+
+        if gates != prover_key.gates()
+            || wires.iter().any(|w| w.len() != gates as usize)
+            || self.cs.public_input_indexes()
+                != prover_key.public_input_indexes()
+            || &self.cs.wires_digest() != prover_key.wires_digest()
+        {
+            return Err(Error::CircuitShapeMismatch);
+        }
+
+        Ok(())
+    }
+
     /// Convert witnesses to their actual witness values.
     pub(crate) fn to_scalars(&self, vars: &[Witness]) -> Vec<BlsScalar> {
         vars.iter().map(|var| self.cs.witnesses[var]).collect()
@@ -128,7 +229,85 @@ impl Prover {
     /// This function is used when the user wants to make multiple proofs with
     /// the same circuit.
     pub fn clear_witness(&mut self) {
-        self.cs = TurboComposer::new();
+        self.cs = if self.cs.is_witness_only() {
+            TurboComposer::witness_only()
+        } else {
+            TurboComposer::new()
+        };
+    }
+
+    /// Compresses the lookup tables and the queries of the lookup gates,
+    /// tagging each of them with the id of the table it belongs to.
+    ///
+    /// The queries of the gates that are not lookup gates are replaced with
+    /// the first row of the table.
+    fn compress_lookups(
+        prover_key: &ProverKey,
+        zeta: BlsScalar,
+        [padded_q_lookup, padded_q_c]: [&[BlsScalar]; 2],
+        [w_l_scalar, w_r_scalar, w_o_scalar, w_4_scalar]: [&[BlsScalar]; 4],
+    ) -> (MultiSet, MultiSet) {
+        // Compress table into vector of single elements, tagging each
+        // row with the id of the table it belongs to
+        let compressed_t_multiset = MultiSet::compress_five_arity(
+            [
+                &prover_key.lookup.table_1.0,
+                &prover_key.lookup.table_2.0,
+                &prover_key.lookup.table_3.0,
+                &prover_key.lookup.table_4.0,
+                &prover_key.lookup.table_id.0,
+            ],
+            zeta,
+        );
+
+        // Compute table f
+        // When q_lookup[i] is zero the wire value is replaced with a dummy
+        // value Currently set as the first row of the public table
+        // If q_lookup is one the wire values are preserved
+        let f_1_scalar = w_l_scalar
+            .iter()
+            .zip(padded_q_lookup)
+            .map(|(w, s)| {
+                w * s + (BlsScalar::one() - s) * compressed_t_multiset.0[0]
+            })
+            .collect::<Vec<BlsScalar>>();
+        let f_2_scalar = w_r_scalar
+            .iter()
+            .zip(padded_q_lookup)
+            .map(|(w, s)| w * s)
+            .collect::<Vec<BlsScalar>>();
+        let f_3_scalar = w_o_scalar
+            .iter()
+            .zip(padded_q_lookup)
+            .map(|(w, s)| w * s)
+            .collect::<Vec<BlsScalar>>();
+        let f_4_scalar = w_4_scalar
+            .iter()
+            .zip(padded_q_lookup)
+            .map(|(w, s)| w * s)
+            .collect::<Vec<BlsScalar>>();
+
+        // The constant selector of a lookup gate holds the id of the
+        // table it queries
+        let f_id_scalar = padded_q_c
+            .iter()
+            .zip(padded_q_lookup)
+            .map(|(q, s)| q * s)
+            .collect::<Vec<BlsScalar>>();
+
+        // Compress all wires into a single vector
+        let compressed_f_multiset = MultiSet::compress_five_arity(
+            [
+                &MultiSet::from(&f_1_scalar[..]),
+                &MultiSet::from(&f_2_scalar[..]),
+                &MultiSet::from(&f_3_scalar[..]),
+                &MultiSet::from(&f_4_scalar[..]),
+                &MultiSet::from(&f_id_scalar[..]),
+            ],
+            zeta,
+        );
+
+        (compressed_t_multiset, compressed_f_multiset)
     }
 
     /// Clears all data in the `Prover` instance.
@@ -152,17 +331,27 @@ impl Prover {
     /// after calling this method, the user should then call
     /// [`Prover::clear_witness`].
     /// This is automatically done when [`Prover::prove`] is called.
+    ///
+    /// Returns [`Error::CircuitShapeMismatch`] if the circuit has more gates
+    /// than the `prover_key` was preprocessed with. The circuit of a
+    /// witness-only composer must also have the number of gates, the wires
+    /// and the public input positions recorded by the `prover_key`, and must
+    /// not be optimized, see [`Error::WitnessOnlyOptimization`].
     pub fn prove_with_preprocessed(
         &self,
-        commit_key: &CommitKey,
+        commit_key: &S::CommitKey,
         prover_key: &ProverKey,
-    ) -> Result<Proof, Error> {
-        // make sure the domain is big enough to handle the circuit as well as
-        // the lookup table
-        let domain = EvaluationDomain::new(core::cmp::max(
-            self.cs.gates() as usize,
-            self.cs.lookup_table.0.len(),
-        ))?;
+    ) -> Result<Proof<S>, Error> {
+        // The domain the circuit was preprocessed with is big enough to
+        // handle the circuit as well as the lookup table
+        let domain = EvaluationDomain::new(prover_key.padded_gates() as usize)?;
+        if self.cs.gates() as usize > domain.size() {
+            return Err(Error::CircuitShapeMismatch);
+        }
+
+        if self.cs.is_witness_only() {
+            self.check_witness_only_shape(prover_key)?;
+        }
 
         // Since the caller is passing a pre-processed circuit
         // We assume that the Transcript has been seeded with the preprocessed
@@ -179,9 +368,6 @@ impl Prover {
         let w_o_scalar = &[&self.to_scalars(&self.cs.w_o)[..], &pad].concat();
         let w_4_scalar = &[&self.to_scalars(&self.cs.w_4)[..], &pad].concat();
 
-        // make sure q_lookup is also the right size for constructing f
-        let padded_q_lookup = [&self.cs.q_lookup[..], &pad].concat();
-
         // Witnesses are now in evaluation form, convert them to coefficients
         // So that we may commit to them
         let w_l_poly =
@@ -194,10 +380,24 @@ impl Prover {
             Polynomial::from_coefficients_vec(domain.ifft(w_4_scalar));
 
         // Commit to witness polynomials
-        let w_l_poly_commit = commit_key.commit(&w_l_poly)?;
-        let w_r_poly_commit = commit_key.commit(&w_r_poly)?;
-        let w_o_poly_commit = commit_key.commit(&w_o_poly)?;
-        let w_4_poly_commit = commit_key.commit(&w_4_poly)?;
+        let w_l_poly_commit = S::commit(commit_key, &w_l_poly)?;
+        let w_r_poly_commit = S::commit(commit_key, &w_r_poly)?;
+        let w_o_poly_commit = S::commit(commit_key, &w_o_poly)?;
+        let w_4_poly_commit = S::commit(commit_key, &w_4_poly)?;
+
+        // The plonkup polynomials are only computed, committed to and opened
+        // if the circuit uses lookups
+        let lookups = prover_key.lookups_enabled();
+        let lookup_argument = if lookups {
+            prover_key.lookup_argument
+        } else {
+            LookupArgument::default()
+        };
+        let logup = lookup_argument == LookupArgument::LogUp;
+
+        // Bind the lookup argument of the key to the transcript
+        transcript
+            .append_message(b"lookup_argument", &[lookup_argument.to_byte()]);
 
         // Add witness polynomial commitments to transcript
         transcript.append_commitment(b"w_l", &w_l_poly_commit);
@@ -208,70 +408,121 @@ impl Prover {
         // Generate table compression factor
         let zeta = transcript.challenge_scalar(b"zeta");
 
-        // Compress table into vector of single elements
-        let compressed_t_multiset = MultiSet::compress_four_arity(
-            [
-                &prover_key.lookup.table_1.0,
-                &prover_key.lookup.table_2.0,
-                &prover_key.lookup.table_3.0,
-                &prover_key.lookup.table_4.0,
-            ],
-            zeta,
-        );
-
-        // Compute table poly
-        let table_poly = Polynomial::from_coefficients_vec(
-            domain.ifft(&compressed_t_multiset.0),
-        );
-
-        // Compute table f
-        // When q_lookup[i] is zero the wire value is replaced with a dummy
-        // value Currently set as the first row of the public table
-        // If q_lookup is one the wire values are preserved
-        let f_1_scalar = w_l_scalar
-            .iter()
-            .zip(&padded_q_lookup)
-            .map(|(w, s)| {
-                w * s + (BlsScalar::one() - s) * compressed_t_multiset.0[0]
-            })
-            .collect::<Vec<BlsScalar>>();
-        let f_2_scalar = w_r_scalar
-            .iter()
-            .zip(&padded_q_lookup)
-            .map(|(w, s)| w * s)
-            .collect::<Vec<BlsScalar>>();
-        let f_3_scalar = w_o_scalar
-            .iter()
-            .zip(&padded_q_lookup)
-            .map(|(w, s)| w * s)
-            .collect::<Vec<BlsScalar>>();
-        let f_4_scalar = w_4_scalar
-            .iter()
-            .zip(&padded_q_lookup)
-            .map(|(w, s)| w * s)
-            .collect::<Vec<BlsScalar>>();
-
-        // Compress all wires into a single vector
-        let compressed_f_multiset = MultiSet::compress_four_arity(
-            [
-                &MultiSet::from(&f_1_scalar[..]),
-                &MultiSet::from(&f_2_scalar[..]),
-                &MultiSet::from(&f_3_scalar[..]),
-                &MultiSet::from(&f_4_scalar[..]),
-            ],
-            zeta,
-        );
-
-        // Compute long query poly
-        let f_poly = Polynomial::from_coefficients_vec(
-            domain.ifft(&compressed_f_multiset.0),
-        );
-
-        // Commit to query polynomial
-        let f_poly_commit = commit_key.commit(&f_poly)?;
-
-        // Add f_poly commitment to transcript
-        transcript.append_commitment(b"f", &f_poly_commit);
+        // Make sure q_lookup and q_c are the right size for constructing f.
+        // A witness-only composer doesn't record them, so they're evaluated
+        // from the key instead
+        let (padded_q_lookup, padded_q_c) = if !lookups {
+            (vec![], vec![])
+        } else if self.cs.is_witness_only() {
+            (
+                domain.fft(&prover_key.lookup.q_lookup.0),
+                domain.fft(&prover_key.arithmetic.q_c.0),
+            )
+        } else {
+            (
+                [&self.cs.q_lookup[..], &pad].concat(),
+                [&self.cs.q_c[..], &pad].concat(),
+            )
+        };
+
+        let (
+            compressed_t_multiset,
+            compressed_f_multiset,
+            table_poly,
+            f_poly,
+            f_poly_commit,
+            m_poly,
+            m_poly_commit,
+        ) = if logup {
+            let (compressed_t_multiset, compressed_f_multiset) =
+                Self::compress_lookups(
+                    prover_key,
+                    zeta,
+                    [&padded_q_lookup, &padded_q_c],
+                    [w_l_scalar, w_r_scalar, w_o_scalar, w_4_scalar],
+                );
+
+            // Compute table poly
+            let table_poly = Polynomial::from_coefficients_vec(
+                domain.ifft(&compressed_t_multiset.0),
+            );
+
+            // Only the rows of the lookup gates are queries
+            let queries = compressed_f_multiset
+                .0
+                .iter()
+                .zip(&padded_q_lookup)
+                .filter(|(_, q)| **q != BlsScalar::zero())
+                .map(|(f, _)| *f)
+                .collect::<Vec<BlsScalar>>();
+
+            // Count how many times every row of the table is queried
+            let m = compressed_t_multiset
+                .multiplicities(&MultiSet::from(&queries[..]))?;
+
+            // Compute multiplicities poly
+            let m_poly = Polynomial::from_coefficients_vec(domain.ifft(&m.0));
+
+            // Commit to multiplicities polynomial
+            let m_poly_commit = S::commit(commit_key, &m_poly)?;
+
+            // Add m_poly commitment to transcript
+            transcript.append_commitment(b"m", &m_poly_commit);
+
+            (
+                compressed_t_multiset,
+                compressed_f_multiset,
+                table_poly,
+                Polynomial::zero(),
+                Commitment::default(),
+                m_poly,
+                m_poly_commit,
+            )
+        } else if lookups {
+            let (compressed_t_multiset, compressed_f_multiset) =
+                Self::compress_lookups(
+                    prover_key,
+                    zeta,
+                    [&padded_q_lookup, &padded_q_c],
+                    [w_l_scalar, w_r_scalar, w_o_scalar, w_4_scalar],
+                );
+
+            // Compute table poly
+            let table_poly = Polynomial::from_coefficients_vec(
+                domain.ifft(&compressed_t_multiset.0),
+            );
+
+            // Compute long query poly
+            let f_poly = Polynomial::from_coefficients_vec(
+                domain.ifft(&compressed_f_multiset.0),
+            );
+
+            // Commit to query polynomial
+            let f_poly_commit = S::commit(commit_key, &f_poly)?;
+
+            // Add f_poly commitment to transcript
+            transcript.append_commitment(b"f", &f_poly_commit);
+
+            (
+                compressed_t_multiset,
+                compressed_f_multiset,
+                table_poly,
+                f_poly,
+                f_poly_commit,
+                Polynomial::zero(),
+                Commitment::default(),
+            )
+        } else {
+            (
+                MultiSet::new(),
+                MultiSet::new(),
+                Polynomial::zero(),
+                Polynomial::zero(),
+                Commitment::default(),
+                Polynomial::zero(),
+                Commitment::default(),
+            )
+        };
 
         // 2. Compute permutation polynomial
         //
@@ -301,7 +552,7 @@ impl Prover {
 
         // Commit to permutation polynomial
         //
-        let z_poly_commit = commit_key.commit(&z_poly)?;
+        let z_poly_commit = S::commit(commit_key, &z_poly)?;
 
         // Add commitment to permutation polynomial to transcript
         transcript.append_commitment(b"z", &z_poly_commit);
@@ -314,45 +565,99 @@ impl Prover {
         // Compute evaluation challenge; `z`
         let z_challenge = transcript.challenge_scalar(b"z_challenge");
 
-        // Compute s, as the sorted and concatenated version of f and t
-        let s = compressed_t_multiset
-            .sorted_concat(&compressed_f_multiset)
-            .unwrap();
-
-        // Compute first and second halves of s, as h_1 and h_2
-        let (h_1, h_2) = s.halve_alternating();
-
-        // Compute h polys
-        let h_1_poly = Polynomial::from_coefficients_vec(domain.ifft(&h_1.0));
-        let h_2_poly = Polynomial::from_coefficients_vec(domain.ifft(&h_2.0));
-
-        // Commit to h polys
-        let h_1_poly_commit = commit_key.commit(&h_1_poly).unwrap();
-        let h_2_poly_commit = commit_key.commit(&h_2_poly).unwrap();
-
-        // Add h polynomials to transcript
-        transcript.append_commitment(b"h1", &h_1_poly_commit);
-        transcript.append_commitment(b"h2", &h_2_poly_commit);
-
-        // Compute lookup permutation poly
-        let p_poly = Polynomial::from_coefficients_slice(
-            &self.cs.perm.compute_lookup_permutation_poly(
+        let (
+            h_1_poly,
+            h_2_poly,
+            p_poly,
+            h_1_poly_commit,
+            h_2_poly_commit,
+            p_poly_commit,
+        ) = if logup {
+            // Compute the running sum of the logUp argument, which takes the
+            // place of the plonkup permutation polynomial
+            let p_poly = self.cs.perm.compute_logup_running_sum_poly(
                 &domain,
                 &compressed_f_multiset.0,
+                &padded_q_lookup,
                 &compressed_t_multiset.0,
-                &h_1.0,
-                &h_2.0,
-                &delta,
+                &domain.fft(&m_poly),
                 &epsilon,
-            ),
-        );
-
-        // Commit to permutation polynomial
-        //
-        let p_poly_commit = commit_key.commit(&p_poly)?;
-
-        // Add permutation polynomial commitment to transcript
-        transcript.append_commitment(b"p", &p_poly_commit);
+            );
+
+            // Commit to running sum polynomial
+            let p_poly_commit = S::commit(commit_key, &p_poly)?;
+
+            // Add running sum polynomial commitment to transcript
+            transcript.append_commitment(b"p", &p_poly_commit);
+
+            (
+                Polynomial::zero(),
+                Polynomial::zero(),
+                p_poly,
+                Commitment::default(),
+                Commitment::default(),
+                p_poly_commit,
+            )
+        } else if lookups {
+            // Compute s, as the sorted and concatenated version of f and t
+            let s =
+                compressed_t_multiset.sorted_concat(&compressed_f_multiset)?;
+
+            // Compute first and second halves of s, as h_1 and h_2
+            let (h_1, h_2) = s.halve_alternating();
+
+            // Compute h polys
+            let h_1_poly =
+                Polynomial::from_coefficients_vec(domain.ifft(&h_1.0));
+            let h_2_poly =
+                Polynomial::from_coefficients_vec(domain.ifft(&h_2.0));
+
+            // Commit to h polys
+            let h_1_poly_commit = S::commit(commit_key, &h_1_poly).unwrap();
+            let h_2_poly_commit = S::commit(commit_key, &h_2_poly).unwrap();
+
+            // Add h polynomials to transcript
+            transcript.append_commitment(b"h1", &h_1_poly_commit);
+            transcript.append_commitment(b"h2", &h_2_poly_commit);
+
+            // Compute lookup permutation poly
+            let p_poly = Polynomial::from_coefficients_slice(
+                &self.cs.perm.compute_lookup_permutation_poly(
+                    &domain,
+                    &compressed_f_multiset.0,
+                    &compressed_t_multiset.0,
+                    &h_1.0,
+                    &h_2.0,
+                    &delta,
+                    &epsilon,
+                ),
+            );
+
+            // Commit to permutation polynomial
+            //
+            let p_poly_commit = S::commit(commit_key, &p_poly)?;
+
+            // Add permutation polynomial commitment to transcript
+            transcript.append_commitment(b"p", &p_poly_commit);
+
+            (
+                h_1_poly,
+                h_2_poly,
+                p_poly,
+                h_1_poly_commit,
+                h_2_poly_commit,
+                p_poly_commit,
+            )
+        } else {
+            (
+                Polynomial::zero(),
+                Polynomial::zero(),
+                Polynomial::zero(),
+                Commitment::default(),
+                Commitment::default(),
+                Commitment::default(),
+            )
+        };
 
         // 4. Compute quotient polynomial
         //
@@ -379,6 +684,8 @@ impl Prover {
             &table_poly,
             &h_1_poly,
             &h_2_poly,
+            &m_poly,
+            lookup_argument,
             &pi_poly,
             &(
                 alpha,
@@ -400,10 +707,10 @@ impl Prover {
             self.split_tx_poly(domain.size(), &t_poly);
 
         // Commit to splitted quotient polynomial
-        let t_1_commit = commit_key.commit(&t_1_poly)?;
-        let t_2_commit = commit_key.commit(&t_2_poly)?;
-        let t_3_commit = commit_key.commit(&t_3_poly)?;
-        let t_4_commit = commit_key.commit(&t_4_poly)?;
+        let t_1_commit = S::commit(commit_key, &t_1_poly)?;
+        let t_2_commit = S::commit(commit_key, &t_2_poly)?;
+        let t_3_commit = S::commit(commit_key, &t_3_poly)?;
+        let t_4_commit = S::commit(commit_key, &t_4_poly)?;
 
         // Add quotient polynomial commitments to transcript
         transcript.append_commitment(b"t_1", &t_1_commit);
@@ -442,6 +749,8 @@ impl Prover {
             &h_2_poly,
             &table_poly,
             &p_poly,
+            &m_poly,
+            lookup_argument,
         );
 
         // Add evaluations to transcript
@@ -470,21 +779,38 @@ impl Prover {
         transcript.append_scalar(b"q_c_eval", &evaluations.proof.q_c_eval);
         transcript.append_scalar(b"q_l_eval", &evaluations.proof.q_l_eval);
         transcript.append_scalar(b"q_r_eval", &evaluations.proof.q_r_eval);
-        transcript
-            .append_scalar(b"q_lookup_eval", &evaluations.proof.q_lookup_eval);
+        if lookups {
+            transcript.append_scalar(
+                b"q_lookup_eval",
+                &evaluations.proof.q_lookup_eval,
+            );
+        }
         transcript.append_scalar(b"perm_eval", &evaluations.proof.perm_eval);
-        transcript.append_scalar(
-            b"lookup_perm_eval",
-            &evaluations.proof.lookup_perm_eval,
-        );
-        transcript.append_scalar(b"h_1_eval", &evaluations.proof.h_1_eval);
-        transcript
-            .append_scalar(b"h_1_next_eval", &evaluations.proof.h_1_next_eval);
-        transcript.append_scalar(b"h_2_eval", &evaluations.proof.h_2_eval);
+        if lookups {
+            transcript.append_scalar(
+                b"lookup_perm_eval",
+                &evaluations.proof.lookup_perm_eval,
+            );
+            if logup {
+                transcript.append_scalar(
+                    b"table_eval",
+                    &evaluations.proof.table_eval,
+                );
+            } else {
+                transcript
+                    .append_scalar(b"h_1_eval", &evaluations.proof.h_1_eval);
+                transcript.append_scalar(
+                    b"h_1_next_eval",
+                    &evaluations.proof.h_1_next_eval,
+                );
+                transcript
+                    .append_scalar(b"h_2_eval", &evaluations.proof.h_2_eval);
+            }
+        }
         transcript.append_scalar(b"t_eval", &evaluations.quot_eval);
         transcript.append_scalar(b"r_eval", &evaluations.proof.lin_poly_eval);
 
-        // 5. Compute Openings using KZG10
+        // 5. Compute Openings using the commitment scheme
         //
         // We merge the quotient polynomial using the `z_challenge` so the SRS
         // is linear in the circuit size `n`
@@ -497,40 +823,43 @@ impl Prover {
             &z_challenge,
         );
 
-        // Compute aggregate witness to polynomials evaluated at the evaluation
-        // challenge `z`
-        let aggregate_witness = commit_key.compute_aggregate_witness(
-            &[
-                quot,
-                lin_poly,
-                w_l_poly.clone(),
-                w_r_poly.clone(),
-                w_o_poly,
-                w_4_poly.clone(),
-                prover_key.permutation.left_sigma.0.clone(),
-                prover_key.permutation.right_sigma.0.clone(),
-                prover_key.permutation.out_sigma.0.clone(),
+        // Open the polynomials evaluated at the evaluation challenge `z`
+        let mut z_polys = vec![
+            quot,
+            lin_poly,
+            w_l_poly.clone(),
+            w_r_poly.clone(),
+            w_o_poly,
+            w_4_poly.clone(),
+            prover_key.permutation.left_sigma.0.clone(),
+            prover_key.permutation.right_sigma.0.clone(),
+            prover_key.permutation.out_sigma.0.clone(),
+        ];
+        if logup {
+            z_polys.push(table_poly.clone());
+        } else if lookups {
+            z_polys.extend([
                 f_poly,
                 h_1_poly.clone(),
                 h_2_poly,
                 table_poly.clone(),
-            ],
-            &z_challenge,
-            &mut transcript,
-        );
-        let w_z_comm = commit_key.commit(&aggregate_witness)?;
-
-        // Compute aggregate witness to polynomials evaluated at the shifted
-        // evaluation challenge
-        let shifted_aggregate_witness = commit_key.compute_aggregate_witness(
-            &[
-                z_poly, w_l_poly, w_r_poly, w_4_poly, h_1_poly, p_poly,
-                table_poly,
-            ],
+            ]);
+        }
+        let w_z = S::open(commit_key, &z_polys, &z_challenge, &mut transcript)?;
+
+        // Open the polynomials evaluated at the shifted evaluation challenge
+        let mut zw_polys = vec![z_poly, w_l_poly, w_r_poly, w_4_poly];
+        if logup {
+            zw_polys.push(p_poly);
+        } else if lookups {
+            zw_polys.extend([h_1_poly, p_poly, table_poly]);
+        }
+        let w_zw = S::open(
+            commit_key,
+            &zw_polys,
             &(z_challenge * domain.group_gen),
             &mut transcript,
-        );
-        let w_zx_comm = commit_key.commit(&shifted_aggregate_witness)?;
+        )?;
 
         // Create Proof
         Ok(Proof {
@@ -547,13 +876,16 @@ impl Prover {
             z_comm: z_poly_commit,
             p_comm: p_poly_commit,
 
+            m_comm: m_poly_commit,
+            lookup_argument,
+
             t_1_comm: t_1_commit,
             t_2_comm: t_2_commit,
             t_3_comm: t_3_commit,
             t_4_comm: t_4_commit,
 
-            w_z_comm,
-            w_zw_comm: w_zx_comm,
+            w_z,
+            w_zw,
 
             evaluations: evaluations.proof,
         })
@@ -562,14 +894,18 @@ impl Prover {
     /// Proves a circuit is satisfied, then clears the witness variables
     /// If the circuit is not pre-processed, then the preprocessed circuit will
     /// also be computed.
-    pub fn prove(&mut self, commit_key: &CommitKey) -> Result<Proof, Error> {
+    pub fn prove(
+        &mut self,
+        commit_key: &S::CommitKey,
+    ) -> Result<Proof<S>, Error> {
         let prover_key: &ProverKey;
 
         if self.prover_key.is_none() {
             // Preprocess circuit
-            let prover_key = self.cs.preprocess_prover(
+            let (prover_key, _) = self.cs.preprocess_prover::<S>(
                 commit_key,
                 &mut self.preprocessed_transcript,
+                self.lookup_argument,
             )?;
             // Store preprocessed circuit and transcript in the Prover
             self.prover_key = Some(prover_key);
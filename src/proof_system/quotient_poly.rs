@@ -7,6 +7,7 @@
 use crate::{
     error::Error,
     fft::{EvaluationDomain, Polynomial},
+    plonkup::LookupArgument,
     proof_system::ProverKey,
 };
 use dusk_bls12_381::BlsScalar;
@@ -30,6 +31,8 @@ pub(crate) fn compute(
     t_poly: &Polynomial,
     h_1_poly: &Polynomial,
     h_2_poly: &Polynomial,
+    m_poly: &Polynomial,
+    lookup_argument: LookupArgument,
     public_inputs_poly: &Polynomial,
     (
         alpha,
@@ -65,36 +68,67 @@ pub(crate) fn compute(
     z_eval_4n.push(z_eval_4n[2]);
     z_eval_4n.push(z_eval_4n[3]);
 
-    // Compute 4n eval of p(X)
-    let mut p_eval_4n = domain_4n.coset_fft(p_poly);
-    p_eval_4n.push(p_eval_4n[0]);
-    p_eval_4n.push(p_eval_4n[1]);
-    p_eval_4n.push(p_eval_4n[2]);
-    p_eval_4n.push(p_eval_4n[3]);
-
-    // Compute 4n evals of table poly, t(x)
-    let mut t_eval_4n = domain_4n.coset_fft(t_poly);
-    t_eval_4n.push(t_eval_4n[0]);
-    t_eval_4n.push(t_eval_4n[1]);
-    t_eval_4n.push(t_eval_4n[2]);
-    t_eval_4n.push(t_eval_4n[3]);
-
-    // Compute f(x)
-    let f_eval_4n = domain_4n.coset_fft(f_poly);
-
-    // Compute 4n eval of h_1
-    let mut h_1_eval_4n = domain_4n.coset_fft(h_1_poly);
-    h_1_eval_4n.push(h_1_eval_4n[0]);
-    h_1_eval_4n.push(h_1_eval_4n[1]);
-    h_1_eval_4n.push(h_1_eval_4n[2]);
-    h_1_eval_4n.push(h_1_eval_4n[3]);
-
-    // Compute 4n eval of h_2
-    let mut h_2_eval_4n = domain_4n.coset_fft(h_2_poly);
-    h_2_eval_4n.push(h_2_eval_4n[0]);
-    h_2_eval_4n.push(h_2_eval_4n[1]);
-    h_2_eval_4n.push(h_2_eval_4n[2]);
-    h_2_eval_4n.push(h_2_eval_4n[3]);
+    // The lookup polynomials are only evaluated if the circuit uses lookups
+    let lookups = prover_key.lookups_enabled();
+    let lookup_evals_4n =
+        if lookups && lookup_argument == LookupArgument::Plookup {
+            // Compute 4n eval of p(X)
+            let mut p_eval_4n = domain_4n.coset_fft(p_poly);
+            p_eval_4n.push(p_eval_4n[0]);
+            p_eval_4n.push(p_eval_4n[1]);
+            p_eval_4n.push(p_eval_4n[2]);
+            p_eval_4n.push(p_eval_4n[3]);
+
+            // Compute 4n evals of table poly, t(x)
+            let mut t_eval_4n = domain_4n.coset_fft(t_poly);
+            t_eval_4n.push(t_eval_4n[0]);
+            t_eval_4n.push(t_eval_4n[1]);
+            t_eval_4n.push(t_eval_4n[2]);
+            t_eval_4n.push(t_eval_4n[3]);
+
+            // Compute f(x)
+            let f_eval_4n = domain_4n.coset_fft(f_poly);
+
+            // Compute 4n eval of h_1
+            let mut h_1_eval_4n = domain_4n.coset_fft(h_1_poly);
+            h_1_eval_4n.push(h_1_eval_4n[0]);
+            h_1_eval_4n.push(h_1_eval_4n[1]);
+            h_1_eval_4n.push(h_1_eval_4n[2]);
+            h_1_eval_4n.push(h_1_eval_4n[3]);
+
+            // Compute 4n eval of h_2
+            let mut h_2_eval_4n = domain_4n.coset_fft(h_2_poly);
+            h_2_eval_4n.push(h_2_eval_4n[0]);
+            h_2_eval_4n.push(h_2_eval_4n[1]);
+            h_2_eval_4n.push(h_2_eval_4n[2]);
+            h_2_eval_4n.push(h_2_eval_4n[3]);
+
+            Some((f_eval_4n, p_eval_4n, t_eval_4n, h_1_eval_4n, h_2_eval_4n))
+        } else {
+            None
+        };
+
+    // The logUp argument replaces the query and sorted polynomials with the
+    // multiplicities of the table rows, and `p(X)` holds its running sum
+    let logup_evals_4n = if lookups && lookup_argument == LookupArgument::LogUp
+    {
+        // Compute 4n eval of m(X)
+        let m_eval_4n = domain_4n.coset_fft(m_poly);
+
+        // Compute 4n eval of φ(X)
+        let mut phi_eval_4n = domain_4n.coset_fft(p_poly);
+        phi_eval_4n.push(phi_eval_4n[0]);
+        phi_eval_4n.push(phi_eval_4n[1]);
+        phi_eval_4n.push(phi_eval_4n[2]);
+        phi_eval_4n.push(phi_eval_4n[3]);
+
+        // Compute 4n evals of table poly, t(x)
+        let t_eval_4n = domain_4n.coset_fft(t_poly);
+
+        Some((m_eval_4n, phi_eval_4n, t_eval_4n))
+    } else {
+        None
+    };
 
     // Compute 4n evaluations of the wire polynomials
     let mut wl_eval_4n = domain_4n.coset_fft(w_l_poly);
@@ -129,11 +163,18 @@ pub(crate) fn compute(
         public_inputs_poly,
         zeta,
         (delta, epsilon),
-        &f_eval_4n,
-        &p_eval_4n,
-        &t_eval_4n,
-        &h_1_eval_4n,
-        &h_2_eval_4n,
+        lookup_evals_4n.as_ref().map(|(f, p, t, h_1, h_2)| {
+            (
+                f.as_slice(),
+                p.as_slice(),
+                t.as_slice(),
+                h_1.as_slice(),
+                h_2.as_slice(),
+            )
+        }),
+        logup_evals_4n
+            .as_ref()
+            .map(|(m, phi, t)| (m.as_slice(), phi.as_slice(), t.as_slice())),
     );
 
     let t_2 = compute_permutation_checks(
@@ -184,19 +225,26 @@ fn compute_circuit_satisfiability_equation(
     pi_poly: &Polynomial,
     zeta: &BlsScalar,
     (delta, epsilon): (&BlsScalar, &BlsScalar),
-    f_eval_4n: &[BlsScalar],
-    p_eval_4n: &[BlsScalar],
-    t_eval_4n: &[BlsScalar],
-    h_1_eval_4n: &[BlsScalar],
-    h_2_eval_4n: &[BlsScalar],
+    lookup_evals_4n: Option<(
+        &[BlsScalar],
+        &[BlsScalar],
+        &[BlsScalar],
+        &[BlsScalar],
+        &[BlsScalar],
+    )>,
+    logup_evals_4n: Option<(&[BlsScalar], &[BlsScalar], &[BlsScalar])>,
 ) -> Vec<BlsScalar> {
     let domain_4n = EvaluationDomain::new(4 * domain.size()).unwrap();
     let public_eval_4n = domain_4n.coset_fft(pi_poly);
 
-    let l1_eval_4n = domain_4n.coset_fft(&compute_first_lagrange_poly_scaled(
-        domain,
-        BlsScalar::one(),
-    ));
+    // The first lagrange polynomial is only needed by the lookup argument
+    let l1_eval_4n = match lookup_evals_4n {
+        Some(_) => domain_4n.coset_fft(&compute_first_lagrange_poly_scaled(
+            domain,
+            BlsScalar::one(),
+        )),
+        None => Vec::new(),
+    };
 
     #[cfg(not(feature = "std"))]
     let range = (0..domain_4n.size()).into_iter();
@@ -214,15 +262,6 @@ fn compute_circuit_satisfiability_equation(
             let wr_next = &wr_eval_4n[i + 4];
             let w4_next = &w4_eval_4n[i + 4];
             let pi = &public_eval_4n[i];
-            let p = &p_eval_4n[i];
-            let p_next = &p_eval_4n[i + 4];
-            let fi = &f_eval_4n[i];
-            let ti = &t_eval_4n[i];
-            let ti_next = &t_eval_4n[i + 4];
-            let h1 = &h_1_eval_4n[i];
-            let h2 = &h_2_eval_4n[i];
-            let h1_next = &h_1_eval_4n[i + 4];
-            let l1i = &l1_eval_4n[i];
 
             let a = prover_key.arithmetic.compute_quotient_i(i, wl, wr, wo, w4);
 
@@ -272,27 +311,58 @@ fn compute_circuit_satisfiability_equation(
                 w4_next,
             );
 
-            let f = prover_key.lookup.compute_quotient_i(
-                i,
-                lookup_challenge,
-                wl,
-                wr,
-                wo,
-                w4,
-                fi,
-                p,
-                p_next,
-                ti,
-                ti_next,
-                h1,
-                h1_next,
-                h2,
-                l1i,
-                (delta, epsilon),
-                zeta,
-            );
+            let f = match lookup_evals_4n {
+                Some((
+                    f_eval_4n,
+                    p_eval_4n,
+                    t_eval_4n,
+                    h_1_eval_4n,
+                    h_2_eval_4n,
+                )) => prover_key.lookup.compute_quotient_i(
+                    i,
+                    lookup_challenge,
+                    wl,
+                    wr,
+                    wo,
+                    w4,
+                    &prover_key.logic.q_c.1[i],
+                    &f_eval_4n[i],
+                    &p_eval_4n[i],
+                    &p_eval_4n[i + 4],
+                    &t_eval_4n[i],
+                    &t_eval_4n[i + 4],
+                    &h_1_eval_4n[i],
+                    &h_1_eval_4n[i + 4],
+                    &h_2_eval_4n[i],
+                    &l1_eval_4n[i],
+                    (delta, epsilon),
+                    zeta,
+                ),
+                None => BlsScalar::zero(),
+            };
+
+            let g = match logup_evals_4n {
+                Some((m_eval_4n, phi_eval_4n, t_eval_4n)) => {
+                    prover_key.lookup.compute_logup_quotient_i(
+                        i,
+                        lookup_challenge,
+                        wl,
+                        wr,
+                        wo,
+                        w4,
+                        &prover_key.logic.q_c.1[i],
+                        &m_eval_4n[i],
+                        &phi_eval_4n[i],
+                        &phi_eval_4n[i + 4],
+                        &t_eval_4n[i],
+                        epsilon,
+                        zeta,
+                    )
+                }
+                None => BlsScalar::zero(),
+            };
 
-            (a + pi) + b + c + d + e + f
+            (a + pi) + b + c + d + e + f + g
         })
         .collect();
     t
@@ -4,19 +4,24 @@
 //
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
-use crate::commitment_scheme::{CommitKey, OpeningKey};
+use crate::commitment_scheme::{CommitmentScheme, Kzg10};
 use crate::constraint_system::TurboComposer;
 use crate::error::Error;
+use crate::plonkup::LookupArgument;
 use crate::proof_system::widget::VerifierKey;
 use crate::proof_system::Proof;
+use core::marker::PhantomData;
 use dusk_bls12_381::BlsScalar;
 use merlin::Transcript;
 
 /// Abstraction structure designed verify [`Proof`]s.
+///
+/// The [`Proof`]s are verified with the [`CommitmentScheme`] `S`, KZG10 by
+/// default.
 #[allow(missing_debug_implementations)]
-pub struct Verifier {
+pub struct Verifier<S = Kzg10> {
     /// VerificationKey which is used to verify a specific PLONK circuit
-    pub verifier_key: Option<VerifierKey>,
+    pub verifier_key: Option<VerifierKey<S>>,
 
     pub(crate) cs: TurboComposer,
     /// Store the messages exchanged during the preprocessing stage
@@ -25,38 +30,80 @@ pub struct Verifier {
     /// is not copied, then the verification procedure will modify
     /// the transcript, making it unusable for future proofs.
     pub preprocessed_transcript: Transcript,
+
+    lookup_argument: LookupArgument,
+
+    scheme: PhantomData<S>,
+}
+
+impl<S> Verifier<S> {
+    /// Returns the number of gates in the circuit.
+    pub const fn gates(&self) -> u32 {
+        self.cs.gates()
+    }
+
+    /// Returns the [`LookupArgument`] the lookups of the circuit are proven
+    /// with.
+    pub const fn lookup_argument(&self) -> LookupArgument {
+        self.lookup_argument
+    }
+
+    /// Sets the [`LookupArgument`] the lookups of the circuit are proven
+    /// with.
+    ///
+    /// The argument is recorded in the [`VerifierKey`] when the circuit is
+    /// preprocessed, so it must be set before and match the one of the
+    /// [`Prover`](super::Prover).
+    pub fn set_lookup_argument(&mut self, argument: LookupArgument) {
+        self.lookup_argument = argument;
+    }
 }
 
-impl Default for Verifier {
-    fn default() -> Verifier {
-        Verifier::new(b"plonk")
+impl<S: CommitmentScheme> Default for Verifier<S> {
+    fn default() -> Verifier<S> {
+        Verifier::with_label(b"plonk")
     }
 }
 
 impl Verifier {
     /// Creates a new `Verifier` instance.
     pub fn new(label: &'static [u8]) -> Verifier {
+        Verifier::with_label(label)
+    }
+
+    /// Creates a new `Verifier` instance with some expected size.
+    pub fn with_size(label: &'static [u8], size: usize) -> Verifier {
+        Verifier::with_label_and_size(label, size)
+    }
+}
+
+impl<S: CommitmentScheme> Verifier<S> {
+    /// Creates a new `Verifier` instance for the [`CommitmentScheme`] `S`.
+    pub fn with_label(label: &'static [u8]) -> Verifier<S> {
         Verifier {
             verifier_key: None,
             cs: TurboComposer::new(),
             preprocessed_transcript: Transcript::new(label),
+            lookup_argument: LookupArgument::default(),
+            scheme: PhantomData,
         }
     }
 
-    /// Creates a new `Verifier` instance with some expected size.
-    pub fn with_size(label: &'static [u8], size: usize) -> Verifier {
+    /// Creates a new `Verifier` instance for the [`CommitmentScheme`] `S`
+    /// with some expected size.
+    pub fn with_label_and_size(
+        label: &'static [u8],
+        size: usize,
+    ) -> Verifier<S> {
         Verifier {
             verifier_key: None,
             cs: TurboComposer::with_size(size),
             preprocessed_transcript: Transcript::new(label),
+            lookup_argument: LookupArgument::default(),
+            scheme: PhantomData,
         }
     }
 
-    /// Returns the number of gates in the circuit.
-    pub const fn gates(&self) -> u32 {
-        self.cs.gates()
-    }
-
     /// Mutable borrow of the [`TurboComposer`].
     pub fn composer_mut(&mut self) -> &mut TurboComposer {
         &mut self.cs
@@ -65,10 +112,14 @@ impl Verifier {
     /// Preprocess a circuit to obtain a [`VerifierKey`] and a circuit
     /// descriptor so that the `Verifier` instance can verify [`Proof`]s
     /// for this circuit descriptor instance.
-    pub fn preprocess(&mut self, commit_key: &CommitKey) -> Result<(), Error> {
-        let vk = self.cs.preprocess_verifier(
+    pub fn preprocess(
+        &mut self,
+        commit_key: &S::CommitKey,
+    ) -> Result<(), Error> {
+        let vk = self.cs.preprocess_verifier::<S>(
             commit_key,
             &mut self.preprocessed_transcript,
+            self.lookup_argument,
         )?;
 
         self.verifier_key = Some(vk);
@@ -84,8 +135,8 @@ impl Verifier {
     /// Verifies a [`Proof`].
     pub fn verify(
         &self,
-        proof: &Proof,
-        opening_key: &OpeningKey,
+        proof: &Proof<S>,
+        opening_key: &S::OpeningKey,
         public_inputs: &[BlsScalar],
     ) -> Result<(), Error> {
         let mut cloned_transcript = self.preprocessed_transcript.clone();
@@ -4,7 +4,9 @@
 //
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
-use crate::commitment_scheme::Commitment;
+use crate::commitment_scheme::{Commitment, CommitmentScheme, Kzg10};
+use crate::plonkup::LookupArgument;
+use core::marker::PhantomData;
 use dusk_bytes::{DeserializableSlice, Serializable};
 use parity_scale_codec::{Decode, Encode};
 
@@ -19,10 +21,16 @@ pub mod range;
 ///
 /// This structure is used by the Verifier in order to verify a
 /// [`Proof`](super::Proof).
+///
+/// The key holds the commitments to the selector, sigma and lookup table
+/// polynomials of the circuit computed with the [`CommitmentScheme`] `S`,
+/// KZG10 by default, so it can only verify proofs of that same scheme.
 #[derive(Debug, PartialEq, Eq, Copy, Clone, Decode, Encode)]
-pub struct VerifierKey {
-    /// Circuit size (not padded to a power of two).
+pub struct VerifierKey<S = Kzg10> {
+    /// Circuit size, padded to a power of two on preprocessing.
     pub(crate) n: u32,
+    /// Argument the lookups of the circuit are proven with
+    pub(crate) lookup_argument: LookupArgument,
     /// VerifierKey for arithmetic gates
     pub(crate) arithmetic: arithmetic::VerifierKey,
     /// VerifierKey for logic gates
@@ -37,9 +45,14 @@ pub struct VerifierKey {
     pub(crate) lookup: lookup::VerifierKey,
     /// VerifierKey for permutation checks
     pub(crate) permutation: permutation::VerifierKey,
+    /// Commitment scheme the commitments of the key were computed with
+    #[codec(skip)]
+    pub(crate) scheme: PhantomData<S>,
 }
 
-impl Serializable<{ 20 * Commitment::SIZE + u64::SIZE }> for VerifierKey {
+impl<S> Serializable<{ 21 * Commitment::SIZE + u64::SIZE + 1 }>
+    for VerifierKey<S>
+{
     type Error = dusk_bytes::Error;
 
     #[allow(unused_must_use)]
@@ -49,6 +62,7 @@ impl Serializable<{ 20 * Commitment::SIZE + u64::SIZE }> for VerifierKey {
         let mut writer = &mut buff[..];
 
         writer.write(&(self.n as u64).to_bytes());
+        writer.write(&[self.lookup_argument.to_byte()]);
         writer.write(&self.arithmetic.q_m.to_bytes());
         writer.write(&self.arithmetic.q_l.to_bytes());
         writer.write(&self.arithmetic.q_r.to_bytes());
@@ -69,15 +83,28 @@ impl Serializable<{ 20 * Commitment::SIZE + u64::SIZE }> for VerifierKey {
         writer.write(&self.lookup.table_2.to_bytes());
         writer.write(&self.lookup.table_3.to_bytes());
         writer.write(&self.lookup.table_4.to_bytes());
+        writer.write(&self.lookup.table_id.to_bytes());
 
         buff
     }
 
-    fn from_bytes(buf: &[u8; Self::SIZE]) -> Result<VerifierKey, Self::Error> {
+    fn from_bytes(buf: &[u8; Self::SIZE]) -> Result<Self, Self::Error> {
         let mut buffer = &buf[..];
 
+        // Preprocessed circuits are always padded to a power of two
+        let n = u64::from_reader(&mut buffer)?;
+        if !n.is_power_of_two() || n > u32::MAX as u64 {
+            return Err(dusk_bytes::Error::InvalidData);
+        }
+
+        let (argument, mut buffer) = buffer.split_first().unwrap();
+        let lookup_argument = LookupArgument::from_byte(*argument)
+            .ok_or(dusk_bytes::Error::InvalidData)?;
+
         Ok(Self::from_polynomial_commitments(
-            u64::from_reader(&mut buffer)? as usize,
+            n as usize,
+            lookup_argument,
+            Commitment::from_reader(&mut buffer)?,
             Commitment::from_reader(&mut buffer)?,
             Commitment::from_reader(&mut buffer)?,
             Commitment::from_reader(&mut buffer)?,
@@ -102,17 +129,33 @@ impl Serializable<{ 20 * Commitment::SIZE + u64::SIZE }> for VerifierKey {
     }
 }
 
-impl VerifierKey {
+impl<S> VerifierKey<S> {
     /// Returns the Circuit size padded to the next power of two.
     pub const fn padded_gates(&self) -> u32 {
         self.n.next_power_of_two()
     }
 
+    /// Returns `true` if the circuit uses the lookup argument.
+    ///
+    /// Lookups are enabled when at least one gate of the circuit is a lookup
+    /// gate, in which case the commitment to the lookup selector is not the
+    /// identity.
+    pub fn lookups_enabled(&self) -> bool {
+        self.lookup.q_lookup != Commitment::default()
+    }
+
+    /// Returns the [`LookupArgument`] the lookups of the circuit are proven
+    /// with.
+    pub const fn lookup_argument(&self) -> LookupArgument {
+        self.lookup_argument
+    }
+
     /// Constructs a [`VerifierKey`] from the widget VerifierKey's that are
     /// constructed based on the selector polynomial commitments and the
     /// sigma polynomial commitments.
     pub(crate) fn from_polynomial_commitments(
         n: usize,
+        lookup_argument: LookupArgument,
         q_m: Commitment,
         q_l: Commitment,
         q_r: Commitment,
@@ -133,7 +176,8 @@ impl VerifierKey {
         table_2: Commitment,
         table_3: Commitment,
         table_4: Commitment,
-    ) -> VerifierKey {
+        table_id: Commitment,
+    ) -> Self {
         let arithmetic = arithmetic::VerifierKey {
             q_m,
             q_l,
@@ -161,6 +205,7 @@ impl VerifierKey {
             table_2,
             table_3,
             table_4,
+            table_id,
         };
 
         let permutation = permutation::VerifierKey {
@@ -174,6 +219,7 @@ impl VerifierKey {
 
         VerifierKey {
             n,
+            lookup_argument,
             arithmetic,
             logic,
             range,
@@ -181,6 +227,7 @@ impl VerifierKey {
             variable_base,
             lookup,
             permutation,
+            scheme: PhantomData,
         }
     }
 }
@@ -196,7 +243,7 @@ use merlin::Transcript;
 use sp_std::vec;
 use sp_std::vec::Vec;
 
-impl VerifierKey {
+impl<S> VerifierKey<S> {
     /// Adds the circuit description to the transcript
     pub(crate) fn seed_transcript(&self, transcript: &mut Transcript) {
         transcript.append_commitment(b"q_m", &self.arithmetic.q_m);
@@ -238,6 +285,8 @@ impl VerifierKey {
 pub struct ProverKey {
     /// Circuit size
     pub(crate) n: usize,
+    /// Argument the lookups of the circuit are proven with
+    pub(crate) lookup_argument: LookupArgument,
     /// ProverKey for arithmetic gate
     pub(crate) arithmetic: arithmetic::ProverKey,
     /// ProverKey for logic gate
@@ -258,6 +307,14 @@ pub struct ProverKey {
     // in their evaluation phase and divide by the quotient
     // polynomial without having to perform IFFT
     pub(crate) v_h_coset_4n: Evaluations,
+    /// Fingerprint of the shape of the circuit the key was preprocessed from
+    pub(crate) fingerprint: [u8; 32],
+    /// Digest of the wires of the circuit the key was preprocessed from
+    pub(crate) wires_digest: [u8; 32],
+    /// Number of gates of the circuit before it's padded
+    pub(crate) gates: u32,
+    /// Positions of the public inputs of the circuit
+    pub(crate) public_input_indexes: Vec<u32>,
 }
 
 impl ProverKey {
@@ -269,7 +326,7 @@ impl ProverKey {
     /// Returns the number of [`MultiSet`]s contained in a ProverKey.
     const fn num_multiset() -> usize {
         // FIXME https://github.com/dusk-network/plonk/issues/581
-        4
+        5
     }
 
     /// Returns the number of [`Evaluations`] contained in a ProverKey.
@@ -300,13 +357,27 @@ impl ProverKey {
             (Self::num_polys() * poly_size
                 + evals_size * Self::num_evals()
                 + multiset_size * Self::num_multiset()
-                + 17 * u64::SIZE) as usize
+                + 19 * u64::SIZE
+                + 1
+                + self.fingerprint.len()
+                + self.wires_digest.len()
+                + 2 * u32::SIZE
+                + self.public_input_indexes.len() * u32::SIZE)
+                as usize
         ];
 
         let mut writer = &mut bytes[..];
         writer.write(&(self.n as u64).to_bytes());
         // Write Evaluation len in bytes.
         writer.write(&(evals_size as u64).to_bytes());
+        writer.write(&[self.lookup_argument.to_byte()]);
+        writer.write(&self.fingerprint);
+        writer.write(&self.wires_digest);
+        writer.write(&self.gates.to_bytes());
+        writer.write(&(self.public_input_indexes.len() as u32).to_bytes());
+        self.public_input_indexes.iter().for_each(|pos| {
+            writer.write(&pos.to_bytes());
+        });
 
         // Arithmetic
         writer.write(&(self.arithmetic.q_m.0.len() as u64).to_bytes());
@@ -391,6 +462,12 @@ impl ProverKey {
         writer.write(&(self.lookup.table_4.1).to_var_bytes());
         writer.write(&(self.lookup.table_4.2).to_var_bytes());
 
+        writer.write(&(self.lookup.table_id.0.len() as u64).to_bytes());
+        writer.write(&(self.lookup.table_id.0).to_var_bytes());
+        writer.write(&(self.lookup.table_id.1.len() as u64).to_bytes());
+        writer.write(&(self.lookup.table_id.1).to_var_bytes());
+        writer.write(&(self.lookup.table_id.2).to_var_bytes());
+
         // Permutation
         writer.write(&(self.permutation.left_sigma.0.len() as u64).to_bytes());
         writer.write(&self.permutation.left_sigma.0.to_var_bytes());
@@ -421,6 +498,35 @@ impl ProverKey {
         let mut buffer = bytes;
         let n = u64::from_reader(&mut buffer)? as usize;
         let evaluations_size = u64::from_reader(&mut buffer)? as usize;
+
+        let (argument, b) =
+            buffer.split_first().ok_or(Error::NotEnoughBytes)?;
+        let lookup_argument = LookupArgument::from_byte(*argument)
+            .ok_or(dusk_bytes::Error::InvalidData)?;
+        buffer = b;
+
+        let mut fingerprint = [0u8; 32];
+        if buffer.len() < fingerprint.len() {
+            return Err(Error::NotEnoughBytes);
+        }
+        let (a, b) = buffer.split_at(fingerprint.len());
+        fingerprint.copy_from_slice(a);
+        buffer = b;
+
+        let mut wires_digest = [0u8; 32];
+        if buffer.len() < wires_digest.len() {
+            return Err(Error::NotEnoughBytes);
+        }
+        let (a, b) = buffer.split_at(wires_digest.len());
+        wires_digest.copy_from_slice(a);
+        buffer = b;
+
+        let gates = u32::from_reader(&mut buffer)?;
+        let public_inputs = u32::from_reader(&mut buffer)?;
+        let public_input_indexes = (0..public_inputs)
+            .map(|_| u32::from_reader(&mut buffer))
+            .collect::<Result<Vec<u32>, _>>()?;
+
         // let domain = crate::fft::EvaluationDomain::new(4 * size)?;
         // TODO: By creating this we can avoid including the
         // EvaluationDomain inside Evaluations. See:
@@ -536,6 +642,11 @@ impl ProverKey {
         let table_4_evals = evals_from_reader(&mut buffer)?;
         let table_4 = (table_4_multiset, table_4_poly, table_4_evals);
 
+        let table_id_multiset = multiset_from_reader(&mut buffer)?;
+        let table_id_poly = poly_from_reader(&mut buffer)?;
+        let table_id_evals = evals_from_reader(&mut buffer)?;
+        let table_id = (table_id_multiset, table_id_poly, table_id_evals);
+
         let left_sigma_poly = poly_from_reader(&mut buffer)?;
         let left_sigma_evals = evals_from_reader(&mut buffer)?;
         let left_sigma = (left_sigma_poly, left_sigma_evals);
@@ -598,10 +709,12 @@ impl ProverKey {
             table_2,
             table_3,
             table_4,
+            table_id,
         };
 
         let prover_key = ProverKey {
             n,
+            lookup_argument,
             arithmetic,
             logic,
             range,
@@ -610,11 +723,99 @@ impl ProverKey {
             lookup,
             permutation,
             v_h_coset_4n,
+            fingerprint,
+            wires_digest,
+            gates,
+            public_input_indexes,
         };
 
         Ok(prover_key)
     }
 
+    /// Returns `true` if the circuit uses the lookup argument, that is, if at
+    /// least one of its gates is a lookup gate.
+    ///
+    /// Proofs of circuits without lookups skip all of the plonkup work and
+    /// don't carry any of its commitments or evaluations.
+    pub fn lookups_enabled(&self) -> bool {
+        !self.lookup.q_lookup.0.is_zero()
+    }
+
+    /// Returns the Circuit size padded to the next power of two, which is the
+    /// size of the domain the circuit was preprocessed with.
+    pub const fn padded_gates(&self) -> u32 {
+        self.n as u32
+    }
+
+    /// Returns the [`LookupArgument`] the lookups of the circuit are proven
+    /// with.
+    pub const fn lookup_argument(&self) -> LookupArgument {
+        self.lookup_argument
+    }
+
+    /// Returns the fingerprint of the shape of the circuit the key was
+    /// preprocessed from.
+    pub const fn fingerprint(&self) -> &[u8; 32] {
+        &self.fingerprint
+    }
+
+    /// Returns the digest of the witnesses assigned to the wires and of the
+    /// public input positions of the circuit the key was preprocessed from.
+    pub const fn wires_digest(&self) -> &[u8; 32] {
+        &self.wires_digest
+    }
+
+    /// Returns the number of gates of the circuit the key was preprocessed
+    /// from, before it was padded.
+    pub const fn gates(&self) -> u32 {
+        self.gates
+    }
+
+    /// Returns the positions of the public inputs of the circuit the key was
+    /// preprocessed from.
+    pub fn public_input_indexes(&self) -> &[u32] {
+        &self.public_input_indexes
+    }
+
+    /// Computes the [`VerifierKey`] of the circuit by committing to the
+    /// selector, sigma and lookup table polynomials of the key with the
+    /// [`CommitmentScheme`] `S`.
+    ///
+    /// The `commit_key` must be the one the circuit was preprocessed with for
+    /// the result to match the [`VerifierKey`] obtained on preprocessing.
+    pub fn verifier_key<S: CommitmentScheme>(
+        &self,
+        commit_key: &S::CommitKey,
+    ) -> Result<VerifierKey<S>, Error> {
+        let commit = |poly: &Polynomial| S::commit(commit_key, poly);
+
+        Ok(VerifierKey::from_polynomial_commitments(
+            self.n,
+            self.lookup_argument,
+            commit(&self.arithmetic.q_m.0)?,
+            commit(&self.arithmetic.q_l.0)?,
+            commit(&self.arithmetic.q_r.0)?,
+            commit(&self.arithmetic.q_o.0)?,
+            commit(&self.arithmetic.q_4.0)?,
+            commit(&self.arithmetic.q_c.0)?,
+            commit(&self.arithmetic.q_arith.0)?,
+            commit(&self.logic.q_logic.0)?,
+            commit(&self.range.q_range.0)?,
+            commit(&self.fixed_base.q_fixed_group_add.0)?,
+            commit(&self.variable_base.q_variable_group_add.0)?,
+            commit(&self.lookup.q_lookup.0)?,
+            commit(&self.permutation.left_sigma.0)?,
+            commit(&self.permutation.right_sigma.0)?,
+            commit(&self.permutation.out_sigma.0)?,
+            commit(&self.permutation.fourth_sigma.0)?,
+            commit(&self.lookup.table_1.1)?,
+            commit(&self.lookup.table_2.1)?,
+            commit(&self.lookup.table_3.1)?,
+            commit(&self.lookup.table_4.1)?,
+            commit(&self.lookup.table_id.1)?,
+        ))
+    }
+
     pub(crate) fn v_h_coset_4n(&self) -> &Evaluations {
         &self.v_h_coset_4n
     }
@@ -628,7 +829,7 @@ mod test {
     use crate::fft::{EvaluationDomain, Evaluations, Polynomial};
     use crate::plonkup::MultiSet;
     use dusk_bls12_381::BlsScalar;
-    use rand_core::OsRng;
+    use rand_core::{OsRng, RngCore};
     use sp_std::vec::Vec;
 
     fn rand_poly_eval(n: usize) -> (Polynomial, Evaluations) {
@@ -684,9 +885,18 @@ mod test {
         let table_2 = rand_multiset(n);
         let table_3 = rand_multiset(n);
         let table_4 = rand_multiset(n);
+        let table_id = rand_multiset(n);
 
         let v_h_coset_4n = rand_evaluations(n);
 
+        let mut fingerprint = [0u8; 32];
+        OsRng.fill_bytes(&mut fingerprint);
+        let mut wires_digest = [0u8; 32];
+        OsRng.fill_bytes(&mut wires_digest);
+
+        let gates = n as u32 - 3;
+        let public_input_indexes = vec![1, 4, n as u32 - 4];
+
         let arithmetic = arithmetic::ProverKey {
             q_m,
             q_l: q_l.clone(),
@@ -710,6 +920,7 @@ mod test {
             table_2,
             table_3,
             table_4,
+            table_id,
         };
 
         let fixed_base = ecc::scalar_mul::fixed_base::ProverKey {
@@ -733,6 +944,7 @@ mod test {
 
         let prover_key = ProverKey {
             n,
+            lookup_argument: LookupArgument::LogUp,
             arithmetic,
             logic,
             fixed_base,
@@ -741,6 +953,10 @@ mod test {
             lookup,
             permutation,
             v_h_coset_4n,
+            fingerprint,
+            wires_digest,
+            gates,
+            public_input_indexes,
         };
 
         let prover_key_bytes = prover_key.to_var_bytes();
@@ -782,6 +998,7 @@ mod test {
         let table_2 = Commitment(G1Affine::generator());
         let table_3 = Commitment(G1Affine::generator());
         let table_4 = Commitment(G1Affine::generator());
+        let table_id = Commitment(G1Affine::generator());
 
         let arithmetic = arithmetic::VerifierKey {
             q_m,
@@ -803,6 +1020,7 @@ mod test {
             table_2,
             table_3,
             table_4,
+            table_id,
         };
 
         let fixed_base = ecc::scalar_mul::fixed_base::VerifierKey {
@@ -821,8 +1039,9 @@ mod test {
             fourth_sigma,
         };
 
-        let verifier_key = VerifierKey {
+        let verifier_key: VerifierKey = VerifierKey {
             n,
+            lookup_argument: LookupArgument::LogUp,
             arithmetic,
             logic,
             range,
@@ -830,6 +1049,7 @@ mod test {
             variable_base,
             lookup,
             permutation,
+            scheme: PhantomData,
         };
 
         let verifier_key_bytes = verifier_key.to_bytes();
@@ -14,15 +14,17 @@ pub use verifierkey::VerifierKey;
 // enacted below
 use dusk_bls12_381::BlsScalar;
 
-fn compress(
+pub(crate) fn compress(
     w_l: BlsScalar,
     w_r: BlsScalar,
     w_o: BlsScalar,
     w_4: BlsScalar,
+    table_id: BlsScalar,
     zeta: BlsScalar,
 ) -> BlsScalar {
     let zeta_sq = zeta.square();
     let zeta_cu = zeta_sq * zeta;
+    let zeta_qu = zeta_sq.square();
 
     let a = w_l;
 
@@ -32,5 +34,7 @@ fn compress(
 
     let d = w_4 * zeta_cu;
 
-    a + b + c + d
+    let e = table_id * zeta_qu;
+
+    a + b + c + d + e
 }
@@ -17,6 +17,7 @@ pub struct ProverKey {
     pub(crate) table_2: (MultiSet, Polynomial, Evaluations),
     pub(crate) table_3: (MultiSet, Polynomial, Evaluations),
     pub(crate) table_4: (MultiSet, Polynomial, Evaluations),
+    pub(crate) table_id: (MultiSet, Polynomial, Evaluations),
 }
 
 impl ProverKey {
@@ -29,6 +30,7 @@ impl ProverKey {
         w_r_i: &BlsScalar,
         w_o_i: &BlsScalar,
         w_4_i: &BlsScalar,
+        q_c_i: &BlsScalar,
         f_i: &BlsScalar,
         p_i: &BlsScalar,
         p_i_next: &BlsScalar,
@@ -48,11 +50,14 @@ impl ProverKey {
         let epsilon_one_plus_delta = epsilon * one_plus_delta;
 
         // q_lookup(X) * (a(X) + zeta * b(X) + (zeta^2 * c(X)) + (zeta^3 * d(X)
-        // - f(X))) * α_1
+        // + (zeta^4 * q_c(X)) - f(X))) * α_1
+        //
+        // The constant selector of a lookup gate holds the id of the table it
+        // queries
         let a = {
             let q_lookup_i = self.q_lookup.1[index];
             let compressed_tuple =
-                compress(*w_l_i, *w_r_i, *w_o_i, *w_4_i, *zeta);
+                compress(*w_l_i, *w_r_i, *w_o_i, *w_4_i, *q_c_i, *zeta);
 
             q_lookup_i * (compressed_tuple - f_i) * lookup_separation_challenge
         };
@@ -80,6 +85,37 @@ impl ProverKey {
         a + b + c + d
     }
 
+    /// Compute identity check for lookup gates with the logUp argument
+    pub(crate) fn compute_logup_quotient_i(
+        &self,
+        index: usize,
+        lookup_separation_challenge: &BlsScalar,
+        w_l_i: &BlsScalar,
+        w_r_i: &BlsScalar,
+        w_o_i: &BlsScalar,
+        w_4_i: &BlsScalar,
+        q_c_i: &BlsScalar,
+        m_i: &BlsScalar,
+        phi_i: &BlsScalar,
+        phi_i_next: &BlsScalar,
+        t_i: &BlsScalar,
+        epsilon: &BlsScalar,
+        zeta: &BlsScalar,
+    ) -> BlsScalar {
+        let q_lookup_i = self.q_lookup.1[index];
+
+        // The query is compressed directly from the wires, since the rows
+        // that are not lookup gates are cancelled by q_lookup(X)
+        let f_i =
+            epsilon + compress(*w_l_i, *w_r_i, *w_o_i, *w_4_i, *q_c_i, *zeta);
+        let t_i = epsilon + t_i;
+
+        // ((φ(Xω) − φ(X)) * (ε + f(X)) * (ε + t(X)) − q_lookup(X) * (ε +
+        // t(X)) + m(X) * (ε + f(X))) * α_1
+        ((phi_i_next - phi_i) * f_i * t_i - q_lookup_i * t_i + m_i * f_i)
+            * lookup_separation_challenge
+    }
+
     /// Compute linearisation for lookup gates
     pub(crate) fn compute_linearisation(
         &self,
@@ -87,6 +123,7 @@ impl ProverKey {
         b_eval: &BlsScalar,
         c_eval: &BlsScalar,
         d_eval: &BlsScalar,
+        q_c_eval: &BlsScalar,
         f_eval: &BlsScalar,
         t_eval: &BlsScalar,
         t_next_eval: &BlsScalar,
@@ -104,14 +141,18 @@ impl ProverKey {
         let l_sep_3 = l_sep_2 * lookup_separation_challenge;
         let zeta_sq = zeta * zeta;
         let zeta_cu = zeta * zeta_sq;
+        let zeta_qu = zeta_sq * zeta_sq;
         let one_plus_delta = delta + BlsScalar::one();
         let epsilon_one_plus_delta = epsilon * one_plus_delta;
 
         //
         // - q_lookup(X) * f_eval * lookup_separation_challenge
         let a = {
-            let a_0 =
-                a_eval + zeta * b_eval + zeta_sq * c_eval + zeta_cu * d_eval;
+            let a_0 = a_eval
+                + zeta * b_eval
+                + zeta_sq * c_eval
+                + zeta_cu * d_eval
+                + zeta_qu * q_c_eval;
 
             &self.q_lookup.0 * &((a_0 - f_eval) * lookup_separation_challenge)
         };
@@ -141,4 +182,39 @@ impl ProverKey {
 
         r
     }
+
+    /// Compute linearisation for lookup gates with the logUp argument
+    pub(crate) fn compute_logup_linearisation(
+        &self,
+        a_eval: &BlsScalar,
+        b_eval: &BlsScalar,
+        c_eval: &BlsScalar,
+        d_eval: &BlsScalar,
+        q_c_eval: &BlsScalar,
+        t_eval: &BlsScalar,
+        phi_poly: &Polynomial,
+        m_poly: &Polynomial,
+        epsilon: &BlsScalar,
+        zeta: &BlsScalar,
+        lookup_separation_challenge: &BlsScalar,
+    ) -> Polynomial {
+        let f_eval = epsilon
+            + compress(*a_eval, *b_eval, *c_eval, *d_eval, *q_c_eval, *zeta);
+        let t_eval = epsilon + t_eval;
+
+        // − φ(X) * (ε + f_bar) * (ε + t_bar) * α_1
+        let a = phi_poly * &(-f_eval * t_eval * lookup_separation_challenge);
+
+        // − q_lookup(X) * (ε + t_bar) * α_1
+        let b = &self.q_lookup.0 * &(-t_eval * lookup_separation_challenge);
+
+        // m(X) * (ε + f_bar) * α_1
+        let c = m_poly * &(f_eval * lookup_separation_challenge);
+
+        let mut r = a;
+        r += &b;
+        r += &c;
+
+        r
+    }
 }
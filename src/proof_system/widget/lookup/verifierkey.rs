@@ -4,6 +4,7 @@
 //
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
+use super::compress;
 use crate::commitment_scheme::Commitment;
 use parity_scale_codec::{Decode, Encode};
 
@@ -14,6 +15,7 @@ pub struct VerifierKey {
     pub(crate) table_2: Commitment,
     pub(crate) table_3: Commitment,
     pub(crate) table_4: Commitment,
+    pub(crate) table_id: Commitment,
 }
 
 use crate::proof_system::linearisation_poly::ProofEvaluations;
@@ -39,14 +41,16 @@ impl VerifierKey {
         let l_sep_3 = lookup_separation_challenge * l_sep_2;
         let zeta_sq = zeta * zeta;
         let zeta_cu = zeta * zeta_sq;
+        let zeta_qu = zeta_sq * zeta_sq;
 
-        // (a_eval + zeta*b_eval + zeta^2*c_eval + zeta^3d_eval - f_eval) *
-        // q_lookup * alpha_1
+        // (a_eval + zeta*b_eval + zeta^2*c_eval + zeta^3d_eval +
+        // zeta^4*q_c_eval - f_eval) * q_lookup * alpha_1
         let a = {
             let a_0 = evaluations.a_eval
                 + zeta * evaluations.b_eval
                 + zeta_sq * evaluations.c_eval
-                + zeta_cu * evaluations.d_eval;
+                + zeta_cu * evaluations.d_eval
+                + zeta_qu * evaluations.q_c_eval;
             let a_1 = evaluations.f_eval;
 
             (a_0 - a_1) * lookup_separation_challenge
@@ -88,4 +92,41 @@ impl VerifierKey {
         scalars.push(d);
         points.push(p_comm);
     }
+
+    pub(crate) fn compute_logup_linearisation_commitment(
+        &self,
+        lookup_separation_challenge: &BlsScalar,
+        scalars: &mut Vec<BlsScalar>,
+        points: &mut Vec<G1Affine>,
+        evaluations: &ProofEvaluations,
+        epsilon: &BlsScalar,
+        zeta: &BlsScalar,
+        phi_comm: G1Affine,
+        m_comm: G1Affine,
+    ) {
+        let f_eval = epsilon
+            + compress(
+                evaluations.a_eval,
+                evaluations.b_eval,
+                evaluations.c_eval,
+                evaluations.d_eval,
+                evaluations.q_c_eval,
+                *zeta,
+            );
+        let t_eval = epsilon + evaluations.table_eval;
+
+        //
+        // - (epsilon + f_eval) * (epsilon + t_eval) * alpha_1 * phi
+        scalars.push(-f_eval * t_eval * lookup_separation_challenge);
+        points.push(phi_comm);
+
+        //
+        // - (epsilon + t_eval) * alpha_1 * q_lookup
+        scalars.push(-t_eval * lookup_separation_challenge);
+        points.push(self.q_lookup.0);
+
+        // (epsilon + f_eval) * alpha_1 * m
+        scalars.push(f_eval * lookup_separation_challenge);
+        points.push(m_comm);
+    }
 }
@@ -0,0 +1,226 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_bls12_381::G1Affine;
+use dusk_plonk::commitment_scheme::{Commitment, PointEncoding, Polynomial};
+use dusk_plonk::prelude::*;
+use rand_core::OsRng;
+
+const ZCASH_RESPONSE: &[u8] =
+    include_bytes!("fixtures/ceremony/zcash_response_8.params");
+const ZCASH_CHALLENGE: &[u8] =
+    include_bytes!("fixtures/ceremony/zcash_challenge_8.params");
+const ETHEREUM_TRANSCRIPT: &[u8] =
+    include_bytes!("fixtures/ceremony/ethereum_transcript.json");
+
+// Secrets the fixtures were generated with
+const ZCASH_TAU: u64 = 0x1f1f;
+const ETHEREUM_TAU: u64 = 0x2a2a;
+
+// Size of the hash prefixing a Zcash accumulator
+const ZCASH_HASH_SIZE: usize = 64;
+
+// Checks the parameters hold the powers of `tau` by committing to a
+// polynomial of maximum degree and evaluating it at `tau` in the clear
+fn assert_powers_of(pp: &PublicParameters, tau: u64) {
+    let coeffs: Vec<BlsScalar> = (1..=pp.max_degree() as u64 + 1)
+        .map(BlsScalar::from)
+        .collect();
+    let poly = Polynomial::from_coefficients_vec(coeffs);
+
+    let commitment = pp
+        .commit_key()
+        .commit(&poly)
+        .expect("Failed to commit to the polynomial");
+    let eval = poly.evaluate(&BlsScalar::from(tau));
+
+    assert_eq!(commitment, Commitment::from(G1Affine::generator() * eval));
+}
+
+#[test]
+fn import_zcash_accumulator() {
+    for (bytes, encoding) in [
+        (ZCASH_RESPONSE, PointEncoding::Compressed),
+        (ZCASH_CHALLENGE, PointEncoding::Uncompressed),
+    ] {
+        let pp = PublicParameters::from_zcash_accumulator(
+            bytes, 8, encoding, 14, OsRng,
+        )
+        .expect("Failed to import the accumulator");
+
+        assert_eq!(pp.max_degree(), 14);
+        assert_powers_of(&pp, ZCASH_TAU);
+    }
+
+    assert!(matches!(
+        PublicParameters::from_zcash_accumulator(
+            ZCASH_RESPONSE,
+            8,
+            PointEncoding::Compressed,
+            15,
+            OsRng
+        ),
+        Err(Error::CeremonyNotEnoughPowers)
+    ));
+    assert!(matches!(
+        PublicParameters::from_zcash_accumulator(
+            &ZCASH_RESPONSE[..900],
+            8,
+            PointEncoding::Compressed,
+            4,
+            OsRng
+        ),
+        Err(Error::NotEnoughBytes)
+    ));
+}
+
+#[test]
+fn import_zcash_accumulator_inconsistent() {
+    // Replace tau * G2 with the generator of G2
+    let mut bytes = ZCASH_RESPONSE.to_vec();
+    let g2_offset = ZCASH_HASH_SIZE + 15 * 48;
+    bytes.copy_within(g2_offset..g2_offset + 96, g2_offset + 96);
+
+    assert!(matches!(
+        PublicParameters::from_zcash_accumulator(
+            &bytes,
+            8,
+            PointEncoding::Compressed,
+            8,
+            OsRng
+        ),
+        Err(Error::CeremonyInconsistentPowers)
+    ));
+}
+
+#[test]
+fn import_zcash_accumulator_tampered_power() {
+    // Replace tau^5 * G1 with tau^4 * G1
+    let mut bytes = ZCASH_RESPONSE.to_vec();
+    let offset = ZCASH_HASH_SIZE + 4 * 48;
+    bytes.copy_within(offset..offset + 48, offset + 48);
+
+    assert!(matches!(
+        PublicParameters::from_zcash_accumulator(
+            &bytes,
+            8,
+            PointEncoding::Compressed,
+            8,
+            OsRng
+        ),
+        Err(Error::PublicParametersPowerMismatch { index: 5 })
+    ));
+}
+
+#[test]
+fn import_ethereum_ceremony() {
+    let pp = PublicParameters::from_ethereum_ceremony(
+        ETHEREUM_TRANSCRIPT,
+        10,
+        OsRng,
+    )
+    .expect("Failed to import the transcript");
+
+    assert_eq!(pp.max_degree(), 10);
+    assert_powers_of(&pp, ETHEREUM_TAU);
+
+    assert!(matches!(
+        PublicParameters::from_ethereum_ceremony(
+            ETHEREUM_TRANSCRIPT,
+            16,
+            OsRng
+        ),
+        Err(Error::CeremonyNotEnoughPowers)
+    ));
+    assert!(matches!(
+        PublicParameters::from_ethereum_ceremony(
+            &ETHEREUM_TRANSCRIPT[..100],
+            4,
+            OsRng
+        ),
+        Err(Error::CeremonyTranscriptMalformed)
+    ));
+}
+
+#[test]
+fn import_ethereum_ceremony_tampered_power() {
+    // Replace tau^3 * G1 with tau^2 * G1 in every transcript
+    let mut json: serde_json::Value =
+        serde_json::from_slice(ETHEREUM_TRANSCRIPT).unwrap();
+    for transcript in json["transcripts"].as_array_mut().unwrap() {
+        let powers = &mut transcript["powersOfTau"]["G1Powers"];
+        powers[3] = powers[2].clone();
+    }
+    let json = serde_json::to_vec(&json).unwrap();
+
+    assert!(matches!(
+        PublicParameters::from_ethereum_ceremony(&json, 10, OsRng),
+        Err(Error::PublicParametersPowerMismatch { index: 3 })
+    ));
+}
+
+#[test]
+fn contribute_stream_files() -> Result<(), Box<dyn std::error::Error>> {
+    use rand_core::OsRng;
+    use std::fs::File;
+    use std::io::{BufReader, BufWriter};
+    use tempdir::TempDir;
+
+    let dir = TempDir::new("ceremony")?;
+    let path = |i: usize| dir.path().join(format!("params_{}.bin", i));
+
+    // Exceed the streaming chunk of 2^12 powers to cover its boundaries
+    let max_degree = (1 << 12) + 3;
+    let genesis = PublicParameters::ceremony_genesis(max_degree)?;
+    std::fs::write(path(0), genesis.to_var_bytes())?;
+
+    let contributions = (0..2)
+        .map(|i| -> Result<_, Box<dyn std::error::Error>> {
+            let reader = BufReader::new(File::open(path(i))?);
+            let writer = BufWriter::new(File::create(path(i + 1))?);
+
+            let contribution =
+                PublicParameters::contribute_stream(reader, writer, OsRng)?;
+            Ok(contribution)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let reader = BufReader::new(File::open(path(2))?);
+    PublicParameters::verify_contributions_stream(
+        reader,
+        &contributions,
+        OsRng,
+    )?;
+
+    // The streamed parameters are the ones the in-memory API reads back
+    let pp = PublicParameters::from_slice(&std::fs::read(path(2))?)?;
+    assert_eq!(pp.max_degree(), max_degree);
+    pp.verify_contributions(&contributions, OsRng)?;
+
+    // The intermediate parameters don't carry the last contribution
+    let reader = BufReader::new(File::open(path(1))?);
+    assert!(PublicParameters::verify_contributions_stream(
+        reader,
+        &contributions,
+        OsRng,
+    )
+    .is_err());
+
+    // A truncated file is rejected
+    let bytes = std::fs::read(path(2))?;
+    std::fs::write(path(3), &bytes[..bytes.len() - 1])?;
+    let reader = BufReader::new(File::open(path(3))?);
+    assert!(matches!(
+        PublicParameters::verify_contributions_stream(
+            reader,
+            &contributions,
+            OsRng,
+        ),
+        Err(Error::NotEnoughBytes)
+    ));
+
+    Ok(())
+}
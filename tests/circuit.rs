@@ -27,6 +27,7 @@ pub struct TestCircuit {
 
 impl Circuit for TestCircuit {
     const CIRCUIT_ID: [u8; 32] = [0xff; 32];
+    type PublicInputs = Vec<PublicInputValue>;
     fn gadget(
         &mut self,
         composer: &mut TurboComposer,
@@ -65,10 +66,6 @@ impl Circuit for TestCircuit {
     fn public_inputs(&self) -> Vec<PublicInputValue> {
         vec![self.c.into(), self.d.into(), self.f.into()]
     }
-
-    fn padded_gates(&self) -> usize {
-        1 << 11
-    }
 }
 
 #[test]
@@ -117,6 +114,8 @@ fn test_full() -> Result<()> {
 
     assert_eq!(vd_p.key(), vd.key());
     assert_eq!(vd_p.public_inputs_indexes(), vd.public_inputs_indexes());
+    assert_eq!(vd_p.fingerprint(), vd.fingerprint());
+    assert_eq!(vd.fingerprint(), pk.fingerprint());
 
     // Prover POV
     let proof = {
@@ -147,3 +146,571 @@ fn test_full() -> Result<()> {
 
     Ok(TestCircuit::verify(&pp, &vd, &proof, &public_inputs, b"Test").unwrap())
 }
+
+#[test]
+fn test_min_srs_degree() -> Result<()> {
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32,
+        0x54, 0x06, 0xbc, 0xe5,
+    ]);
+
+    let mut circuit = TestCircuit::default();
+    let degree = circuit.min_srs_degree()?;
+
+    assert!(degree.is_power_of_two());
+
+    // The parameters of the exact size returned are enough to compile
+    let pp = PublicParameters::setup(degree, &mut rng)?;
+    let (pk, vd) = circuit.compile(&pp)?;
+
+    assert_eq!(pk.padded_gates() as usize, degree);
+    assert_eq!(vd.padded_gates() as usize, degree);
+
+    // Smaller parameters are not
+    let pp = PublicParameters::setup(degree >> 1, &mut rng)?;
+    assert!(circuit.compile(&pp).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_verifier_key_from_prover_key() -> Result<()> {
+    use dusk_plonk::commitment_scheme::Kzg10;
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    let rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32,
+        0x54, 0x06, 0xbc, 0xe5,
+    ]);
+
+    let mut circuit = TestCircuit::default();
+    let pp = PublicParameters::setup(circuit.min_srs_degree()?, rng)?;
+    let (pk, vd) = circuit.compile(&pp)?;
+
+    // The key compiled in a single pass matches a separate preprocessing
+    let (ck, _) = pp.trim(pk.padded_gates() as usize)?;
+    let mut verifier = Verifier::new(b"CircuitCompilation");
+    circuit.gadget(verifier.composer_mut())?;
+    verifier.preprocess(&ck)?;
+
+    assert_eq!(verifier.verifier_key.as_ref(), Some(vd.key()));
+
+    // The verifier key can be regenerated from a stored prover key
+    let pk = ProverKey::from_slice(&pk.to_var_bytes())?;
+
+    assert_eq!(&pk.verifier_key::<Kzg10>(&ck)?, vd.key());
+
+    Ok(())
+}
+
+// Implements a circuit whose gadget branches on the value of a witness, so
+// its shape depends on the witness it's built with
+#[derive(Debug, Default)]
+pub struct BranchingCircuit {
+    a: BlsScalar,
+}
+
+impl Circuit for BranchingCircuit {
+    const CIRCUIT_ID: [u8; 32] = [0xfe; 32];
+    type PublicInputs = ();
+
+    fn gadget(
+        &mut self,
+        composer: &mut TurboComposer,
+    ) -> std::result::Result<(), Error> {
+        let a = composer.append_witness(self.a);
+
+        if unsafe { composer.evaluate_witness(&a) } == &BlsScalar::zero() {
+            composer.assert_equal_constant(a, BlsScalar::zero(), None);
+        } else {
+            // A new witness is wired, so the wires differ as well
+            let b = composer.append_witness(self.a);
+            composer.assert_equal_constant(b, BlsScalar::one(), None);
+        }
+
+        Ok(())
+    }
+
+    fn public_inputs(&self) {}
+}
+
+#[test]
+fn test_circuit_shape_mismatch() -> Result<()> {
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    let rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32,
+        0x54, 0x06, 0xbc, 0xe5,
+    ]);
+
+    let mut circuit = BranchingCircuit::default();
+    let pp = PublicParameters::setup(circuit.min_srs_degree()?, rng)?;
+    let (pk, vd) = circuit.compile(&pp)?;
+
+    // The circuit keeps the shape it was compiled with
+    let proof = circuit.prove(&pp, &pk, b"Test")?;
+    BranchingCircuit::verify(&pp, &vd, &proof, &(), b"Test")?;
+
+    // The circuit is built with a different shape
+    let mut circuit = BranchingCircuit {
+        a: BlsScalar::one(),
+    };
+    let result = circuit.prove(&pp, &pk, b"Test");
+
+    assert!(matches!(result, Err(Error::CircuitShapeMismatch)));
+
+    Ok(())
+}
+
+#[test]
+fn test_prove_witness_only() -> Result<()> {
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    let rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32,
+        0x54, 0x06, 0xbc, 0xe5,
+    ]);
+
+    let mut circuit = TestCircuit::default();
+    let pp = PublicParameters::setup(circuit.min_srs_degree()?, rng)?;
+    let (pk, vd) = circuit.compile(&pp)?;
+
+    let f = JubJubAffine::from(
+        dusk_jubjub::GENERATOR_EXTENDED * JubJubScalar::from(3u64),
+    );
+    let mut circuit = TestCircuit {
+        a: BlsScalar::from(12u64),
+        b: BlsScalar::from(7u64),
+        c: BlsScalar::from(19u64),
+        d: BlsScalar::from(84u64),
+        e: JubJubScalar::from(3u64),
+        f,
+    };
+
+    // The replayed circuit is proven with the selectors of the key
+    let proof = circuit.prove_witness_only(&pp, &pk, b"Test")?;
+    let public_inputs = circuit.public_inputs();
+    TestCircuit::verify(&pp, &vd, &proof, &public_inputs, b"Test")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_circuit_shape_mismatch_witness_only() -> Result<()> {
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    let rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32,
+        0x54, 0x06, 0xbc, 0xe5,
+    ]);
+
+    let mut circuit = BranchingCircuit::default();
+    let pp = PublicParameters::setup(circuit.min_srs_degree()?, rng)?;
+    let (pk, vd) = circuit.compile(&pp)?;
+
+    // The replayed circuit keeps the shape it was compiled with
+    let proof = circuit.prove_witness_only(&pp, &pk, b"Test")?;
+    BranchingCircuit::verify(&pp, &vd, &proof, &(), b"Test")?;
+
+    // The replayed circuit has the same number of gates and public inputs,
+    // but other wires
+    let mut circuit = BranchingCircuit {
+        a: BlsScalar::one(),
+    };
+    let result = circuit.prove_witness_only(&pp, &pk, b"Test");
+
+    assert!(matches!(result, Err(Error::CircuitShapeMismatch)));
+
+    Ok(())
+}
+
+// Implements a circuit with `gates` gates asserting `a == a` and a PI of
+// value `a`, appended before or after them
+#[derive(Debug, Default)]
+pub struct ShapeCircuit {
+    a: BlsScalar,
+    gates: usize,
+    public_first: bool,
+}
+
+impl Circuit for ShapeCircuit {
+    const CIRCUIT_ID: [u8; 32] = [0xf8; 32];
+    type PublicInputs = Vec<PublicInputValue>;
+
+    fn gadget(
+        &mut self,
+        composer: &mut TurboComposer,
+    ) -> std::result::Result<(), Error> {
+        let public = self.public_first.then(|| composer.append_public(self.a));
+
+        let a = composer.append_witness(self.a);
+        (0..self.gates).for_each(|_| composer.assert_equal(a, a));
+
+        let public = public.unwrap_or_else(|| composer.append_public(self.a));
+        composer.assert_equal(a, public);
+
+        Ok(())
+    }
+
+    fn public_inputs(&self) -> Vec<PublicInputValue> {
+        vec![self.a.into()]
+    }
+}
+
+#[test]
+fn test_prove_witness_only_shape_mismatch() -> Result<()> {
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    let rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32,
+        0x54, 0x06, 0xbc, 0xe5,
+    ]);
+
+    let mut circuit = ShapeCircuit {
+        a: BlsScalar::zero(),
+        gates: 2,
+        public_first: false,
+    };
+    let pp = PublicParameters::setup(circuit.min_srs_degree()?, rng)?;
+    let (pk, vd) = circuit.compile(&pp)?;
+
+    // The circuit keeps the shape it was compiled with
+    let mut circuit = ShapeCircuit {
+        a: BlsScalar::from(5u64),
+        gates: 2,
+        public_first: false,
+    };
+    let proof = circuit.prove_witness_only(&pp, &pk, b"Test")?;
+    let public_inputs = circuit.public_inputs();
+    ShapeCircuit::verify(&pp, &vd, &proof, &public_inputs, b"Test")?;
+
+    // The circuit has another number of gates
+    let mut circuit = ShapeCircuit {
+        a: BlsScalar::from(5u64),
+        gates: 3,
+        public_first: false,
+    };
+    let result = circuit.prove_witness_only(&pp, &pk, b"Test");
+
+    assert!(matches!(result, Err(Error::CircuitShapeMismatch)));
+
+    // The circuit has its public input at another position
+    let mut circuit = ShapeCircuit {
+        a: BlsScalar::from(5u64),
+        gates: 2,
+        public_first: true,
+    };
+    let result = circuit.prove_witness_only(&pp, &pk, b"Test");
+
+    assert!(matches!(result, Err(Error::CircuitShapeMismatch)));
+
+    Ok(())
+}
+
+fn compile_and_prove(
+    rng: &mut rand_xorshift::XorShiftRng,
+) -> Result<(PublicParameters, VerifierData, Proof, Vec<PublicInputValue>)> {
+    let mut circuit = TestCircuit::default();
+    let pp = PublicParameters::setup(circuit.min_srs_degree()?, rng)?;
+    let (pk, vd) = circuit.compile(&pp)?;
+
+    let f = JubJubAffine::from(
+        dusk_jubjub::GENERATOR_EXTENDED * JubJubScalar::from(2u64),
+    );
+    let mut circuit = TestCircuit {
+        a: BlsScalar::from(20u64),
+        b: BlsScalar::from(5u64),
+        c: BlsScalar::from(25u64),
+        d: BlsScalar::from(100u64),
+        e: JubJubScalar::from(2u64),
+        f,
+    };
+    let proof = circuit.prove(&pp, &pk, b"Test")?;
+    let public_inputs = circuit.public_inputs();
+
+    Ok((pp, vd, proof, public_inputs))
+}
+
+#[test]
+fn test_verify_malformed_public_inputs() -> Result<()> {
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32,
+        0x54, 0x06, 0xbc, 0xe5,
+    ]);
+    let (pp, vd, proof, public_inputs) = compile_and_prove(&mut rng)?;
+
+    TestCircuit::verify(&pp, &vd, &proof, &public_inputs, b"Test")?;
+
+    // Missing public inputs
+    let result = TestCircuit::verify(
+        &pp,
+        &vd,
+        &proof,
+        &public_inputs[..public_inputs.len() - 1].to_vec(),
+        b"Test",
+    );
+    assert!(matches!(
+        result,
+        Err(Error::PublicInputsLengthMismatch {
+            expected: 4,
+            found: 2
+        })
+    ));
+
+    // Extra public inputs
+    let mut extra = public_inputs.clone();
+    extra.push(BlsScalar::one().into());
+    let result = TestCircuit::verify(&pp, &vd, &proof, &extra, b"Test");
+    assert!(matches!(
+        result,
+        Err(Error::PublicInputsLengthMismatch {
+            expected: 4,
+            found: 5
+        })
+    ));
+
+    // Malformed public input positions
+    let gates = vd.padded_gates();
+    let mut indexes = vd.public_inputs_indexes().to_vec();
+
+    let malformed = |indexes: Vec<u32>| {
+        VerifierData::new(*vd.key(), indexes, *vd.fingerprint())
+    };
+
+    let mut out_of_bounds = indexes.clone();
+    out_of_bounds[3] = gates;
+    let out_of_bounds = malformed(out_of_bounds);
+
+    let mut duplicated = indexes.clone();
+    duplicated[2] = duplicated[1];
+    let duplicated = malformed(duplicated);
+
+    indexes.swap(0, 1);
+    let unsorted = malformed(indexes);
+
+    let result =
+        TestCircuit::verify(&pp, &out_of_bounds, &proof, &public_inputs, b"T");
+    assert!(matches!(
+        result,
+        Err(Error::PublicInputIndexOutOfBounds { index }) if index == gates
+    ));
+    let result = VerifierData::from_slice(&out_of_bounds.to_var_bytes());
+    assert!(matches!(
+        result,
+        Err(Error::PublicInputIndexOutOfBounds { index }) if index == gates
+    ));
+
+    let index = duplicated.public_inputs_indexes()[1];
+    let result =
+        TestCircuit::verify(&pp, &duplicated, &proof, &public_inputs, b"T");
+    assert!(matches!(
+        result,
+        Err(Error::PublicInputIndexDuplicated { index: i }) if i == index
+    ));
+    let result = VerifierData::from_slice(&duplicated.to_var_bytes());
+    assert!(matches!(
+        result,
+        Err(Error::PublicInputIndexDuplicated { index: i }) if i == index
+    ));
+
+    let result =
+        TestCircuit::verify(&pp, &unsorted, &proof, &public_inputs, b"T");
+    assert!(matches!(result, Err(Error::PublicInputIndexesUnsorted)));
+    let result = VerifierData::from_slice(&unsorted.to_var_bytes());
+    assert!(matches!(result, Err(Error::PublicInputIndexesUnsorted)));
+
+    Ok(())
+}
+
+#[test]
+fn test_verifier_data_fuzz() -> Result<()> {
+    use rand::{Rng, SeedableRng};
+    use rand_xorshift::XorShiftRng;
+
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32,
+        0x54, 0x06, 0xbc, 0xe5,
+    ]);
+    let (pp, vd, proof, public_inputs) = compile_and_prove(&mut rng)?;
+    let bytes = vd.to_var_bytes();
+
+    // Neither decoding nor verifying with malformed verifier data and public
+    // inputs should panic
+    for _ in 0..256 {
+        let mut malformed = bytes.clone();
+
+        match rng.gen_range(0..3) {
+            // Truncate the encoding
+            0 => malformed.truncate(rng.gen_range(0..bytes.len())),
+            // Flip a bit of the encoding
+            1 => {
+                let i = rng.gen_range(0..bytes.len());
+                malformed[i] ^= 1 << rng.gen_range(0..8);
+            }
+            // Flip a bit of the public input positions
+            _ => {
+                let i = rng.gen_range(bytes.len() - 16..bytes.len());
+                malformed[i] ^= 1 << rng.gen_range(0..8);
+            }
+        }
+
+        let malformed = match VerifierData::from_slice(&malformed) {
+            Ok(vd) => vd,
+            Err(_) => continue,
+        };
+
+        let len = rng.gen_range(0..=public_inputs.len());
+        let _ = TestCircuit::verify(
+            &pp,
+            &malformed,
+            &proof,
+            &public_inputs[..len].to_vec(),
+            b"Test",
+        );
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, PublicInputs)]
+struct TestPublicInputs {
+    c: BlsScalar,
+    d: BlsScalar,
+    f: JubJubAffine,
+}
+
+#[derive(Debug, PublicInputs)]
+struct NestedPublicInputs(u64, [JubJubScalar; 2], TestPublicInputs);
+
+#[test]
+fn test_derive_public_inputs() {
+    let f = JubJubAffine::from(
+        dusk_jubjub::GENERATOR_EXTENDED * JubJubScalar::from(2u64),
+    );
+
+    let typed = TestPublicInputs {
+        c: BlsScalar::from(25u64),
+        d: BlsScalar::from(100u64),
+        f,
+    };
+    let untyped: Vec<PublicInputValue> = vec![
+        BlsScalar::from(25u64).into(),
+        BlsScalar::from(100u64).into(),
+        f.into(),
+    ];
+
+    assert_eq!(typed.to_scalars(), untyped.to_scalars());
+
+    let nested = NestedPublicInputs(
+        7,
+        [JubJubScalar::from(3u64), JubJubScalar::from(4u64)],
+        typed,
+    );
+    let mut scalars = vec![
+        BlsScalar::from(7u64),
+        BlsScalar::from(3u64),
+        BlsScalar::from(4u64),
+    ];
+    scalars.extend(untyped.to_scalars());
+
+    assert_eq!(nested.to_scalars(), scalars);
+}
+
+// Implements a circuit that computes the inverse of `a` with a hint, and
+// checks it only if `constrained` is set. The gadget refuses to build the
+// circuit with an unconstrained hint if `checked` is set
+#[derive(Debug, Default)]
+pub struct HintCircuit {
+    a: BlsScalar,
+    constrained: bool,
+    checked: bool,
+}
+
+impl Circuit for HintCircuit {
+    const CIRCUIT_ID: [u8; 32] = [0xfd; 32];
+    type PublicInputs = ();
+
+    fn gadget(
+        &mut self,
+        composer: &mut TurboComposer,
+    ) -> std::result::Result<(), Error> {
+        let a = composer.append_witness(self.a);
+        let [inv] = composer.append_hint(&[a], |values| {
+            [values[0].invert().unwrap_or(BlsScalar::zero())]
+        });
+
+        if self.constrained {
+            // a · inv = 1
+            let constraint = Constraint::new().mult(1).a(a).b(inv);
+            let one = composer.gate_mul(constraint);
+            composer.assert_equal_constant(one, BlsScalar::one(), None);
+        }
+
+        if self.checked {
+            composer.check_hints()?;
+        }
+
+        Ok(())
+    }
+
+    fn public_inputs(&self) {}
+}
+
+#[test]
+fn test_unconstrained_hint() -> Result<()> {
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    let rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32,
+        0x54, 0x06, 0xbc, 0xe5,
+    ]);
+
+    let mut circuit = HintCircuit {
+        a: BlsScalar::zero(),
+        constrained: true,
+        checked: true,
+    };
+    let pp = PublicParameters::setup(circuit.min_srs_degree()?, rng)?;
+    let (pk, vd) = circuit.compile(&pp)?;
+
+    let mut circuit = HintCircuit {
+        a: BlsScalar::from(7u64),
+        constrained: true,
+        checked: true,
+    };
+    let proof = circuit.prove(&pp, &pk, b"Test")?;
+    HintCircuit::verify(&pp, &vd, &proof, &(), b"Test")?;
+
+    // The hint is never used in a gate, which is only reported, so the
+    // circuit still compiles
+    let mut circuit = HintCircuit::default();
+    assert!(circuit.compile(&pp).is_ok());
+
+    let mut composer = TurboComposer::new();
+    circuit.gadget(&mut composer)?;
+    assert_eq!(composer.unconstrained_hints().len(), 1);
+    assert_eq!(composer.analyze_constraints().unconstrained_hints.len(), 1);
+
+    // Unless the gadget checks its hints
+    let mut circuit = HintCircuit {
+        checked: true,
+        ..Default::default()
+    };
+    let result = circuit.compile(&pp);
+
+    assert!(matches!(result, Err(Error::UnconstrainedHint(_))));
+
+    Ok(())
+}
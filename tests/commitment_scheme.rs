@@ -0,0 +1,82 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_plonk::commitment_scheme::{Ipa, IpaParameters};
+use dusk_plonk::prelude::*;
+
+const LABEL: &[u8] = b"ipa-test";
+
+// Checks that a * b = c and that a is in range
+fn gadget(composer: &mut TurboComposer, a: u64, b: u64) {
+    let c = BlsScalar::from(a) * BlsScalar::from(b);
+
+    let a = composer.append_witness(BlsScalar::from(a));
+    let b = composer.append_witness(BlsScalar::from(b));
+    let c = composer.append_witness(c);
+
+    let constraint = Constraint::new()
+        .mult(1)
+        .output(-BlsScalar::one())
+        .a(a)
+        .b(b)
+        .o(c);
+    composer.append_gate(constraint);
+    composer.component_range(a, 1 << 6);
+}
+
+#[test]
+fn test_ipa_prove_verify() {
+    let params = IpaParameters::setup(1 << 8).expect("Failed to setup IPA");
+
+    let mut prover = Prover::<Ipa>::with_label(LABEL);
+    gadget(prover.composer_mut(), 12, 35);
+    let proof = prover.prove(&params).expect("Failed to prove");
+
+    let mut verifier = Verifier::<Ipa>::with_label(LABEL);
+    gadget(verifier.composer_mut(), 0, 0);
+    verifier.preprocess(&params).expect("Failed to preprocess");
+
+    verifier
+        .verify(&proof, &params, &[])
+        .expect("The proof should verify");
+
+    let bytes = proof.to_var_bytes();
+    let decoded =
+        Proof::<Ipa>::from_var_bytes(&bytes).expect("Failed to decode proof");
+    assert_eq!(decoded, proof);
+
+    // Openings with a different number of rounds are rejected
+    let larger = IpaParameters::setup(1 << 9).expect("Failed to setup IPA");
+    assert!(matches!(
+        verifier.verify(&proof, &larger, &[]),
+        Err(Error::ProofVerificationError)
+    ));
+}
+
+#[test]
+fn test_ipa_verifier_key() {
+    let params = IpaParameters::setup(1 << 8).expect("Failed to setup IPA");
+
+    let mut prover = Prover::<Ipa>::with_label(LABEL);
+    gadget(prover.composer_mut(), 12, 35);
+    let verifier_key: VerifierKey<Ipa> =
+        prover.preprocess(&params).expect("Failed to preprocess");
+
+    // The prover key holds no commitment, so the verifier key of the scheme
+    // it's used with can be derived from it
+    let prover_key = prover.prover_key.as_ref().unwrap();
+    assert_eq!(
+        prover_key
+            .verifier_key::<Ipa>(&params)
+            .expect("Failed to commit to the prover key"),
+        verifier_key
+    );
+
+    let mut verifier = Verifier::<Ipa>::with_label(LABEL);
+    gadget(verifier.composer_mut(), 0, 0);
+    verifier.preprocess(&params).expect("Failed to preprocess");
+    assert_eq!(verifier.verifier_key, Some(verifier_key));
+}
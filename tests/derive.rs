@@ -0,0 +1,155 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_plonk::prelude::*;
+use rand::SeedableRng;
+use rand_xorshift::XorShiftRng;
+
+// Implements a circuit that checks:
+// 1) a + b = c where C is a PI
+// 2) a * b = d where D is a PI
+// 3) a + k = b where K is a constant
+// 4) JubJub::GENERATOR * e(JubJubScalar) = f where F is a PI
+#[derive(Debug, Default, Circuit)]
+pub struct DerivedCircuit {
+    #[circuit(witness)]
+    a: BlsScalar,
+    #[circuit(witness)]
+    b: BlsScalar,
+    #[circuit(public)]
+    c: BlsScalar,
+    #[circuit(public)]
+    d: BlsScalar,
+    #[circuit(constant)]
+    k: u64,
+    #[circuit(witness)]
+    e: JubJubScalar,
+    #[circuit(public)]
+    f: JubJubAffine,
+    #[circuit(skip)]
+    label: &'static str,
+}
+
+impl DerivedCircuit {
+    fn constrain(
+        &mut self,
+        composer: &mut TurboComposer,
+        witnesses: DerivedCircuitWitnesses,
+    ) -> Result<(), Error> {
+        let DerivedCircuitWitnesses {
+            a,
+            b,
+            c,
+            d,
+            k,
+            e,
+            f,
+        } = witnesses;
+
+        let constraint = Constraint::new()
+            .left(1)
+            .right(1)
+            .output(-BlsScalar::one())
+            .a(a)
+            .b(b)
+            .o(c);
+        composer.append_gate(constraint);
+
+        let constraint = Constraint::new()
+            .mult(1)
+            .output(-BlsScalar::one())
+            .a(a)
+            .b(b)
+            .o(d);
+        composer.append_gate(constraint);
+
+        let sum =
+            composer.gate_add(Constraint::new().left(1).right(1).a(a).b(k));
+        composer.assert_equal(sum, b);
+
+        let point = composer
+            .component_mul_generator(e, dusk_jubjub::GENERATOR_EXTENDED);
+        composer.assert_equal_point(point, f);
+
+        Ok(())
+    }
+}
+
+fn circuit(a: u64, b: u64, e: u64) -> DerivedCircuit {
+    DerivedCircuit {
+        a: BlsScalar::from(a),
+        b: BlsScalar::from(b),
+        c: BlsScalar::from(a + b),
+        d: BlsScalar::from(a * b),
+        k: 2,
+        e: JubJubScalar::from(e),
+        f: JubJubAffine::from(
+            dusk_jubjub::GENERATOR_EXTENDED * JubJubScalar::from(e),
+        ),
+        label: "derived",
+    }
+}
+
+#[test]
+fn test_derive_circuit() {
+    let rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32,
+        0x54, 0x06, 0xbc, 0xe5,
+    ]);
+
+    // The constant is part of the circuit description
+    let mut compiled = DerivedCircuit {
+        k: 2,
+        ..Default::default()
+    };
+    let degree = compiled.min_srs_degree().expect("Failed to size circuit");
+    let pp = PublicParameters::setup(degree, rng).expect("Failed to setup");
+    let (pk, vd) = compiled.compile(&pp).expect("Failed to compile");
+
+    // c, d and both coordinates of f
+    assert_eq!(vd.public_inputs_indexes().len(), 4);
+
+    let mut prover = circuit(3, 5, 7);
+    let proof = prover.prove(&pp, &pk, b"Test").expect("Failed to prove");
+
+    let public_inputs = DerivedCircuitPublicInputs {
+        c: BlsScalar::from(8u64),
+        d: BlsScalar::from(15u64),
+        f: prover.f,
+    };
+    assert_eq!(
+        public_inputs.to_scalars(),
+        prover.public_inputs().to_scalars()
+    );
+
+    DerivedCircuit::verify(&pp, &vd, &proof, &public_inputs, b"Test")
+        .expect("Failed to verify");
+
+    // Wrong public inputs
+    let public_inputs = DerivedCircuitPublicInputs {
+        c: BlsScalar::from(9u64),
+        ..public_inputs
+    };
+    assert!(
+        DerivedCircuit::verify(&pp, &vd, &proof, &public_inputs, b"Test")
+            .is_err()
+    );
+
+    // Unsatisfied constant constraint
+    let mut prover = circuit(3, 6, 7);
+    let proof = prover.prove(&pp, &pk, b"Test").expect("Failed to prove");
+    let public_inputs = prover.public_inputs();
+    assert!(
+        DerivedCircuit::verify(&pp, &vd, &proof, &public_inputs, b"Test")
+            .is_err()
+    );
+}
+
+#[test]
+fn test_derive_circuit_id() {
+    assert_ne!(DerivedCircuit::CIRCUIT_ID, [0u8; 32]);
+    assert_eq!(circuit(1, 3, 1).label, "derived");
+}
@@ -0,0 +1,265 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_bytes::Serializable;
+use dusk_plonk::plonkup::{LookupArgument, LookupTable};
+use dusk_plonk::prelude::*;
+use rand::SeedableRng;
+use rand_xorshift::XorShiftRng;
+
+const LABEL: &[u8] = b"lookup-test";
+
+// Checks that a * b = c
+fn gadget(composer: &mut TurboComposer, a: u64, b: u64) {
+    let c = BlsScalar::from(a) * BlsScalar::from(b);
+
+    let a = composer.append_witness(BlsScalar::from(a));
+    let b = composer.append_witness(BlsScalar::from(b));
+    let c = composer.append_witness(c);
+
+    let constraint = Constraint::new()
+        .mult(1)
+        .output(-BlsScalar::one())
+        .a(a)
+        .b(b)
+        .o(c);
+    composer.append_gate(constraint);
+}
+
+// Checks that a * b = c mod 2^3 with a lookup in a multiplication table
+fn lookup_gadget(composer: &mut TurboComposer, a: u64, b: u64) {
+    let mut table = LookupTable::new();
+    table.insert_multi_mul(0, 3);
+    let mul = composer.append_plonkup_table(&table);
+
+    let c = (a * b) % (1 << 3);
+
+    let a = composer.append_witness(BlsScalar::from(a));
+    let b = composer.append_witness(BlsScalar::from(b));
+    let c = composer.append_witness(BlsScalar::from(c));
+    let one = composer.append_witness(BlsScalar::one());
+
+    composer.append_plonkup_gate(mul, a, b, c, one, None);
+    gadget(composer, 2, 3);
+}
+
+// Registers a multiplication table and a table with a single row that has
+// the same tag as the multiplication rows, then looks that row up in the
+// table selected by `query_mul`
+fn two_tables_gadget(composer: &mut TurboComposer, query_mul: bool) {
+    let mut mul_table = LookupTable::new();
+    mul_table.insert_multi_mul(0, 3);
+    let mul = composer.append_plonkup_table(&mul_table);
+
+    let mut row_table = LookupTable::new();
+    row_table.insert_special_row(
+        BlsScalar::from(2),
+        BlsScalar::from(5),
+        BlsScalar::from(7),
+        BlsScalar::one(),
+    );
+    let row = composer.append_plonkup_table(&row_table);
+
+    let a = composer.append_witness(BlsScalar::from(2));
+    let b = composer.append_witness(BlsScalar::from(5));
+    let c = composer.append_witness(BlsScalar::from(7));
+    let one = composer.append_witness(BlsScalar::one());
+
+    let table = if query_mul { mul } else { row };
+    composer.append_plonkup_gate(table, a, b, c, one, None);
+}
+
+fn prove_and_verify(
+    circuit: fn(&mut TurboComposer, u64, u64),
+) -> (ProverKey, VerifierKey, Proof) {
+    prove_and_verify_with(circuit, LookupArgument::Plookup)
+}
+
+fn prove_and_verify_with(
+    circuit: fn(&mut TurboComposer, u64, u64),
+    argument: LookupArgument,
+) -> (ProverKey, VerifierKey, Proof) {
+    let rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32,
+        0x54, 0x06, 0xbc, 0xe5,
+    ]);
+    let pp = PublicParameters::setup(1 << 9, rng).expect("Failed to setup");
+    let (ck, ok) = pp.trim(1 << 8).expect("Failed to trim");
+
+    let mut prover = Prover::new(LABEL);
+    prover.set_lookup_argument(argument);
+    circuit(prover.composer_mut(), 2, 5);
+    let proof = prover.prove(&ck).expect("Failed to prove");
+
+    let mut verifier = Verifier::new(LABEL);
+    verifier.set_lookup_argument(argument);
+    circuit(verifier.composer_mut(), 0, 0);
+    verifier.preprocess(&ck).expect("Failed to preprocess");
+
+    verifier
+        .verify(&proof, &ok, &[])
+        .expect("The proof should verify");
+
+    let decoded =
+        Proof::from_var_bytes(&proof.to_var_bytes()).expect("Failed to decode");
+    verifier
+        .verify(&decoded, &ok, &[])
+        .expect("The decoded proof should verify");
+
+    let prover_key = prover.prover_key.expect("The prover is preprocessed");
+    let verifier_key =
+        verifier.verifier_key.expect("The verifier is preprocessed");
+
+    (prover_key, verifier_key, proof)
+}
+
+#[test]
+fn test_proof_without_lookups() {
+    let (prover_key, verifier_key, proof) = prove_and_verify(gadget);
+
+    assert!(!prover_key.lookups_enabled());
+    assert!(!verifier_key.lookups_enabled());
+
+    // The constant size encoding still round-trips
+    let decoded =
+        Proof::from_bytes(&proof.to_bytes()).expect("Failed to decode");
+    assert_eq!(decoded, proof);
+}
+
+#[test]
+fn test_proof_with_lookups() {
+    let (prover_key, verifier_key, _) = prove_and_verify(lookup_gadget);
+
+    assert!(prover_key.lookups_enabled());
+    assert!(verifier_key.lookups_enabled());
+}
+
+#[test]
+fn test_proof_without_lookups_is_smaller() {
+    let (_, _, proof) = prove_and_verify(gadget);
+    let (_, _, lookup_proof) = prove_and_verify(lookup_gadget);
+
+    assert!(proof.to_var_bytes().len() < lookup_proof.to_var_bytes().len());
+}
+
+#[test]
+fn test_proof_with_multiple_tables() {
+    let rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32,
+        0x54, 0x06, 0xbc, 0xe5,
+    ]);
+    let pp = PublicParameters::setup(1 << 9, rng).expect("Failed to setup");
+    let (ck, ok) = pp.trim(1 << 8).expect("Failed to trim");
+
+    let mut prover = Prover::new(LABEL);
+    two_tables_gadget(prover.composer_mut(), false);
+    let proof = prover.prove(&ck).expect("Failed to prove");
+
+    let mut verifier = Verifier::new(LABEL);
+    two_tables_gadget(verifier.composer_mut(), false);
+    verifier.preprocess(&ck).expect("Failed to preprocess");
+
+    verifier
+        .verify(&proof, &ok, &[])
+        .expect("The proof should verify");
+
+    // The row belongs to the second table only, so it can't be found when
+    // querying the multiplication table
+    let mut prover = Prover::new(LABEL);
+    two_tables_gadget(prover.composer_mut(), true);
+    assert!(matches!(prover.prove(&ck), Err(Error::ElementNotIndexed)));
+}
+
+#[test]
+fn test_logup_proof_with_lookups() {
+    let (prover_key, _, proof) =
+        prove_and_verify_with(lookup_gadget, LookupArgument::LogUp);
+
+    assert!(prover_key.lookups_enabled());
+    assert_eq!(prover_key.lookup_argument(), LookupArgument::LogUp);
+    assert_eq!(proof.lookup_argument(), LookupArgument::LogUp);
+
+    // The table has more rows than the circuit has gates, so it sets the
+    // size of the domain
+    assert!(prover_key.padded_gates() >= 64);
+
+    let decoded =
+        Proof::from_bytes(&proof.to_bytes()).expect("Failed to decode");
+    assert_eq!(decoded, proof);
+}
+
+#[test]
+fn test_logup_proof_without_lookups() {
+    // The argument is irrelevant if the circuit has no lookups
+    let (_, _, proof) = prove_and_verify_with(gadget, LookupArgument::LogUp);
+    let (_, _, plookup_proof) = prove_and_verify(gadget);
+
+    assert_eq!(proof.lookup_argument(), LookupArgument::Plookup);
+    assert_eq!(proof.to_var_bytes(), plookup_proof.to_var_bytes());
+}
+
+#[test]
+fn test_logup_proof_is_smaller() {
+    let (_, _, proof) =
+        prove_and_verify_with(lookup_gadget, LookupArgument::LogUp);
+    let (_, _, plookup_proof) = prove_and_verify(lookup_gadget);
+
+    assert!(proof.to_var_bytes().len() < plookup_proof.to_var_bytes().len());
+}
+
+#[test]
+fn test_logup_proof_with_multiple_tables() {
+    let rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32,
+        0x54, 0x06, 0xbc, 0xe5,
+    ]);
+    let pp = PublicParameters::setup(1 << 9, rng).expect("Failed to setup");
+    let (ck, ok) = pp.trim(1 << 8).expect("Failed to trim");
+
+    let mut prover = Prover::new(LABEL);
+    prover.set_lookup_argument(LookupArgument::LogUp);
+    two_tables_gadget(prover.composer_mut(), false);
+    let proof = prover.prove(&ck).expect("Failed to prove");
+
+    let mut verifier = Verifier::new(LABEL);
+    verifier.set_lookup_argument(LookupArgument::LogUp);
+    two_tables_gadget(verifier.composer_mut(), false);
+    verifier.preprocess(&ck).expect("Failed to preprocess");
+
+    verifier
+        .verify(&proof, &ok, &[])
+        .expect("The proof should verify");
+
+    // A proof claiming to use the other argument is rejected
+    let mut bytes = proof.to_bytes();
+    bytes[0] = 0;
+    let forged = Proof::from_bytes(&bytes).expect("Failed to decode");
+    assert!(matches!(
+        verifier.verify(&forged, &ok, &[]),
+        Err(Error::LookupArgumentMismatch)
+    ));
+
+    // And a verifier preprocessed for plookup rejects the logUp proof
+    let mut verifier = Verifier::new(LABEL);
+    two_tables_gadget(verifier.composer_mut(), false);
+    verifier.preprocess(&ck).expect("Failed to preprocess");
+
+    assert_eq!(
+        verifier.verifier_key.unwrap().lookup_argument(),
+        LookupArgument::Plookup
+    );
+    assert!(matches!(
+        verifier.verify(&proof, &ok, &[]),
+        Err(Error::LookupArgumentMismatch)
+    ));
+
+    // The row belongs to the second table only, so it can't be found when
+    // querying the multiplication table
+    let mut prover = Prover::new(LABEL);
+    prover.set_lookup_argument(LookupArgument::LogUp);
+    two_tables_gadget(prover.composer_mut(), true);
+    assert!(matches!(prover.prove(&ck), Err(Error::ElementNotIndexed)));
+}
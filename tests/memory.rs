@@ -0,0 +1,210 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_plonk::prelude::*;
+use rand::SeedableRng;
+use rand_xorshift::XorShiftRng;
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+const LABEL: &[u8] = b"memory";
+
+const ROM: [u64; 4] = [5, 7, 11, 13];
+
+// Implements a circuit that reads `address` of a ROM holding `ROM` and
+// checks the read returns `value`, where `value` is a PI
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RomCircuit {
+    address: u64,
+    value: u64,
+}
+
+impl Circuit for RomCircuit {
+    const CIRCUIT_ID: [u8; 32] = [0xfb; 32];
+    type PublicInputs = Vec<BlsScalar>;
+
+    fn gadget(
+        &mut self,
+        composer: &mut TurboComposer,
+    ) -> std::result::Result<(), Error> {
+        let values: Vec<Witness> = ROM
+            .iter()
+            .map(|value| composer.append_constant(BlsScalar::from(*value)))
+            .collect();
+        let mut rom = composer.component_rom(&values);
+
+        let address = composer.append_witness(BlsScalar::from(self.address));
+        let read = composer.component_memory_read(&mut rom, address);
+
+        let value = BlsScalar::from(self.value);
+        composer.assert_equal_constant(read, BlsScalar::zero(), Some(-value));
+
+        composer.component_memory_finalize(rom);
+
+        Ok(())
+    }
+
+    fn public_inputs(&self) -> Vec<BlsScalar> {
+        vec![BlsScalar::from(self.value)]
+    }
+}
+
+// Implements a circuit that writes 9 at `address` of a RAM holding `ROM`
+// and checks a later read of `address` returns `value`, where `value` is a
+// PI
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RamCircuit {
+    address: u64,
+    value: u64,
+}
+
+impl Circuit for RamCircuit {
+    const CIRCUIT_ID: [u8; 32] = [0xfa; 32];
+    type PublicInputs = Vec<BlsScalar>;
+
+    fn gadget(
+        &mut self,
+        composer: &mut TurboComposer,
+    ) -> std::result::Result<(), Error> {
+        let values: Vec<Witness> = ROM
+            .iter()
+            .map(|value| composer.append_witness(BlsScalar::from(*value)))
+            .collect();
+        let mut ram = composer.component_ram(&values);
+
+        let address = composer.append_witness(BlsScalar::from(self.address));
+        let nine = composer.append_constant(BlsScalar::from(9u64));
+        composer.component_memory_write(&mut ram, address, nine);
+
+        let read = composer.component_memory_read(&mut ram, address);
+
+        let value = BlsScalar::from(self.value);
+        composer.assert_equal_constant(read, BlsScalar::zero(), Some(-value));
+
+        composer.component_memory_finalize(ram);
+
+        Ok(())
+    }
+
+    fn public_inputs(&self) -> Vec<BlsScalar> {
+        vec![BlsScalar::from(self.value)]
+    }
+}
+
+// Returns `true` if `circuit` is proven with a proof that verifies
+fn proves<C>(
+    pp: &PublicParameters,
+    pk: &ProverKey,
+    vd: &VerifierData,
+    mut circuit: C,
+) -> bool
+where
+    C: Circuit<PublicInputs = Vec<BlsScalar>>,
+{
+    let public_inputs = circuit.public_inputs();
+
+    circuit
+        .prove(pp, pk, LABEL)
+        .and_then(|proof| C::verify(pp, vd, &proof, &public_inputs, LABEL))
+        .is_ok()
+}
+
+fn setup<C: Circuit>(
+    circuit: &mut C,
+) -> Result<(PublicParameters, ProverKey, VerifierData)> {
+    let rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32,
+        0x54, 0x06, 0xbc, 0xe5,
+    ]);
+
+    let pp = PublicParameters::setup(circuit.min_srs_degree()?, rng)?;
+    let (pk, vd) = circuit.compile(&pp)?;
+
+    Ok((pp, pk, vd))
+}
+
+#[test]
+fn test_rom_read() -> Result<()> {
+    let (pp, pk, vd) = setup(&mut RomCircuit::default())?;
+
+    for (address, value) in ROM.iter().enumerate() {
+        let circuit = RomCircuit {
+            address: address as u64,
+            value: *value,
+        };
+
+        assert!(proves(&pp, &pk, &vd, circuit));
+    }
+
+    // The proof of a read doesn't verify for another value
+    let mut circuit = RomCircuit {
+        address: 2,
+        value: 11,
+    };
+    let proof = circuit.prove(&pp, &pk, LABEL)?;
+    let public_inputs = vec![BlsScalar::from(13u64)];
+
+    assert!(
+        RomCircuit::verify(&pp, &vd, &proof, &public_inputs, LABEL).is_err()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_rom_wrong_value_read() -> Result<()> {
+    let (pp, pk, vd) = setup(&mut RomCircuit::default())?;
+
+    let circuit = RomCircuit {
+        address: 1,
+        value: 11,
+    };
+
+    assert!(!proves(&pp, &pk, &vd, circuit));
+
+    Ok(())
+}
+
+#[test]
+fn test_rom_out_of_bounds_read() -> Result<()> {
+    let (pp, pk, vd) = setup(&mut RomCircuit::default())?;
+
+    // Out of bounds reads return zero, but the memory check fails
+    for address in [ROM.len() as u64, u64::MAX].iter().copied() {
+        let circuit = RomCircuit { address, value: 0 };
+
+        assert!(!proves(&pp, &pk, &vd, circuit));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_ram_read_after_write() -> Result<()> {
+    let (pp, pk, vd) = setup(&mut RamCircuit::default())?;
+
+    let circuit = RamCircuit {
+        address: 3,
+        value: 9,
+    };
+    assert!(proves(&pp, &pk, &vd, circuit));
+
+    // Reading the initial value of the address instead of the written one
+    let circuit = RamCircuit {
+        address: 3,
+        value: 13,
+    };
+    assert!(!proves(&pp, &pk, &vd, circuit));
+
+    // Writing and reading out of bounds
+    let circuit = RamCircuit {
+        address: 4,
+        value: 9,
+    };
+    assert!(!proves(&pp, &pk, &vd, circuit));
+
+    Ok(())
+}
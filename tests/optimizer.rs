@@ -0,0 +1,184 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_plonk::constraint_system::OptimizationReport;
+use dusk_plonk::prelude::*;
+use rand::SeedableRng;
+use rand_xorshift::XorShiftRng;
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+const LABEL: &[u8] = b"optimizer";
+
+// Implements a circuit that optimizes its gates before it's compiled or
+// proven. It checks:
+// 1) a + b + 1 + 1 = c where c is a PI, with duplicated constants
+// 2) a and b are in range, and a ^ b = d where d is a PI
+// 3) b · b⁻¹ = 1 where b⁻¹ is a hint
+// 4) JubJub::GENERATOR · a = e and e + e = f where e and f are PIs
+#[derive(Debug, Default, Clone, Copy)]
+pub struct OptimizedCircuit {
+    a: u64,
+    b: u64,
+    c: u64,
+    report: OptimizationReport,
+}
+
+impl OptimizedCircuit {
+    pub fn new(a: u64, b: u64, c: u64) -> Self {
+        Self {
+            a,
+            b,
+            c,
+            report: OptimizationReport::default(),
+        }
+    }
+
+    fn point(scalar: u64) -> JubJubAffine {
+        JubJubAffine::from(
+            dusk_jubjub::GENERATOR_EXTENDED * JubJubScalar::from(scalar),
+        )
+    }
+}
+
+impl Circuit for OptimizedCircuit {
+    const CIRCUIT_ID: [u8; 32] = [0xf9; 32];
+    type PublicInputs = Vec<PublicInputValue>;
+
+    fn gadget(
+        &mut self,
+        composer: &mut TurboComposer,
+    ) -> std::result::Result<(), Error> {
+        let a = composer.append_witness(BlsScalar::from(self.a));
+        let b = composer.append_witness(BlsScalar::from(self.b));
+
+        let one = composer.append_constant(BlsScalar::one());
+        let one_again = composer.append_constant(BlsScalar::one());
+
+        // Chained additions, merged by the optimizer
+        let constraint = Constraint::new().left(1).right(1).a(a).b(b);
+        let sum = composer.gate_add(constraint);
+        let constraint = Constraint::new().left(1).right(1).a(sum).b(one);
+        let sum = composer.gate_add(constraint);
+        let constraint = Constraint::new().left(1).right(1).a(sum).b(one_again);
+        let sum = composer.gate_add(constraint);
+
+        let c = composer.append_public(BlsScalar::from(self.c));
+        composer.assert_equal(sum, c);
+
+        // Linear gates following the custom gates
+        composer.component_range(a, 16);
+        composer.component_range(b, 16);
+
+        let d = composer.append_public(BlsScalar::from(self.a ^ self.b));
+        let xor = composer.component_xor(a, b, 16);
+        composer.assert_equal(xor, d);
+
+        // The inverse of `b`, mapping zero to zero
+        let [inverse] = composer.append_hint(&[b], |values| {
+            [values[0].invert().unwrap_or(BlsScalar::zero())]
+        });
+        let constraint = Constraint::new().mult(1).a(b).b(inverse);
+        let product = composer.gate_mul(constraint);
+        composer.assert_equal(product, one);
+
+        let e = composer
+            .component_mul_generator(a, dusk_jubjub::GENERATOR_EXTENDED);
+        let f = composer.component_add_point(e, e);
+        composer.assert_equal_public_point(e, Self::point(self.a));
+        composer.assert_equal_public_point(f, Self::point(2 * self.a));
+
+        self.report = composer.optimize();
+
+        Ok(())
+    }
+
+    fn public_inputs(&self) -> Vec<PublicInputValue> {
+        vec![
+            BlsScalar::from(self.c).into(),
+            BlsScalar::from(self.a ^ self.b).into(),
+            Self::point(self.a).into(),
+            Self::point(2 * self.a).into(),
+        ]
+    }
+}
+
+fn setup(
+    circuit: &mut OptimizedCircuit,
+) -> Result<(PublicParameters, ProverKey, VerifierData)> {
+    let rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32,
+        0x54, 0x06, 0xbc, 0xe5,
+    ]);
+
+    let pp = PublicParameters::setup(circuit.min_srs_degree()?, rng)?;
+    let (pk, vd) = circuit.compile(&pp)?;
+
+    Ok((pp, pk, vd))
+}
+
+#[test]
+fn test_optimized_circuit() -> Result<()> {
+    let mut circuit = OptimizedCircuit::new(10, 20, 32);
+    let (pp, pk, vd) = setup(&mut circuit)?;
+
+    // The circuit is compiled with all the kinds of optimization
+    assert!(circuit.report.deduplicated_constants >= 1);
+    assert!(circuit.report.collapsed_equalities >= 1);
+    assert!(circuit.report.merged_gates >= 1);
+
+    let public_inputs = circuit.public_inputs();
+    let proof = circuit.prove(&pp, &pk, LABEL)?;
+
+    OptimizedCircuit::verify(&pp, &vd, &proof, &public_inputs, LABEL)?;
+
+    // The proof doesn't verify for other public inputs
+    let public_inputs = OptimizedCircuit::new(10, 20, 33).public_inputs();
+
+    assert!(
+        OptimizedCircuit::verify(&pp, &vd, &proof, &public_inputs, LABEL)
+            .is_err()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_optimized_circuit_wrong_witness() -> Result<()> {
+    let (pp, pk, vd) = setup(&mut OptimizedCircuit::new(10, 20, 32))?;
+
+    // a + b + 1 + 1 != c, b has no inverse and a is out of range
+    let circuits = [
+        OptimizedCircuit::new(10, 20, 33),
+        OptimizedCircuit::new(10, 0, 12),
+        OptimizedCircuit::new(1 << 16, 20, (1 << 16) + 22),
+    ];
+
+    for mut circuit in circuits.iter().copied() {
+        let public_inputs = circuit.public_inputs();
+
+        let res = circuit.prove(&pp, &pk, LABEL).and_then(|proof| {
+            OptimizedCircuit::verify(&pp, &vd, &proof, &public_inputs, LABEL)
+        });
+
+        assert!(res.is_err());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_optimized_circuit_witness_only() -> Result<()> {
+    let (pp, pk, _) = setup(&mut OptimizedCircuit::new(10, 20, 32))?;
+
+    // The optimization can't be replayed without the selectors
+    let mut circuit = OptimizedCircuit::new(10, 20, 32);
+    let result = circuit.prove_witness_only(&pp, &pk, LABEL);
+
+    assert!(matches!(result, Err(Error::WitnessOnlyOptimization)));
+
+    Ok(())
+}
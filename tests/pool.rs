@@ -0,0 +1,170 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_plonk::prelude::*;
+use rand::SeedableRng;
+use rand_xorshift::XorShiftRng;
+use std::sync::Arc;
+use std::thread;
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+const LABEL: &[u8] = b"pool";
+
+// Implements a circuit that checks:
+// 1) a · b = c where c is a PI
+// 2) a < 2^16
+#[derive(Debug, Default, Clone, Copy)]
+pub struct MulCircuit {
+    a: u64,
+    b: u64,
+    fail: bool,
+}
+
+impl MulCircuit {
+    fn new(a: u64, b: u64) -> Self {
+        Self { a, b, fail: false }
+    }
+}
+
+impl Circuit for MulCircuit {
+    const CIRCUIT_ID: [u8; 32] = [0xfc; 32];
+    type PublicInputs = Vec<BlsScalar>;
+
+    fn gadget(
+        &mut self,
+        composer: &mut TurboComposer,
+    ) -> std::result::Result<(), Error> {
+        if self.fail {
+            return Err(Error::CircuitInputsNotFound);
+        }
+
+        let a = composer.append_witness(BlsScalar::from(self.a));
+        let b = composer.append_witness(BlsScalar::from(self.b));
+        let c = composer.append_public(BlsScalar::from(self.a * self.b));
+
+        let constraint = Constraint::new().mult(1).a(a).b(b);
+        let product = composer.gate_mul(constraint);
+        composer.assert_equal(product, c);
+
+        composer.component_range(a, 16);
+
+        Ok(())
+    }
+
+    fn public_inputs(&self) -> Vec<BlsScalar> {
+        vec![BlsScalar::from(self.a * self.b)]
+    }
+}
+
+fn setup() -> Result<(PublicParameters, ProverKey, VerifierData)> {
+    let rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32,
+        0x54, 0x06, 0xbc, 0xe5,
+    ]);
+
+    let mut circuit = MulCircuit::default();
+    let pp = PublicParameters::setup(circuit.min_srs_degree()?, rng)?;
+    let (pk, vd) = circuit.compile(&pp)?;
+
+    Ok((pp, pk, vd))
+}
+
+fn circuits() -> Vec<MulCircuit> {
+    (1..=8).map(|i| MulCircuit::new(i * 1000, i + 3)).collect()
+}
+
+#[test]
+fn test_prover_pool() -> Result<()> {
+    let (pp, pk, vd) = setup()?;
+    let pk = Arc::new(pk);
+
+    for witness_only in [false, true].iter().copied() {
+        let mut pool =
+            ProverPool::from_public_parameters(&pp, Arc::clone(&pk), LABEL, 4)?;
+        pool.set_witness_only(witness_only);
+
+        // The pool shares the key instead of copying it
+        assert!(Arc::ptr_eq(pool.prover_key(), &pk));
+
+        let circuits = circuits();
+        let proofs = pool.prove_all(circuits.clone());
+
+        assert_eq!(proofs.len(), circuits.len());
+
+        // The proofs are returned in the order of the circuits
+        for (circuit, proof) in circuits.iter().zip(proofs) {
+            let proof = proof?;
+            let public_inputs = circuit.public_inputs();
+
+            MulCircuit::verify(&pp, &vd, &proof, &public_inputs, LABEL)?;
+        }
+
+        // A single circuit can be proven on the calling thread too
+        let mut circuit = MulCircuit::new(7, 6);
+        let proof = pool.prove(&mut circuit)?;
+        MulCircuit::verify(&pp, &vd, &proof, &vec![42u64.into()], LABEL)?;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_prover_pool_errors() -> Result<()> {
+    let (pp, pk, _) = setup()?;
+    let pool = ProverPool::from_public_parameters(&pp, Arc::new(pk), LABEL, 3)?;
+
+    let mut circuits = circuits();
+    circuits[2].fail = true;
+    circuits[5].fail = true;
+
+    let proofs = pool.prove_all(circuits);
+
+    proofs.iter().enumerate().for_each(|(i, proof)| match i {
+        2 | 5 => assert!(matches!(proof, Err(Error::CircuitInputsNotFound))),
+        _ => assert!(proof.is_ok()),
+    });
+
+    // An empty batch doesn't spawn any worker
+    assert!(pool.prove_all(Vec::<MulCircuit>::new()).is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn test_shared_keys() -> Result<()> {
+    let (pp, pk, vd) = setup()?;
+    let (ck, _) = pp.trim(pk.padded_gates() as usize)?;
+
+    let ck = Arc::new(ck);
+    let pk = Arc::new(pk);
+
+    let workers: Vec<_> = circuits()
+        .into_iter()
+        .map(|mut circuit| {
+            let ck = Arc::clone(&ck);
+            let pk = Arc::clone(&pk);
+
+            thread::spawn(move || {
+                circuit
+                    .prove_with_commit_key(&ck, &pk, LABEL)
+                    .map(|proof| (circuit.public_inputs(), proof))
+            })
+        })
+        .collect();
+
+    for worker in workers {
+        let (public_inputs, proof) = worker.join().unwrap()?;
+
+        MulCircuit::verify(&pp, &vd, &proof, &public_inputs, LABEL)?;
+    }
+
+    // Every worker released its reference to the keys
+    assert_eq!(Arc::strong_count(&ck), 1);
+    assert_eq!(Arc::strong_count(&pk), 1);
+
+    Ok(())
+}